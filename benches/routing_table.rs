@@ -0,0 +1,58 @@
+//! Times `RoutingTable::nearest_live_nodes_to` on tables holding thousands of nodes.
+//!
+//! Run with `cargo bench --bench routing_table`.
+
+extern crate comm;
+
+use std::collections::HashSet;
+use std::time::Instant;
+
+use comm::address::{Address, Addressable};
+use comm::node::Node;
+use comm::routing_table::RoutingTable;
+use comm::servers::Connections;
+use comm::transaction::TransactionIdGenerator;
+
+const LOOKUPS: u32 = 1000;
+
+/// An address made up from `content`, hashed the way a public key would be.
+fn address(content: &str) -> Address {
+    Address::for_public_key(content.as_bytes())
+}
+
+fn table(k: usize, inserts: usize) -> RoutingTable {
+    let self_node = Node::new(address("self"), HashSet::new());
+    let mut transaction_ids = TransactionIdGenerator::new();
+    let mut connections = Connections::new();
+    let mut table = RoutingTable::new(k, self_node.address(), vec![]);
+    for i in 0..inserts {
+        let node = Node::new(address(&format!("node {}", i)), HashSet::new());
+        table.insert(node, &self_node, &mut transaction_ids, &mut connections).unwrap();
+    }
+    table
+}
+
+fn bench(k: usize, inserts: usize) {
+    let mut table = table(k, inserts);
+    let targets: Vec<Address> = (0..LOOKUPS)
+        .map(|i| address(&format!("target {}", i)))
+        .collect();
+
+    let start = Instant::now();
+    let mut found = 0;
+    for target in &targets {
+        found += table.nearest_live_nodes_to(target, false).len();
+    }
+    let elapsed = start.elapsed();
+    let nanos = elapsed.as_secs() * 1_000_000_000 + u64::from(elapsed.subsec_nanos());
+
+    println!("k = {:4}, {:6} nodes inserted: {:9} ns/lookup ({} nodes found)",
+             k, inserts, nanos / u64::from(LOOKUPS), found / LOOKUPS as usize);
+}
+
+fn main() {
+    bench(8, 10_000);
+    bench(64, 10_000);
+    bench(256, 20_000);
+    bench(1024, 50_000);
+}
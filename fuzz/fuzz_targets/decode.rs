@@ -0,0 +1,9 @@
+#![no_main]
+#[macro_use] extern crate libfuzzer_sys;
+extern crate comm;
+
+use comm::client::messages;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = messages::decode(data.to_vec());
+});
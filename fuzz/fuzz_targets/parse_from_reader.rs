@@ -0,0 +1,10 @@
+#![no_main]
+#[macro_use] extern crate libfuzzer_sys;
+extern crate comm;
+
+use comm::messages::incoming;
+use std::io::Cursor;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = incoming::parse_from_reader(&mut Cursor::new(data));
+});
@@ -27,17 +27,9 @@ pub struct Address {
 impl Address {
 
     /// Hashes `content` into an `Address`. Current implementation is to take the SHA1 digest of
-    /// `content`, but this is subject to change and should not be depended on.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use comm::address;
-    ///
-    /// let addr = address::Address::for_content("alep");
-    /// let addr_from_str = address::Address::from_str("44751799925b964a00bae3863cc4236f9bb8d519").unwrap();
-    /// assert_eq!(addr, addr_from_str);
-    /// ```
+    /// `content`, but this is subject to change and should not be depended on. Nodes' addresses
+    /// come from their keys instead, so this is only for making up addresses in tests.
+    #[cfg(test)]
     pub fn for_content(content: &str) -> Address {
         let mut hasher = Sha1::new();
         hasher.input_str(content);
@@ -48,10 +40,24 @@ impl Address {
         }
     }
 
+    /// The `Address` of the node with Ed25519 public key `public_key`: its SHA1 digest.
+    pub fn for_public_key(public_key: &[u8]) -> Address {
+        let mut hasher = Sha1::new();
+        hasher.input(public_key);
+        let mut data = [0; 20];
+        hasher.result(&mut data);
+        Address {
+            data: compact_bytes(&data)
+        }
+    }
+
     /// Creates an `Address` from its numeric representation. Useful for randomly generating
     /// `Address`es within a range.
     pub fn from_numeric(numeric: num::BigUint) -> Address {
-        let data = numeric.to_bytes_be();
+        // Pad to the full length so that each byte lands in the right place
+        let bytes = numeric.to_bytes_be();
+        let mut data = vec![0; (LENGTH / 8).saturating_sub(bytes.len())];
+        data.extend(bytes);
         Address {
             data: compact_bytes(data.as_slice())
         }
@@ -72,6 +78,28 @@ impl Address {
         })
     }
 
+    /// Creates an `Address` from the 20 bytes `to_bytes` gives, most significant first. Returns
+    /// `None` if there aren't exactly 20 of them.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Address> {
+        if bytes.len() != LENGTH / 8 {
+            return None
+        }
+        Some(Address {
+            data: compact_bytes(bytes)
+        })
+    }
+
+    /// Decodes an address field of a protobuf. Addresses are sent as 20 bytes, but used to be sent
+    /// as 40 character hexidecimal strings, which are still accepted from nodes that haven't
+    /// caught up.
+    pub fn from_wire(bytes: &[u8]) -> Option<Address> {
+        use std::str;
+        if bytes.len() == LENGTH / 8 {
+            return Self::from_bytes(bytes)
+        }
+        str::from_utf8(bytes).ok().and_then(|string| Self::from_str(string).ok())
+    }
+
     /// The null `Address`. Use to address "nothing." No `Node`, message, or any `Addressable`
     /// thing should ever reside at the null address. It's useful for bootstrapping a
     /// `network::Network` when one does not know the address of any peers, but has connection
@@ -100,11 +128,29 @@ impl Address {
         num::BigUint::new(self.data.to_vec())
     }
 
-    /// The address space distance of `self` from `other`. Computed as the XOR of their numeric
-    /// representations.
-    pub fn distance_from(&self, other: &Self) -> num::BigUint {
-        use std::ops::BitXor;
-        self.as_numeric().bitxor(other.as_numeric())
+    /// The address space distance of `self` from `other`, the XOR of the two, as a fixed-width
+    /// `Distance` that's cheap to compute and compare. Use it wherever addresses are sorted by
+    /// distance.
+    pub fn xor(&self, other: &Self) -> Distance {
+        let mut distance = [0; 5];
+        // `data` holds the least significant word first, `Distance` the most significant
+        for (place, (a, b)) in distance.iter_mut().rev().zip(self.data.iter().zip(other.data.iter())) {
+            *place = a ^ b;
+        }
+        Distance(distance)
+    }
+
+    /// The 20 bytes of an `Address`, most significant first. Half the size of `to_str`, which is
+    /// why they're what's sent over the wire.
+    pub fn to_bytes(&self) -> [u8; 20] {
+        let mut bytes = [0; 20];
+        // `data` holds the least significant word first
+        for (chunk, word) in bytes.chunks_mut(4).zip(self.data.iter().rev()) {
+            for (i, byte) in chunk.iter_mut().enumerate() {
+                *byte = (word >> (8 * (3 - i))) as u8;
+            }
+        }
+        bytes
     }
 
     /// The string representation of an `Address`. Useful for displaying, exporting outside of
@@ -117,6 +163,30 @@ impl Address {
     }
 }
 
+/// The XOR distance between two `Address`es. Distances compare as the numbers they are.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Distance([u32; 5]);
+
+impl Distance {
+    /// The distance with all but its `bits` most significant bits cleared.
+    pub fn leading(&self, bits: usize) -> Distance {
+        let mut distance = self.0;
+        for (i, word) in distance.iter_mut().enumerate() {
+            let start = i * 32;
+            if bits <= start {
+                *word = 0;
+            } else if bits < start + 32 {
+                *word &= !0u32 << (32 - (bits - start));
+            }
+        }
+        Distance(distance)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+}
+
 /// Anything that needs to be uniquely addressed can implement `Addressable`.
 pub trait Addressable {
 
@@ -141,6 +211,42 @@ mod tests {
     use num;
     use super::{Address, LENGTH};
 
+    #[test]
+    fn test_xor() {
+        let addresses: Vec<Address> = ["alpha", "beta", "gamma", "delta", "epsilon", "zeta"]
+            .iter()
+            .map(|content| Address::for_content(content))
+            .collect();
+        let distance = |a: &Address, b: &Address| a.as_numeric() ^ b.as_numeric();
+        for a in &addresses {
+            assert!(a.xor(a).is_zero());
+            for b in &addresses {
+                for c in &addresses {
+                    assert_eq!(a.xor(b) < a.xor(c), distance(a, b) < distance(a, c));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_leading() {
+        let a = Address::from_str("ffffffffffffffffffffffffffffffffffffffff").unwrap();
+        let b = Address::from_str("0000000000000000000000000000000000000000").unwrap();
+        let distance = a.xor(&b);
+        assert_eq!(distance.leading(160), distance);
+        assert!(distance.leading(0).is_zero());
+        let c = Address::from_str("fffffffff0000000000000000000000000000000").unwrap();
+        assert_eq!(distance.leading(36), c.xor(&b));
+    }
+
+    #[test]
+    fn test_from_short_numeric() {
+        use num::bigint::ToBigUint;
+
+        let address = Address::from_numeric(0x0102030405u64.to_biguint().unwrap());
+        assert_eq!(address.to_str(), "0000000000000000000000000000000102030405");
+    }
+
     #[test]
     fn test_for_content() {
         let address = Address::for_content("some string");
@@ -185,6 +291,25 @@ mod tests {
         assert_eq!(address.as_numeric(), 240u8.to_biguint().unwrap());
     }
 
+    #[test]
+    fn test_bytes() {
+        let address = Address::from_str("8b45e4bd1c6acb88bebf6407d16205f567e62a3e").unwrap();
+        let bytes = address.to_bytes();
+        assert_eq!(&bytes[..4], &[0x8b, 0x45, 0xe4, 0xbd]);
+        assert_eq!(&bytes[16..], &[0x67, 0xe6, 0x2a, 0x3e]);
+        assert_eq!(Address::from_bytes(&bytes), Some(address));
+        assert_eq!(Address::from_bytes(&bytes[..19]), None);
+    }
+
+    #[test]
+    fn test_from_wire() {
+        let address = Address::for_content("some string");
+        assert_eq!(Address::from_wire(&address.to_bytes()), Some(address));
+        assert_eq!(Address::from_wire(address.to_str().as_bytes()), Some(address));
+        assert_eq!(Address::from_wire(b"not hexadecimal"), None);
+        assert_eq!(Address::from_wire(&[0xff; 3]), None);
+    }
+
     #[test]
     fn test_equal() {
         let a = Address::from_str("8b45e4bd1c6acb88bebf6407d16205f567e62a3e").unwrap();
@@ -5,6 +5,7 @@ use num::bigint::ToBigUint;
 
 use address;
 use address::Address;
+use messages::incoming::ParseError;
 
 #[derive(Debug, Clone)]
 pub struct TextMessage {
@@ -70,20 +71,20 @@ impl Envelope {
     pub fn encode(self) -> Vec<u8> {
         use protobuf::Message as MessageForFunctions;
         let mut message = protobufs::Envelope::new();
-        message.set_recipient(self.recipient.to_str());
+        message.set_recipient(self.recipient.to_bytes().to_vec());
 
         match self.message {
             Message::TextMessage(text_message) => {
                 let mut encoded = protobufs::TextMessage::new();
-                encoded.set_id(text_message.id.to_str());
-                encoded.set_sender(text_message.sender.to_str());
+                encoded.set_id(text_message.id.to_bytes().to_vec());
+                encoded.set_sender(text_message.sender.to_bytes().to_vec());
                 encoded.set_text(text_message.text);
                 message.set_message_type(protobufs::Envelope_Type::TEXT_MESSAGE);
                 message.set_text_message(encoded);
             }
             Message::MessageAcknowledgement(message_acknowledgement) => {
                 let mut encoded = protobufs::MessageAcknowledgement::new();
-                encoded.set_message_id(message_acknowledgement.message_id.to_str());
+                encoded.set_message_id(message_acknowledgement.message_id.to_bytes().to_vec());
                 message.set_message_type(protobufs::Envelope_Type::MESSAGE_ACKNOWLEDGEMENT);
                 message.set_message_acknowledgement(encoded);
             }
@@ -93,31 +94,34 @@ impl Envelope {
     }
 }
 
-pub fn decode(data: Vec<u8>) -> Envelope {
+/// Decodes a packet received from the network. Packets can come from anyone, so one that isn't
+/// an envelope or has a malformed address is a `ParseError`, not a panic.
+pub fn decode(data: Vec<u8>) -> Result<Envelope, ParseError> {
     use protobuf;
     use std::io::Cursor;
     let mut data = Cursor::new(data);
-    let envelope = protobuf::parse_from_reader::<protobufs::Envelope>(&mut data).unwrap();
-    match envelope.get_message_type() {
+    let envelope = protobuf::parse_from_reader::<protobufs::Envelope>(&mut data)
+        .map_err(|_| ParseError::Malformed)?;
+    let parse_address = |bytes: &[u8]| Address::from_wire(bytes).ok_or(ParseError::InvalidAddress);
+    let recipient = parse_address(envelope.get_recipient())?;
+    let message = match envelope.get_message_type() {
         protobufs::Envelope_Type::TEXT_MESSAGE => {
             let message = envelope.get_text_message();
-            Envelope {
-                recipient: Address::from_str(envelope.get_recipient()).unwrap(),
-                message: Message::TextMessage(TextMessage {
-                    id: Address::from_str(message.get_id()).unwrap(),
-                    sender: Address::from_str(message.get_sender()).unwrap(),
-                    text: message.get_text().to_string()
-                })
-            }
+            Message::TextMessage(TextMessage {
+                id: parse_address(message.get_id())?,
+                sender: parse_address(message.get_sender())?,
+                text: message.get_text().to_string()
+            })
         }
         protobufs::Envelope_Type::MESSAGE_ACKNOWLEDGEMENT => {
             let ack = envelope.get_message_acknowledgement();
-            Envelope {
-                recipient: Address::from_str(envelope.get_recipient()).unwrap(),
-                message: Message::MessageAcknowledgement(MessageAcknowledgement {
-                    message_id: Address::from_str(ack.get_message_id()).unwrap()
-                })
-            }
+            Message::MessageAcknowledgement(MessageAcknowledgement {
+                message_id: parse_address(ack.get_message_id())?
+            })
         }
-    }
+    };
+    Ok(Envelope {
+        recipient: recipient,
+        message: message
+    })
 }
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
@@ -20,15 +21,15 @@
 use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
-#[derive(Clone,Default)]
+#[derive(PartialEq,Clone,Default)]
 pub struct TextMessage {
     // message fields
-    id: ::protobuf::SingularField<::std::string::String>,
-    sender: ::protobuf::SingularField<::std::string::String>,
+    id: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    sender: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     text: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
@@ -45,19 +46,11 @@ impl TextMessage {
             ptr: 0 as *const TextMessage,
         };
         unsafe {
-            instance.get(|| {
-                TextMessage {
-                    id: ::protobuf::SingularField::none(),
-                    sender: ::protobuf::SingularField::none(),
-                    text: ::protobuf::SingularField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(TextMessage::new)
         }
     }
 
-    // required string id = 1;
+    // required bytes id = 1;
 
     pub fn clear_id(&mut self) {
         self.id.clear();
@@ -68,32 +61,40 @@ impl TextMessage {
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: ::std::string::String) {
+    pub fn set_id(&mut self, v: ::std::vec::Vec<u8>) {
         self.id = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+    pub fn mut_id(&mut self) -> &mut ::std::vec::Vec<u8> {
         if self.id.is_none() {
             self.id.set_default();
-        };
+        }
         self.id.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_id(&mut self) -> ::std::string::String {
-        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_id(&mut self) -> ::std::vec::Vec<u8> {
+        self.id.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_id(&self) -> &str {
+    pub fn get_id(&self) -> &[u8] {
         match self.id.as_ref() {
             Some(v) => &v,
-            None => "",
+            None => &[],
         }
     }
 
-    // required string sender = 2;
+    fn get_id_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.id
+    }
+
+    // required bytes sender = 2;
 
     pub fn clear_sender(&mut self) {
         self.sender.clear();
@@ -104,31 +105,39 @@ impl TextMessage {
     }
 
     // Param is passed by value, moved
-    pub fn set_sender(&mut self, v: ::std::string::String) {
+    pub fn set_sender(&mut self, v: ::std::vec::Vec<u8>) {
         self.sender = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_sender(&mut self) -> &mut ::std::string::String {
+    pub fn mut_sender(&mut self) -> &mut ::std::vec::Vec<u8> {
         if self.sender.is_none() {
             self.sender.set_default();
-        };
+        }
         self.sender.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_sender(&mut self) -> ::std::string::String {
-        self.sender.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_sender(&mut self) -> ::std::vec::Vec<u8> {
+        self.sender.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_sender(&self) -> &str {
+    pub fn get_sender(&self) -> &[u8] {
         match self.sender.as_ref() {
             Some(v) => &v,
-            None => "",
+            None => &[],
         }
     }
 
+    fn get_sender_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.sender
+    }
+
+    fn mut_sender_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.sender
+    }
+
     // required string text = 3;
 
     pub fn clear_text(&mut self) {
@@ -149,7 +158,7 @@ impl TextMessage {
     pub fn mut_text(&mut self) -> &mut ::std::string::String {
         if self.text.is_none() {
             self.text.set_default();
-        };
+        }
         self.text.as_mut().unwrap()
     }
 
@@ -164,37 +173,45 @@ impl TextMessage {
             None => "",
         }
     }
+
+    fn get_text_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.text
+    }
+
+    fn mut_text_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.text
+    }
 }
 
 impl ::protobuf::Message for TextMessage {
     fn is_initialized(&self) -> bool {
         if self.id.is_none() {
             return false;
-        };
+        }
         if self.sender.is_none() {
             return false;
-        };
+        }
         if self.text.is_none() {
             return false;
-        };
+        }
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.id)?;
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.sender));
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.sender)?;
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.text));
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.text)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -205,31 +222,31 @@ impl ::protobuf::Message for TextMessage {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
-        };
-        for value in self.sender.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        for value in self.text.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
+        if let Some(ref v) = self.id.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        if let Some(ref v) = self.sender.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id.as_ref() {
-            try!(os.write_string(1, &v));
-        };
-        if let Some(v) = self.sender.as_ref() {
-            try!(os.write_string(2, &v));
-        };
-        if let Some(v) = self.text.as_ref() {
-            try!(os.write_string(3, &v));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.id.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        if let Some(ref v) = self.sender.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -245,13 +262,15 @@ impl ::protobuf::Message for TextMessage {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<TextMessage>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
@@ -271,20 +290,20 @@ impl ::protobuf::MessageStatic for TextMessage {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "id",
-                    TextMessage::has_id,
-                    TextMessage::get_id,
+                    TextMessage::get_id_for_reflect,
+                    TextMessage::mut_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "sender",
-                    TextMessage::has_sender,
-                    TextMessage::get_sender,
+                    TextMessage::get_sender_for_reflect,
+                    TextMessage::mut_sender_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "text",
-                    TextMessage::has_text,
-                    TextMessage::get_text,
+                    TextMessage::get_text_for_reflect,
+                    TextMessage::mut_text_for_reflect,
                 ));
                 ::protobuf::reflect::MessageDescriptor::new::<TextMessage>(
                     "TextMessage",
@@ -305,28 +324,25 @@ impl ::protobuf::Clear for TextMessage {
     }
 }
 
-impl ::std::cmp::PartialEq for TextMessage {
-    fn eq(&self, other: &TextMessage) -> bool {
-        self.id == other.id &&
-        self.sender == other.sender &&
-        self.text == other.text &&
-        self.unknown_fields == other.unknown_fields
-    }
-}
-
 impl ::std::fmt::Debug for TextMessage {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-#[derive(Clone,Default)]
+impl ::protobuf::reflect::ProtobufValue for TextMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
 pub struct MessageAcknowledgement {
     // message fields
-    message_id: ::protobuf::SingularField<::std::string::String>,
+    message_id: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
@@ -343,17 +359,11 @@ impl MessageAcknowledgement {
             ptr: 0 as *const MessageAcknowledgement,
         };
         unsafe {
-            instance.get(|| {
-                MessageAcknowledgement {
-                    message_id: ::protobuf::SingularField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(MessageAcknowledgement::new)
         }
     }
 
-    // required string message_id = 1;
+    // required bytes message_id = 1;
 
     pub fn clear_message_id(&mut self) {
         self.message_id.clear();
@@ -364,49 +374,57 @@ impl MessageAcknowledgement {
     }
 
     // Param is passed by value, moved
-    pub fn set_message_id(&mut self, v: ::std::string::String) {
+    pub fn set_message_id(&mut self, v: ::std::vec::Vec<u8>) {
         self.message_id = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_message_id(&mut self) -> &mut ::std::string::String {
+    pub fn mut_message_id(&mut self) -> &mut ::std::vec::Vec<u8> {
         if self.message_id.is_none() {
             self.message_id.set_default();
-        };
+        }
         self.message_id.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_message_id(&mut self) -> ::std::string::String {
-        self.message_id.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_message_id(&mut self) -> ::std::vec::Vec<u8> {
+        self.message_id.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_message_id(&self) -> &str {
+    pub fn get_message_id(&self) -> &[u8] {
         match self.message_id.as_ref() {
             Some(v) => &v,
-            None => "",
+            None => &[],
         }
     }
+
+    fn get_message_id_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.message_id
+    }
+
+    fn mut_message_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.message_id
+    }
 }
 
 impl ::protobuf::Message for MessageAcknowledgement {
     fn is_initialized(&self) -> bool {
         if self.message_id.is_none() {
             return false;
-        };
+        }
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.message_id));
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.message_id)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -417,19 +435,19 @@ impl ::protobuf::Message for MessageAcknowledgement {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.message_id.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
-        };
+        if let Some(ref v) = self.message_id.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.message_id.as_ref() {
-            try!(os.write_string(1, &v));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.message_id.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -445,13 +463,15 @@ impl ::protobuf::Message for MessageAcknowledgement {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<MessageAcknowledgement>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
@@ -471,10 +491,10 @@ impl ::protobuf::MessageStatic for MessageAcknowledgement {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "message_id",
-                    MessageAcknowledgement::has_message_id,
-                    MessageAcknowledgement::get_message_id,
+                    MessageAcknowledgement::get_message_id_for_reflect,
+                    MessageAcknowledgement::mut_message_id_for_reflect,
                 ));
                 ::protobuf::reflect::MessageDescriptor::new::<MessageAcknowledgement>(
                     "MessageAcknowledgement",
@@ -493,29 +513,28 @@ impl ::protobuf::Clear for MessageAcknowledgement {
     }
 }
 
-impl ::std::cmp::PartialEq for MessageAcknowledgement {
-    fn eq(&self, other: &MessageAcknowledgement) -> bool {
-        self.message_id == other.message_id &&
-        self.unknown_fields == other.unknown_fields
-    }
-}
-
 impl ::std::fmt::Debug for MessageAcknowledgement {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-#[derive(Clone,Default)]
+impl ::protobuf::reflect::ProtobufValue for MessageAcknowledgement {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
 pub struct Envelope {
     // message fields
     message_type: ::std::option::Option<Envelope_Type>,
-    recipient: ::protobuf::SingularField<::std::string::String>,
+    recipient: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     text_message: ::protobuf::SingularPtrField<TextMessage>,
     message_acknowledgement: ::protobuf::SingularPtrField<MessageAcknowledgement>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
@@ -532,16 +551,7 @@ impl Envelope {
             ptr: 0 as *const Envelope,
         };
         unsafe {
-            instance.get(|| {
-                Envelope {
-                    message_type: ::std::option::Option::None,
-                    recipient: ::protobuf::SingularField::none(),
-                    text_message: ::protobuf::SingularPtrField::none(),
-                    message_acknowledgement: ::protobuf::SingularPtrField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(Envelope::new)
         }
     }
 
@@ -564,7 +574,15 @@ impl Envelope {
         self.message_type.unwrap_or(Envelope_Type::TEXT_MESSAGE)
     }
 
-    // required string recipient = 2;
+    fn get_message_type_for_reflect(&self) -> &::std::option::Option<Envelope_Type> {
+        &self.message_type
+    }
+
+    fn mut_message_type_for_reflect(&mut self) -> &mut ::std::option::Option<Envelope_Type> {
+        &mut self.message_type
+    }
+
+    // required bytes recipient = 2;
 
     pub fn clear_recipient(&mut self) {
         self.recipient.clear();
@@ -575,31 +593,39 @@ impl Envelope {
     }
 
     // Param is passed by value, moved
-    pub fn set_recipient(&mut self, v: ::std::string::String) {
+    pub fn set_recipient(&mut self, v: ::std::vec::Vec<u8>) {
         self.recipient = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_recipient(&mut self) -> &mut ::std::string::String {
+    pub fn mut_recipient(&mut self) -> &mut ::std::vec::Vec<u8> {
         if self.recipient.is_none() {
             self.recipient.set_default();
-        };
+        }
         self.recipient.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_recipient(&mut self) -> ::std::string::String {
-        self.recipient.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_recipient(&mut self) -> ::std::vec::Vec<u8> {
+        self.recipient.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_recipient(&self) -> &str {
+    pub fn get_recipient(&self) -> &[u8] {
         match self.recipient.as_ref() {
             Some(v) => &v,
-            None => "",
+            None => &[],
         }
     }
 
+    fn get_recipient_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.recipient
+    }
+
+    fn mut_recipient_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.recipient
+    }
+
     // optional .TextMessage text_message = 3;
 
     pub fn clear_text_message(&mut self) {
@@ -620,7 +646,7 @@ impl Envelope {
     pub fn mut_text_message(&mut self) -> &mut TextMessage {
         if self.text_message.is_none() {
             self.text_message.set_default();
-        };
+        }
         self.text_message.as_mut().unwrap()
     }
 
@@ -633,6 +659,14 @@ impl Envelope {
         self.text_message.as_ref().unwrap_or_else(|| TextMessage::default_instance())
     }
 
+    fn get_text_message_for_reflect(&self) -> &::protobuf::SingularPtrField<TextMessage> {
+        &self.text_message
+    }
+
+    fn mut_text_message_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<TextMessage> {
+        &mut self.text_message
+    }
+
     // optional .MessageAcknowledgement message_acknowledgement = 4;
 
     pub fn clear_message_acknowledgement(&mut self) {
@@ -653,7 +687,7 @@ impl Envelope {
     pub fn mut_message_acknowledgement(&mut self) -> &mut MessageAcknowledgement {
         if self.message_acknowledgement.is_none() {
             self.message_acknowledgement.set_default();
-        };
+        }
         self.message_acknowledgement.as_mut().unwrap()
     }
 
@@ -665,41 +699,55 @@ impl Envelope {
     pub fn get_message_acknowledgement(&self) -> &MessageAcknowledgement {
         self.message_acknowledgement.as_ref().unwrap_or_else(|| MessageAcknowledgement::default_instance())
     }
+
+    fn get_message_acknowledgement_for_reflect(&self) -> &::protobuf::SingularPtrField<MessageAcknowledgement> {
+        &self.message_acknowledgement
+    }
+
+    fn mut_message_acknowledgement_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<MessageAcknowledgement> {
+        &mut self.message_acknowledgement
+    }
 }
 
 impl ::protobuf::Message for Envelope {
     fn is_initialized(&self) -> bool {
         if self.message_type.is_none() {
             return false;
-        };
+        }
         if self.recipient.is_none() {
             return false;
+        }
+        for v in &self.text_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.message_acknowledgement {
+            if !v.is_initialized() {
+                return false;
+            }
         };
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_enum());
-                    self.message_type = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_proto2_enum_with_unknown_fields_into(wire_type, is, &mut self.message_type, 1, &mut self.unknown_fields)?
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.recipient));
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.recipient)?;
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.text_message));
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.text_message)?;
                 },
                 4 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.message_acknowledgement));
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.message_acknowledgement)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -710,20 +758,20 @@ impl ::protobuf::Message for Envelope {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.message_type.iter() {
-            my_size += ::protobuf::rt::enum_size(1, *value);
-        };
-        for value in self.recipient.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        for value in self.text_message.iter() {
-            let len = value.compute_size();
+        if let Some(v) = self.message_type {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        }
+        if let Some(ref v) = self.recipient.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        if let Some(ref v) = self.text_message.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.message_acknowledgement.iter() {
-            let len = value.compute_size();
+        }
+        if let Some(ref v) = self.message_acknowledgement.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -731,22 +779,22 @@ impl ::protobuf::Message for Envelope {
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
         if let Some(v) = self.message_type {
-            try!(os.write_enum(1, v.value()));
-        };
-        if let Some(v) = self.recipient.as_ref() {
-            try!(os.write_string(2, &v));
-        };
-        if let Some(v) = self.text_message.as_ref() {
-            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.message_acknowledgement.as_ref() {
-            try!(os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+            os.write_enum(1, v.value())?;
+        }
+        if let Some(ref v) = self.recipient.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        if let Some(ref v) = self.text_message.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.message_acknowledgement.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -762,13 +810,15 @@ impl ::protobuf::Message for Envelope {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Envelope>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
@@ -788,25 +838,25 @@ impl ::protobuf::MessageStatic for Envelope {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Envelope_Type>>(
                     "message_type",
-                    Envelope::has_message_type,
-                    Envelope::get_message_type,
+                    Envelope::get_message_type_for_reflect,
+                    Envelope::mut_message_type_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "recipient",
-                    Envelope::has_recipient,
-                    Envelope::get_recipient,
+                    Envelope::get_recipient_for_reflect,
+                    Envelope::mut_recipient_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TextMessage>>(
                     "text_message",
-                    Envelope::has_text_message,
-                    Envelope::get_text_message,
+                    Envelope::get_text_message_for_reflect,
+                    Envelope::mut_text_message_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MessageAcknowledgement>>(
                     "message_acknowledgement",
-                    Envelope::has_message_acknowledgement,
-                    Envelope::get_message_acknowledgement,
+                    Envelope::get_message_acknowledgement_for_reflect,
+                    Envelope::mut_message_acknowledgement_for_reflect,
                 ));
                 ::protobuf::reflect::MessageDescriptor::new::<Envelope>(
                     "Envelope",
@@ -828,22 +878,18 @@ impl ::protobuf::Clear for Envelope {
     }
 }
 
-impl ::std::cmp::PartialEq for Envelope {
-    fn eq(&self, other: &Envelope) -> bool {
-        self.message_type == other.message_type &&
-        self.recipient == other.recipient &&
-        self.text_message == other.text_message &&
-        self.message_acknowledgement == other.message_acknowledgement &&
-        self.unknown_fields == other.unknown_fields
-    }
-}
-
 impl ::std::fmt::Debug for Envelope {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
+impl ::protobuf::reflect::ProtobufValue for Envelope {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum Envelope_Type {
     TEXT_MESSAGE = 1,
@@ -871,7 +917,7 @@ impl ::protobuf::ProtobufEnum for Envelope_Type {
         values
     }
 
-    fn enum_descriptor_static(_: Option<Envelope_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+    fn enum_descriptor_static(_: ::std::option::Option<Envelope_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
@@ -887,84 +933,64 @@ impl ::protobuf::ProtobufEnum for Envelope_Type {
 impl ::std::marker::Copy for Envelope_Type {
 }
 
-static file_descriptor_proto_data: &'static [u8] = &[
-    0x0a, 0x23, 0x73, 0x72, 0x63, 0x2f, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x2f, 0x6d, 0x65, 0x73,
-    0x73, 0x61, 0x67, 0x65, 0x73, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x73, 0x2e,
-    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x49, 0x0a, 0x0b, 0x54, 0x65, 0x78, 0x74, 0x4d, 0x65, 0x73,
-    0x73, 0x61, 0x67, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09,
-    0x52, 0x02, 0x69, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x18, 0x02,
-    0x20, 0x02, 0x28, 0x09, 0x52, 0x06, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x12, 0x12, 0x0a, 0x04,
-    0x74, 0x65, 0x78, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x52, 0x04, 0x74, 0x65, 0x78, 0x74,
-    0x22, 0x37, 0x0a, 0x16, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x41, 0x63, 0x6b, 0x6e, 0x6f,
-    0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x6d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x09,
-    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x49, 0x64, 0x22, 0x95, 0x02, 0x0a, 0x08, 0x45, 0x6e,
-    0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x31, 0x0a, 0x0c, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
-    0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0e, 0x2e, 0x45,
-    0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x0b, 0x6d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x72, 0x65, 0x63,
-    0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x52, 0x09, 0x72, 0x65,
-    0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x12, 0x2f, 0x0a, 0x0c, 0x74, 0x65, 0x78, 0x74, 0x5f,
-    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e,
-    0x54, 0x65, 0x78, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x52, 0x0b, 0x74, 0x65, 0x78,
-    0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x50, 0x0a, 0x17, 0x6d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x5f, 0x61, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x6d,
-    0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x4d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x6d, 0x65,
-    0x6e, 0x74, 0x52, 0x16, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x41, 0x63, 0x6b, 0x6e, 0x6f,
-    0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x22, 0x35, 0x0a, 0x04, 0x54, 0x79,
-    0x70, 0x65, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x45, 0x58, 0x54, 0x5f, 0x4d, 0x45, 0x53, 0x53, 0x41,
-    0x47, 0x45, 0x10, 0x01, 0x12, 0x1b, 0x0a, 0x17, 0x4d, 0x45, 0x53, 0x53, 0x41, 0x47, 0x45, 0x5f,
-    0x41, 0x43, 0x4b, 0x4e, 0x4f, 0x57, 0x4c, 0x45, 0x44, 0x47, 0x45, 0x4d, 0x45, 0x4e, 0x54, 0x10,
-    0x02, 0x4a, 0xf2, 0x05, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x00, 0x12, 0x04, 0x00, 0x00, 0x04, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
-    0x03, 0x00, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x01, 0x04,
-    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x01, 0x04, 0x0c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x01, 0x0d, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x01, 0x14, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x01, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x12, 0x03, 0x02, 0x04, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x02, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x02,
-    0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x02, 0x14, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x02, 0x1d, 0x1e, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x03, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x03, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x03, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x03, 0x14, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x03, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x06, 0x08, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x01, 0x02, 0x00, 0x12, 0x03, 0x07, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x07, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x07, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07,
-    0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x21, 0x22,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x0a, 0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x02, 0x04, 0x00,
-    0x12, 0x04, 0x0b, 0x04, 0x0e, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x04, 0x00, 0x01, 0x12,
-    0x03, 0x0b, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x0c, 0x08, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x0c, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
-    0x0c, 0x17, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0d,
-    0x08, 0x24, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d,
-    0x08, 0x1f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0d,
-    0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x04, 0x23, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x10, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x10, 0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x10, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x11, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x11,
-    0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x11, 0x0d, 0x13,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11, 0x14, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x11, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x12, 0x04, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x12, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x06,
-    0x12, 0x03, 0x12, 0x0d, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x12, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x12, 0x28,
-    0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x13, 0x04, 0x40, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x04, 0x12, 0x03, 0x13, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x03, 0x06, 0x12, 0x03, 0x13, 0x0d, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x03, 0x01, 0x12, 0x03, 0x13, 0x24, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03,
-    0x03, 0x12, 0x03, 0x13, 0x3e, 0x3f,
-];
+impl ::protobuf::reflect::ProtobufValue for Envelope_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n#src/client/messages/protobufs.proto\"I\n\x0bTextMessage\x12\x0e\n\x02\
+    id\x18\x01\x20\x02(\x0cR\x02id\x12\x16\n\x06sender\x18\x02\x20\x02(\x0cR\
+    \x06sender\x12\x12\n\x04text\x18\x03\x20\x02(\tR\x04text\"7\n\x16Message\
+    Acknowledgement\x12\x1d\n\nmessage_id\x18\x01\x20\x02(\x0cR\tmessageId\"\
+    \x95\x02\n\x08Envelope\x121\n\x0cmessage_type\x18\x01\x20\x02(\x0e2\x0e.\
+    Envelope.TypeR\x0bmessageType\x12\x1c\n\trecipient\x18\x02\x20\x02(\x0cR\
+    \trecipient\x12/\n\x0ctext_message\x18\x03\x20\x01(\x0b2\x0c.TextMessage\
+    R\x0btextMessage\x12P\n\x17message_acknowledgement\x18\x04\x20\x01(\x0b2\
+    \x17.MessageAcknowledgementR\x16messageAcknowledgement\"5\n\x04Type\x12\
+    \x10\n\x0cTEXT_MESSAGE\x10\x01\x12\x1b\n\x17MESSAGE_ACKNOWLEDGEMENT\x10\
+    \x02J\xd0\x06\n\x06\x12\x04\x01\0\x15\x01\nh\n\x02\x04\0\x12\x04\x01\0\
+    \x05\x01\x1a\\\x20Addresses\x20are\x2020\x20bytes.\x20They\x20used\x20to\
+    \x20be\x2040\x20character\x20hex\x20strings,\x20which\x20are\x20still\
+    \x20decoded.\n\n\n\n\x03\x04\0\x01\x12\x03\x01\x08\x13\n\x0b\n\x04\x04\0\
+    \x02\0\x12\x03\x02\x04\x1a\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x02\x04\
+    \x0c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x02\r\x12\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x02\x13\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x02\x18\
+    \x19\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x03\x04\x1e\n\x0c\n\x05\x04\0\x02\
+    \x01\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x03\r\
+    \x12\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x03\x13\x19\n\x0c\n\x05\x04\0\
+    \x02\x01\x03\x12\x03\x03\x1c\x1d\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x04\
+    \x04\x1d\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\x04\x04\x0c\n\x0c\n\x05\
+    \x04\0\x02\x02\x05\x12\x03\x04\r\x13\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
+    \x03\x04\x14\x18\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x04\x1b\x1c\n\n\n\
+    \x02\x04\x01\x12\x04\x07\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\
+    \x1e\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\"\n\x0c\n\x05\x04\x01\x02\
+    \0\x04\x12\x03\x08\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\r\
+    \x12\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x13\x1d\n\x0c\n\x05\x04\
+    \x01\x02\0\x03\x12\x03\x08\x20!\n\n\n\x02\x04\x02\x12\x04\x0b\0\x15\x01\
+    \n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x10\n\x0c\n\x04\x04\x02\x04\0\x12\
+    \x04\x0c\x04\x0f\x05\n\x0c\n\x05\x04\x02\x04\0\x01\x12\x03\x0c\t\r\n\r\n\
+    \x06\x04\x02\x04\0\x02\0\x12\x03\r\x08\x19\n\x0e\n\x07\x04\x02\x04\0\x02\
+    \0\x01\x12\x03\r\x08\x14\n\x0e\n\x07\x04\x02\x04\0\x02\0\x02\x12\x03\r\
+    \x17\x18\n\r\n\x06\x04\x02\x04\0\x02\x01\x12\x03\x0e\x08$\n\x0e\n\x07\
+    \x04\x02\x04\0\x02\x01\x01\x12\x03\x0e\x08\x1f\n\x0e\n\x07\x04\x02\x04\0\
+    \x02\x01\x02\x12\x03\x0e\"#\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x11\x04#\n\
+    \x0c\n\x05\x04\x02\x02\0\x04\x12\x03\x11\x04\x0c\n\x0c\n\x05\x04\x02\x02\
+    \0\x06\x12\x03\x11\r\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x11\x12\
+    \x1e\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x11!\"\n\x0b\n\x04\x04\x02\
+    \x02\x01\x12\x03\x12\x04!\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x12\
+    \x04\x0c\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x12\r\x12\n\x0c\n\x05\
+    \x04\x02\x02\x01\x01\x12\x03\x12\x13\x1c\n\x0c\n\x05\x04\x02\x02\x01\x03\
+    \x12\x03\x12\x1f\x20\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x13\x04*\n\x0c\
+    \n\x05\x04\x02\x02\x02\x04\x12\x03\x13\x04\x0c\n\x0c\n\x05\x04\x02\x02\
+    \x02\x06\x12\x03\x13\r\x18\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x13\
+    \x19%\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x13()\n\x0b\n\x04\x04\x02\
+    \x02\x03\x12\x03\x14\x04@\n\x0c\n\x05\x04\x02\x02\x03\x04\x12\x03\x14\
+    \x04\x0c\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x14\r#\n\x0c\n\x05\x04\
+    \x02\x02\x03\x01\x12\x03\x14$;\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\
+    \x14>?\
+";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
     lock: ::protobuf::lazy::ONCE_INIT,
@@ -4,6 +4,7 @@ use address::Address;
 use mio;
 use network;
 use self::messages::{Message, TextMessage, MessageAcknowledgement, Envelope};
+use snapshot::RoutingTableSnapshot;
 use std::collections::{HashMap, HashSet};
 use std::sync::mpsc;
 use std::thread;
@@ -18,6 +19,17 @@ pub enum Task {
     /// Schedules a message to be delivered.
     ScheduleMessageDelivery(Address, TextMessage),
 
+    /// Asks the `Network` for a snapshot of its routing table, which it replies with.
+    SnapshotRoutingTable(mpsc::Sender<RoutingTableSnapshot>),
+
+    /// Has the `Network` look up the nodes nearest to an address, adding them to its routing
+    /// table.
+    FindNode(Address),
+
+    /// Has the `Network` punch a hole through the NATs between us and the node at an address,
+    /// by way of a mutual peer.
+    PunchHole(Address),
+
     /// Shuts down the `Client`. When it has completed the shutdown procedure, it will emit an
     /// `Event::Shutdown`.
     Shutdown
@@ -47,12 +59,20 @@ pub enum Event {
 
     /// The client has shut down
     ///
-    /// TODO: It might be useful for `Shutdown` to contain the serialized state of the entire
-    /// `Client`, `Network`, `RoutingTable`, `Bucket`s and `Nodes` so that a user may store it and
-    /// later re-initialize a `Client` with this state in order to prevent re-bootstrapping from
-    /// scratch.
+    /// The `Network`'s routing table is saved on shutdown if it was told where to with
+    /// `Network::save_routing_table_to`, so that it needn't bootstrap from scratch next time.
+    ///
+    /// TODO: It might be useful for `Shutdown` to contain the serialized state of the `Client`
+    /// too, such as messages still waiting to be delivered.
     Shutdown,
 
+    /// `Network` has joined the network, having found nodes near us.
+    Bootstrapped,
+
+    /// `Network` couldn't join the network through any of its routers. It tries again as soon as
+    /// any node contacts it.
+    BootstrapFailed,
+
     /// `Network` has started running. This does not necessary guarantee that we are "connected" to
     /// the network, because we haven't necessarily sent/received any messages. It just indicates
     /// that the `Network` is open for business.
@@ -130,7 +150,13 @@ impl Client {
     fn handle_networking_event(&mut self, event: network::Event, event_loop: &mut mio::EventLoop<Client>) {
         match event {
             network::Event::ReceivedPacket(sender, data) => {
-                let envelope = messages::decode(data);
+                let envelope = match messages::decode(data) {
+                    Ok(envelope) => envelope,
+                    Err(error) => {
+                        debug!("Dropping packet from {}: {}", &sender, error);
+                        return
+                    }
+                };
                 let Envelope { recipient, .. } = envelope;
 
                 match envelope.message {
@@ -171,6 +197,28 @@ impl Client {
                 }
             }
 
+            network::Event::Bootstrapped => {
+                debug!("Received Bootstrapped signal from Network");
+                self.broadcast_event(Event::Bootstrapped);
+            }
+
+            network::Event::BootstrapFailed => {
+                debug!("Received BootstrapFailed signal from Network");
+                self.broadcast_event(Event::BootstrapFailed);
+            }
+
+            network::Event::HolePunched(address) => {
+                debug!("Punched a hole to {}", address);
+            }
+
+            network::Event::LookupFinished(target, nearest) => {
+                debug!("Found {:?} nearest to {}", nearest, target);
+            }
+
+            network::Event::RegisteredWithRelay(address) => {
+                debug!("Registered with relay {}", address);
+            }
+
             network::Event::Shutdown => {
                 debug!("Received Shutdown signal from Network");
                 event_loop.shutdown();
@@ -225,6 +273,24 @@ impl Client {
         }
     }
 
+    fn snapshot_routing_table(&self, reply: mpsc::Sender<RoutingTableSnapshot>) {
+        if let Some(ref commands) = self.network_commands {
+            commands.send(network::OneshotTask::SnapshotRoutingTable(reply)).unwrap();
+        }
+    }
+
+    fn find_node(&self, target: Address) {
+        if let Some(ref commands) = self.network_commands {
+            commands.send(network::OneshotTask::FindNode(target)).unwrap();
+        }
+    }
+
+    fn punch_hole(&self, target: Address) {
+        if let Some(ref commands) = self.network_commands {
+            commands.send(network::OneshotTask::PunchHole(target)).unwrap();
+        }
+    }
+
     fn shutdown(&self, _event_loop: &mut mio::EventLoop<Client>) {
         debug!("Received Shutdown command from user");
         if let Some(ref commands) = self.network_commands {
@@ -241,6 +307,9 @@ impl mio::Handler for Client {
         match task {
             Task::HandleNetworkEvent(event) => self.handle_networking_event(event, event_loop),
             Task::ScheduleMessageDelivery(recipient, message) => self.schedule_message_delivery(recipient, message, event_loop),
+            Task::SnapshotRoutingTable(reply) => self.snapshot_routing_table(reply),
+            Task::FindNode(target) => self.find_node(target),
+            Task::PunchHole(target) => self.punch_hole(target),
             Task::Shutdown => self.shutdown(event_loop)
         }
     }
@@ -0,0 +1,342 @@
+use address::Address;
+use std::collections::HashMap;
+use time;
+use transaction::TransactionId;
+
+/// The most bytes of a payload carried by one packet query. Larger payloads are split into
+/// fragments of this size, so that each query still fits in a datagram on a typical 1500 byte
+/// MTU.
+pub const FRAGMENT_SIZE: usize = 1024;
+
+/// The largest payload we send or put back together, unless told otherwise.
+pub const DEFAULT_MAX_PAYLOAD_SIZE: usize = 1 << 20;
+
+/// Fragments of at most this many payloads' worth of bytes are held on to at once. When more
+/// arrive, the payloads we started putting back together first are given up on.
+pub const MAX_BUFFERED_PAYLOADS: usize = 4;
+
+/// How many payloads from one node are put back together at once. When another is started, the
+/// node's payload started longest ago is given up on.
+pub const MAX_PAYLOADS_PER_SENDER: usize = 4;
+
+/// How many payloads are put back together at once, from all nodes. Each holds a slot for every
+/// one of its fragments, however few have arrived, so they're limited as well as their bytes.
+pub const MAX_PAYLOADS: usize = 64;
+
+/// Identifies the payload a fragment is part of, among those from the same sender. They're drawn
+/// from the sender's transaction IDs.
+pub type FragmentId = TransactionId;
+
+/// Where a packet query's payload belongs in the larger payload it's a part of.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fragment {
+    pub id: FragmentId,
+    pub index: usize,
+    pub count: usize
+}
+
+/// The number of fragments `size` bytes are split into.
+pub fn fragment_count(size: usize) -> usize {
+    (size + FRAGMENT_SIZE - 1) / FRAGMENT_SIZE
+}
+
+/// What became of a fragment handed to `Reassembler::insert`.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Reassembly {
+    /// It's the first of its payload's fragments to arrive. It's held on to until the rest
+    /// arrive or the payload is given up on with `Reassembler::expire`.
+    Started,
+
+    /// It's held on to until the rest of its payload's fragments arrive.
+    Buffered,
+
+    /// It was the last one missing, and this is the whole payload.
+    Complete(Vec<u8>),
+
+    /// It was dropped, for the reason given.
+    Dropped(&'static str)
+}
+
+/// A payload some of whose fragments have arrived.
+struct Partial {
+    fragments: Vec<Option<Vec<u8>>>,
+    missing: usize,
+    size: usize,
+    sequence: u64,
+    started: time::Tm
+}
+
+/// Puts the fragments of payloads too large for one datagram back together. Fragments can come
+/// from anyone, so how large a payload is, and how many payloads and bytes of fragments are held on
+/// to, are all limited.
+pub struct Reassembler {
+    max_payload_size: usize,
+    payloads: HashMap<(Address, FragmentId), Partial>,
+    buffered: usize,
+    next_sequence: u64
+}
+
+impl Reassembler {
+    pub fn new(max_payload_size: usize) -> Reassembler {
+        Reassembler {
+            max_payload_size: max_payload_size,
+            payloads: HashMap::new(),
+            buffered: 0,
+            next_sequence: 0
+        }
+    }
+
+    pub fn max_payload_size(&self) -> usize {
+        self.max_payload_size
+    }
+
+    pub fn set_max_payload_size(&mut self, max_payload_size: usize) {
+        self.max_payload_size = max_payload_size;
+    }
+
+    /// Adds `data`, the `fragment` of a payload from `sender`.
+    pub fn insert(&mut self, sender: Address, fragment: Fragment, data: Vec<u8>) -> Reassembly {
+        if fragment.index >= fragment.count {
+            return Reassembly::Dropped("Fragment index out of range");
+        }
+        if data.len() > FRAGMENT_SIZE {
+            return Reassembly::Dropped("Fragment too large");
+        }
+        // Otherwise a payload could hold a slot for every fragment while barely growing
+        if data.is_empty() {
+            return Reassembly::Dropped("Empty fragment");
+        }
+        if fragment.index + 1 < fragment.count && data.len() < FRAGMENT_SIZE {
+            return Reassembly::Dropped("Only the last fragment may be short");
+        }
+        if fragment.count > fragment_count(self.max_payload_size) {
+            return Reassembly::Dropped("Payload too large");
+        }
+        if fragment.count == 1 {
+            return Reassembly::Complete(data);
+        }
+
+        let key = (sender, fragment.id);
+        let started = !self.payloads.contains_key(&key);
+        if started {
+            self.make_room_for_payload(sender);
+            self.payloads.insert(key, Partial {
+                fragments: vec![None; fragment.count],
+                missing: fragment.count,
+                size: 0,
+                sequence: self.next_sequence,
+                started: time::now_utc()
+            });
+            self.next_sequence += 1;
+        }
+
+        let (complete, size) = {
+            let partial = &self.payloads[&key];
+            if partial.fragments.len() != fragment.count {
+                return Reassembly::Dropped("Fragment count doesn't match the payload's");
+            }
+            if partial.fragments[fragment.index].is_some() {
+                return Reassembly::Dropped("Duplicate fragment");
+            }
+            (partial.missing == 1, partial.size + data.len())
+        };
+        if size > self.max_payload_size {
+            self.remove(&key);
+            return Reassembly::Dropped("Payload too large");
+        }
+        if !complete {
+            self.make_room_for_bytes(data.len(), &key);
+        }
+
+        self.buffered += data.len();
+        let partial = self.payloads.get_mut(&key).unwrap();
+        partial.fragments[fragment.index] = Some(data);
+        partial.missing -= 1;
+        partial.size = size;
+        if complete {
+            let partial = self.remove(&key).unwrap();
+            let mut payload = Vec::with_capacity(partial.size);
+            for data in partial.fragments.into_iter().flatten() {
+                payload.extend(data);
+            }
+            Reassembly::Complete(payload)
+        } else if started {
+            Reassembly::Started
+        } else {
+            Reassembly::Buffered
+        }
+    }
+
+    /// Whether any payloads are still missing fragments.
+    pub fn is_reassembling(&self) -> bool {
+        !self.payloads.is_empty()
+    }
+
+    /// Gives up on the payloads whose first fragment arrived more than `timeout` ago, and returns
+    /// how many there were.
+    pub fn expire(&mut self, timeout: time::Duration) -> usize {
+        let deadline = time::now_utc() - timeout;
+        let expired: Vec<(Address, FragmentId)> = self.payloads
+            .iter()
+            .filter(|&(_, partial)| partial.started <= deadline)
+            .map(|(key, _)| *key)
+            .collect();
+        for key in &expired {
+            debug!("Gave up on reassembling payload {} from {}", key.1, key.0);
+            self.remove(key);
+        }
+        expired.len()
+    }
+
+    fn remove(&mut self, key: &(Address, FragmentId)) -> Option<Partial> {
+        let partial = self.payloads.remove(key)?;
+        self.buffered -= partial.size;
+        Some(partial)
+    }
+
+    /// The payload started longest ago of those `filter` accepts.
+    fn oldest<F>(&self, filter: F) -> Option<(Address, FragmentId)>
+        where F: Fn(&(Address, FragmentId)) -> bool {
+        self.payloads
+            .iter()
+            .filter(|&(key, _)| filter(key))
+            .min_by_key(|&(_, partial)| partial.sequence)
+            .map(|(key, _)| *key)
+    }
+
+    fn give_up_on(&mut self, key: &(Address, FragmentId)) {
+        debug!("Giving up on reassembling payload {} from {} to make room", key.1, key.0);
+        self.remove(key);
+    }
+
+    /// Gives up on the payloads started longest ago, from `sender` and then from anyone, until
+    /// another can be started without going over `MAX_PAYLOADS_PER_SENDER` or `MAX_PAYLOADS`.
+    fn make_room_for_payload(&mut self, sender: Address) {
+        while self.payloads.keys().filter(|key| key.0 == sender).count() >= MAX_PAYLOADS_PER_SENDER {
+            match self.oldest(|key| key.0 == sender) {
+                Some(key) => self.give_up_on(&key),
+                None => break
+            }
+        }
+        while self.payloads.len() >= MAX_PAYLOADS {
+            match self.oldest(|_| true) {
+                Some(key) => self.give_up_on(&key),
+                None => break
+            }
+        }
+    }
+
+    /// Gives up on the payloads started longest ago, other than `keep`, until there's room for
+    /// `size` more bytes.
+    fn make_room_for_bytes(&mut self, size: usize, keep: &(Address, FragmentId)) {
+        let limit = self.max_payload_size.saturating_mul(MAX_BUFFERED_PAYLOADS);
+        while self.buffered + size > limit {
+            match self.oldest(|key| key != keep) {
+                Some(key) => self.give_up_on(&key),
+                None => break
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use address::Address;
+    use super::{Fragment, Reassembler, Reassembly, FRAGMENT_SIZE, MAX_BUFFERED_PAYLOADS,
+                MAX_PAYLOADS, MAX_PAYLOADS_PER_SENDER};
+    use time;
+
+    fn fragment(id: u32, index: usize, count: usize) -> Fragment {
+        Fragment {
+            id: id,
+            index: index,
+            count: count
+        }
+    }
+
+    #[test]
+    fn test_reassembles_out_of_order() {
+        let sender = Address::for_content("alpha");
+        let mut reassembler = Reassembler::new(1 << 20);
+        assert_eq!(reassembler.insert(sender, fragment(1, 2, 3), b"baz".to_vec()), Reassembly::Started);
+        assert_eq!(reassembler.insert(sender, fragment(1, 0, 3), vec![1; FRAGMENT_SIZE]), Reassembly::Buffered);
+        assert_eq!(reassembler.insert(sender, fragment(1, 0, 3), vec![1; FRAGMENT_SIZE]), Reassembly::Dropped("Duplicate fragment"));
+        assert_eq!(reassembler.buffered, FRAGMENT_SIZE + 3);
+
+        // Payloads from another sender are kept apart, even with the same ID
+        let other = Address::for_content("beta");
+        assert_eq!(reassembler.insert(other, fragment(1, 2, 3), b"qux".to_vec()), Reassembly::Started);
+
+        let mut payload = vec![1; FRAGMENT_SIZE];
+        payload.extend(vec![2; FRAGMENT_SIZE]);
+        payload.extend(b"baz");
+        assert_eq!(reassembler.insert(sender, fragment(1, 1, 3), vec![2; FRAGMENT_SIZE]), Reassembly::Complete(payload));
+        assert_eq!(reassembler.buffered, 3);
+    }
+
+    #[test]
+    fn test_drops_fragments_that_dont_fit() {
+        let sender = Address::for_content("alpha");
+        let mut reassembler = Reassembler::new(4 * FRAGMENT_SIZE);
+        assert_eq!(reassembler.insert(sender, fragment(1, 3, 3), vec![1]), Reassembly::Dropped("Fragment index out of range"));
+        assert_eq!(reassembler.insert(sender, fragment(1, 0, 3), vec![0; FRAGMENT_SIZE + 1]), Reassembly::Dropped("Fragment too large"));
+        assert_eq!(reassembler.insert(sender, fragment(1, 2, 3), vec![]), Reassembly::Dropped("Empty fragment"));
+        assert_eq!(reassembler.insert(sender, fragment(1, 1, 3), vec![1]), Reassembly::Dropped("Only the last fragment may be short"));
+        assert_eq!(reassembler.insert(sender, fragment(1, 0, 5), vec![0; FRAGMENT_SIZE]), Reassembly::Dropped("Payload too large"));
+        assert_eq!(reassembler.insert(sender, fragment(1, 2, 3), vec![1]), Reassembly::Started);
+        assert_eq!(reassembler.insert(sender, fragment(1, 3, 4), vec![1]), Reassembly::Dropped("Fragment count doesn't match the payload's"));
+        assert_eq!(reassembler.insert(sender, fragment(2, 0, 1), b"whole".to_vec()), Reassembly::Complete(b"whole".to_vec()));
+    }
+
+    #[test]
+    fn test_expire() {
+        let sender = Address::for_content("alpha");
+        let mut reassembler = Reassembler::new(1 << 20);
+        reassembler.insert(sender, fragment(1, 0, 2), vec![0; FRAGMENT_SIZE]);
+        assert_eq!(reassembler.expire(time::Duration::seconds(10)), 0);
+        assert!(reassembler.is_reassembling());
+        assert_eq!(reassembler.expire(time::Duration::zero()), 1);
+        assert!(!reassembler.is_reassembling());
+        assert_eq!(reassembler.buffered, 0);
+
+        // What's left of the payload starts it over
+        assert_eq!(reassembler.insert(sender, fragment(1, 1, 2), b"bar".to_vec()), Reassembly::Started);
+    }
+
+    #[test]
+    fn test_gives_up_on_oldest_payloads_when_full() {
+        let mut reassembler = Reassembler::new(2 * FRAGMENT_SIZE);
+        // The first half of twice as many payloads as fit fills the buffer, and one more overflows it
+        let last = 2 * MAX_BUFFERED_PAYLOADS;
+        let senders: Vec<Address> = (0..last + 1).map(|i| Address::for_content(&i.to_string())).collect();
+        for sender in &senders {
+            reassembler.insert(*sender, fragment(1, 0, 2), vec![0; FRAGMENT_SIZE]);
+        }
+        assert_eq!(reassembler.buffered, 2 * MAX_BUFFERED_PAYLOADS * FRAGMENT_SIZE);
+        assert!(!reassembler.payloads.contains_key(&(senders[0], 1)));
+        assert!(reassembler.payloads.contains_key(&(senders[1], 1)));
+        assert!(reassembler.payloads.contains_key(&(senders[last], 1)));
+    }
+
+    #[test]
+    fn test_limits_payloads_started_with_tiny_fragments() {
+        let mut reassembler = Reassembler::new(1 << 20);
+        let count = super::fragment_count(1 << 20);
+
+        // Each last fragment starts a payload with a slot for every one of its fragments
+        let sender = Address::for_content("alpha");
+        for id in 0..1000 {
+            reassembler.insert(sender, fragment(id, count - 1, count), vec![0]);
+        }
+        assert_eq!(reassembler.payloads.len(), MAX_PAYLOADS_PER_SENDER);
+        assert!(reassembler.payloads.contains_key(&(sender, 999)));
+
+        for i in 0..1000 {
+            let sender = Address::for_content(&i.to_string());
+            reassembler.insert(sender, fragment(1, count - 1, count), vec![0]);
+        }
+        assert_eq!(reassembler.payloads.len(), MAX_PAYLOADS);
+        assert!(reassembler.buffered <= MAX_PAYLOADS);
+        assert!(reassembler.payloads.contains_key(&(Address::for_content("999"), 1)));
+    }
+}
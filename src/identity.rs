@@ -0,0 +1,100 @@
+use address::Address;
+use crypto::digest::Digest;
+use crypto::ed25519;
+use crypto::sha2::Sha256;
+use std::fmt;
+
+/// The length of an Ed25519 public key in bytes.
+pub const PUBLIC_KEY_LENGTH: usize = 32;
+
+/// The length of an Ed25519 signature in bytes.
+pub const SIGNATURE_LENGTH: usize = 64;
+
+/// A node's Ed25519 key pair. The node's address is derived from its public key, so nobody can
+/// claim an address without holding the secret key that goes with it, and the node signs every
+/// query and response it sends to prove it.
+#[derive(Clone)]
+pub struct Identity {
+    secret_key: [u8; 64],
+    public_key: [u8; PUBLIC_KEY_LENGTH]
+}
+
+impl Identity {
+    /// The identity whose key pair is generated from the 32 byte `seed`.
+    pub fn from_seed(seed: &[u8; 32]) -> Identity {
+        let (secret_key, public_key) = ed25519::keypair(seed);
+        Identity {
+            secret_key: secret_key,
+            public_key: public_key
+        }
+    }
+
+    /// The identity for `secret`, which is always the same one for the same secret.
+    pub fn for_secret(secret: &str) -> Identity {
+        let mut hasher = Sha256::new();
+        hasher.input_str(secret);
+        let mut seed = [0; 32];
+        hasher.result(&mut seed);
+        Identity::from_seed(&seed)
+    }
+
+    /// A new identity with a randomly generated key pair.
+    pub fn generate() -> Identity {
+        use rand::{OsRng, Rng};
+
+        let mut seed = [0; 32];
+        OsRng::new().expect("Couldn't open the OS random number generator").fill_bytes(&mut seed);
+        Identity::from_seed(&seed)
+    }
+
+    pub fn address(&self) -> Address {
+        Address::for_public_key(&self.public_key)
+    }
+
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; SIGNATURE_LENGTH] {
+        ed25519::signature(message, &self.secret_key)
+    }
+}
+
+impl fmt::Debug for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Identity {{ {} }}", self.address())
+    }
+}
+
+/// Whether `signature` is `public_key`'s over `message`.
+pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    public_key.len() == PUBLIC_KEY_LENGTH &&
+        signature.len() == SIGNATURE_LENGTH &&
+        ed25519::verify(message, public_key, signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use address::Address;
+    use super::{Identity, verify};
+
+    #[test]
+    fn test_for_secret() {
+        let alpha = Identity::for_secret("alpha");
+        assert_eq!(alpha.public_key(), Identity::for_secret("alpha").public_key());
+        assert!(alpha.public_key() != Identity::for_secret("beta").public_key());
+        assert_eq!(alpha.address(), Address::for_public_key(alpha.public_key()));
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let alpha = Identity::generate();
+        let beta = Identity::generate();
+        let signature = alpha.sign(b"hello");
+        assert!(verify(alpha.public_key(), b"hello", &signature));
+        assert!(!verify(alpha.public_key(), b"goodbye", &signature));
+        assert!(!verify(beta.public_key(), b"hello", &signature));
+        assert!(!verify(&alpha.public_key()[1..], b"hello", &signature));
+        assert!(!verify(alpha.public_key(), b"hello", &signature[1..]));
+    }
+}
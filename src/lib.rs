@@ -1,8 +1,11 @@
 extern crate env_logger;
 extern crate crypto;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 extern crate log;
 extern crate mio;
+extern crate net2;
 extern crate num;
 extern crate protobuf;
 extern crate rand;
@@ -11,11 +14,17 @@ extern crate time;
 
 pub mod address;
 pub mod client;
+pub mod fragment;
+pub mod identity;
+pub mod lookup;
 pub mod messages;
 pub mod network;
 pub mod node;
 pub mod node_bucket;
+pub mod operation;
 pub mod routing_table;
 pub mod servers;
+pub mod snapshot;
 pub mod stun;
 pub mod transaction;
+pub mod websocket;
@@ -0,0 +1,297 @@
+use address::{Address, Addressable};
+use node::Node;
+use std::cmp;
+use transaction::{TransactionId, TransactionIdGenerator};
+
+#[derive(Debug, PartialEq)]
+enum State {
+    Unqueried,
+    Pending(TransactionId),
+    Responded,
+    Failed
+}
+
+struct Candidate {
+    node: Node,
+    state: State,
+    path: usize
+}
+
+/// An iterative Kademlia lookup for the `k` nodes nearest to a target address.
+///
+/// The lookup keeps a shortlist of candidates sorted by their distance from the target, starting
+/// from the nearest nodes in our routing table. It queries the `alpha` nearest candidates it
+/// hasn't queried yet, and merges the nodes each of them responds with into the shortlist. As
+/// responses come in, more candidates are queried, so that there are never more than `alpha`
+/// queries in flight. Once all of the `k` nearest candidates have either responded or failed to,
+/// no closer nodes are left to be found and the lookup is finished.
+///
+/// A lookup can also run over several node-disjoint paths, in the style of S/Kademlia. Each path
+/// is a lookup of its own with its own shortlist, but a node that one path has heard of is never
+/// taken up by another. Whoever controls the part of the address space one path wanders into
+/// can't steer the others, so they'd have to capture every path to hide the target from us.
+pub struct Lookup {
+    target: Address,
+    k: usize,
+    alpha: usize,
+    paths: usize,
+    candidates: Vec<Candidate>
+}
+
+impl Lookup {
+    /// A lookup over `paths` disjoint paths, each with up to `alpha` queries in flight. The seeds
+    /// are dealt out between the paths nearest first.
+    pub fn new(target: Address, k: usize, alpha: usize, paths: usize, mut seeds: Vec<Node>) -> Lookup {
+        let paths = cmp::max(paths, 1);
+        let mut lookup = Lookup {
+            target: target,
+            k: k,
+            alpha: alpha,
+            paths: paths,
+            candidates: vec![]
+        };
+        seeds.sort_by_key(|node| node.address().xor(&target));
+        // Seeds aren't deduplicated, since routers all share the null address
+        for (i, node) in seeds.into_iter().enumerate() {
+            lookup.candidates.push(Candidate {
+                node: node,
+                state: State::Unqueried,
+                path: i % paths
+            });
+        }
+        lookup.sort();
+        lookup
+    }
+
+    pub fn target(&self) -> Address {
+        self.target
+    }
+
+    /// Picks the nodes to query next, up to `alpha` queries in flight on each path, and marks them
+    /// as pending under the transaction IDs they're returned with.
+    pub fn next_queries(&mut self, transaction_ids: &mut TransactionIdGenerator) -> Vec<(TransactionId, Node)> {
+        let mut queries = vec![];
+        let k = self.k;
+        for path in 0..self.paths {
+            let mut in_flight = self.candidates
+                .iter()
+                .filter(|c| c.path == path)
+                .filter(|c| match c.state { State::Pending(_) => true, _ => false })
+                .count();
+            for candidate in self.candidates.iter_mut().filter(|c| c.path == path && c.state != State::Failed).take(k) {
+                if in_flight >= self.alpha {
+                    break
+                }
+                if candidate.state == State::Unqueried {
+                    let transaction_id = transaction_ids.generate();
+                    candidate.state = State::Pending(transaction_id);
+                    queries.push((transaction_id, candidate.node.clone()));
+                    in_flight += 1;
+                }
+            }
+        }
+        queries
+    }
+
+    /// Records `responder`'s response to the query with TID `transaction_id`, adding the `nodes`
+    /// it knows of near the target to the shortlist of the responder's path. Nodes already on any
+    /// path's shortlist are left where they are.
+    ///
+    /// The responder takes the place of the candidate we queried. They must be one and the same,
+    /// except for routers, whose addresses we don't know until they respond. Returns whether the
+    /// response was taken, which it isn't from anyone else, lest they take over the candidate's
+    /// place in the shortlist.
+    pub fn received_response(&mut self, transaction_id: TransactionId, responder: Node, nodes: Vec<Node>) -> bool {
+        let responder_address = responder.address();
+        let path = match self.candidates.iter_mut().find(|c| c.state == State::Pending(transaction_id)) {
+            Some(ref candidate) if candidate.node.address() != Address::null()
+                && candidate.node.address() != responder_address => {
+                debug!("{} responded in place of {}", &responder_address, &candidate.node.address());
+                return false
+            }
+            Some(candidate) => {
+                candidate.node = responder;
+                candidate.state = State::Responded;
+                candidate.path
+            }
+            None => return false
+        };
+        self.candidates.retain(|c| c.node.address() != responder_address || c.state != State::Unqueried);
+
+        for node in nodes {
+            if !self.candidates.iter().any(|c| c.node.address() == node.address()) {
+                self.candidates.push(Candidate {
+                    node: node,
+                    state: State::Unqueried,
+                    path: path
+                });
+            }
+        }
+        self.sort();
+        true
+    }
+
+    /// Records that the query with TID `transaction_id` went unanswered.
+    pub fn failed(&mut self, transaction_id: TransactionId) {
+        if let Some(candidate) = self.candidates.iter_mut().find(|c| c.state == State::Pending(transaction_id)) {
+            candidate.state = State::Failed;
+        }
+    }
+
+    /// Whether on every path, each of the `k` nearest candidates that hasn't failed has responded.
+    pub fn is_finished(&self) -> bool {
+        (0..self.paths).all(|path| {
+            self.candidates
+                .iter()
+                .filter(|c| c.path == path && c.state != State::Failed)
+                .take(self.k)
+                .all(|c| c.state == State::Responded)
+        })
+    }
+
+    /// The nearest node that responded on each path that found any. No two are the same node.
+    pub fn nearest_on_each_path(&self) -> Vec<&Node> {
+        (0..self.paths)
+            .filter_map(|path| {
+                self.candidates
+                    .iter()
+                    .find(|c| c.path == path && c.state == State::Responded)
+                    .map(|c| &c.node)
+            })
+            .collect()
+    }
+
+    /// The addresses of the `k` nearest nodes that responded on any path, nearest first.
+    pub fn nearest(&self) -> Vec<Address> {
+        self.candidates
+            .iter()
+            .filter(|c| c.state == State::Responded)
+            .take(self.k)
+            .map(|c| c.node.address())
+            .collect()
+    }
+
+    fn sort(&mut self) {
+        let target = self.target;
+        self.candidates.sort_by_key(|c| c.node.address().xor(&target));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use address::{Address, Addressable};
+    use node::Node;
+    use num::BigUint;
+    use std::collections::HashSet;
+    use super::Lookup;
+    use transaction::TransactionIdGenerator;
+
+    /// A node at distance `distance` from the null address.
+    fn node(distance: u32) -> Node {
+        Node::new(Address::from_numeric(BigUint::new(vec![distance])), HashSet::new())
+    }
+
+    fn addresses(queries: &[(u32, Node)]) -> Vec<Address> {
+        queries.iter().map(|(_, node)| node.address()).collect()
+    }
+
+    #[test]
+    fn test_queries_alpha_nearest() {
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut lookup = Lookup::new(Address::null(), 3, 2, 1, vec![node(11), node(9), node(10), node(8)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(8).address(), node(9).address()]);
+
+        // No more until one of them responds
+        assert!(lookup.next_queries(&mut transaction_ids).is_empty());
+        lookup.received_response(queries[0].0, node(8), vec![node(2), node(1), node(9)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(1).address()]);
+    }
+
+    #[test]
+    fn test_finishes_when_no_closer_nodes_appear() {
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut lookup = Lookup::new(Address::null(), 2, 3, 1, vec![node(8), node(9), node(10)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(queries.len(), 2);
+        lookup.received_response(queries[0].0, node(8), vec![node(3)]);
+        assert!(!lookup.is_finished());
+
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(3).address()]);
+        lookup.received_response(queries[0].0, node(3), vec![node(8), node(12)]);
+        assert!(lookup.is_finished());
+        assert_eq!(lookup.nearest(), vec![node(3).address(), node(8).address()]);
+    }
+
+    #[test]
+    fn test_skips_failed_nodes() {
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut lookup = Lookup::new(Address::null(), 2, 1, 1, vec![node(1), node(2), node(3)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        lookup.failed(queries[0].0);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(2).address()]);
+        lookup.received_response(queries[0].0, node(2), vec![]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(3).address()]);
+        lookup.received_response(queries[0].0, node(3), vec![]);
+        assert!(lookup.is_finished());
+        assert_eq!(lookup.nearest(), vec![node(2).address(), node(3).address()]);
+    }
+
+    #[test]
+    fn test_router_takes_responders_address() {
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut lookup = Lookup::new(Address::for_content("target"), 8, 3, 1, vec![node(0)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        let router = Node::new(Address::for_content("router"), HashSet::new());
+        lookup.received_response(queries[0].0, router, vec![]);
+        assert!(lookup.is_finished());
+        assert_eq!(lookup.nearest(), vec![Address::for_content("router")]);
+    }
+
+    #[test]
+    fn test_ignores_response_from_another_node() {
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut lookup = Lookup::new(Address::null(), 2, 1, 1, vec![node(2), node(3)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert!(!lookup.received_response(queries[0].0, node(1), vec![node(4)]));
+        assert!(lookup.next_queries(&mut transaction_ids).is_empty());
+        assert!(lookup.received_response(queries[0].0, node(2), vec![]));
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(3).address()]);
+        lookup.received_response(queries[0].0, node(3), vec![]);
+        assert_eq!(lookup.nearest(), vec![node(2).address(), node(3).address()]);
+    }
+
+    #[test]
+    fn test_disjoint_paths_share_no_nodes() {
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut lookup = Lookup::new(Address::null(), 2, 1, 2, vec![node(4), node(3), node(2), node(1)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(1).address(), node(2).address()]);
+
+        // Each path keeps to the nodes it heard of first
+        lookup.received_response(queries[0].0, node(1), vec![node(2), node(5)]);
+        lookup.received_response(queries[1].0, node(2), vec![node(5), node(6)]);
+        let queries = lookup.next_queries(&mut transaction_ids);
+        assert_eq!(addresses(&queries), vec![node(3).address(), node(4).address()]);
+        lookup.received_response(queries[0].0, node(3), vec![]);
+        assert!(!lookup.is_finished());
+        lookup.received_response(queries[1].0, node(4), vec![]);
+        assert!(lookup.is_finished());
+
+        let nearest: Vec<Address> = lookup.nearest_on_each_path().iter().map(|n| n.address()).collect();
+        assert_eq!(nearest, vec![node(1).address(), node(2).address()]);
+        assert_eq!(lookup.nearest(), vec![node(1).address(), node(2).address()]);
+    }
+
+    #[test]
+    fn test_finishes_without_seeds() {
+        let lookup = Lookup::new(Address::null(), 8, 3, 1, vec![]);
+        assert!(lookup.is_finished());
+        assert!(lookup.nearest().is_empty());
+    }
+}
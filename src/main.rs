@@ -2,34 +2,45 @@ extern crate env_logger;
 extern crate clap;
 extern crate crypto;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 extern crate log;
 extern crate mio;
+extern crate net2;
 extern crate num;
 extern crate protobuf;
 extern crate rand;
 extern crate rustc_serialize;
 extern crate time;
 
+use rustc_serialize::json::ToJson;
 use std::collections::HashSet;
 use std::io;
-use std::net::ToSocketAddrs;
 use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
 
 use address::Address;
 use client::Task;
 use client::messages::TextMessage;
+use identity::Identity;
 
 mod address;
 mod client;
+mod fragment;
+mod identity;
+mod lookup;
 mod messages;
 mod network;
 mod node;
 mod node_bucket;
+mod operation;
 mod routing_table;
 mod servers;
+mod snapshot;
 mod stun;
 mod transaction;
+mod websocket;
 
 /// Starts a command line client.
 ///
@@ -49,7 +60,6 @@ fn main() {
         .arg(clap::Arg::with_name("secret")
              .long("secret")
              .value_name("SECRET")
-             .required(true)
              .takes_value(true))
         .arg(clap::Arg::with_name("server")
              .long("server")
@@ -64,31 +74,113 @@ fn main() {
              .value_name("URL")
              .takes_value(true)
              .multiple(true))
+        .arg(clap::Arg::with_name("stun-server")
+             .long("stun-server")
+             .value_name("HOST:PORT")
+             .takes_value(true)
+             .multiple(true))
+        .arg(clap::Arg::with_name("stun-timeout")
+             .long("stun-timeout")
+             .value_name("MS")
+             .takes_value(true))
+        .arg(clap::Arg::with_name("no-stun")
+             .long("no-stun")
+             .conflicts_with_all(&["stun-server", "stun-timeout"]))
+        .arg(clap::Arg::with_name("relay")
+             .long("relay"))
+        .arg(clap::Arg::with_name("routing-table")
+             .long("routing-table")
+             .value_name("FILE")
+             .takes_value(true))
+        .arg(clap::Arg::with_name("disjoint-paths")
+             .long("disjoint-paths")
+             .value_name("D")
+             .takes_value(true))
+        .arg(clap::Arg::with_name("max-packet-size")
+             .long("max-packet-size")
+             .value_name("BYTES")
+             .takes_value(true))
+        .arg(clap::Arg::with_name("bucket-ip-limits")
+             .long("bucket-ip-limits")
+             .value_name("PER_IP,PER_SUBNET")
+             .takes_value(true))
+        .arg(clap::Arg::with_name("table-ip-limits")
+             .long("table-ip-limits")
+             .value_name("PER_IP,PER_SUBNET")
+             .takes_value(true))
         .get_matches();
 
-    let secret = matches.value_of("server").expect("No secret");
+    // Without a secret, the node is someone new each time it starts
+    let identity = match matches.value_of("secret") {
+        Some(secret) => Identity::for_secret(secret),
+        None => Identity::generate()
+    };
+    let address = identity.address();
 
-    let address = Address::for_content(secret);
+    let stun_config = if matches.is_present("no-stun") {
+        stun::Config::disabled()
+    } else {
+        let mut stun_config = stun::Config::new();
+        if let Some(stun_servers) = matches.values_of("stun-server") {
+            stun_config.servers = stun_servers.map(|s| s.to_string()).collect();
+        }
+        if let Some(timeout) = matches.value_of("stun-timeout") {
+            let timeout = timeout.parse().expect("Invalid STUN timeout");
+            stun_config.timeout = Duration::from_millis(timeout);
+        }
+        stun_config
+    };
 
     let servers = matches
         .values_of("server")
         .expect("No servers")
-        .map(|url| servers::Server::create(url).expect("Invalid server spec"))
+        .map(|url| {
+            let mut server = servers::Server::create(url).expect("Invalid server spec");
+            server.set_stun_config(stun_config.clone());
+            server
+        })
         .collect();
 
     let routers: Vec<node::Node> = match matches.values_of("router") {
         Some(urls) => {
             urls.map(|url| {
                 let mut transports = HashSet::new();
-                transports.insert(node::Transport::Udp(node::UdpTransport::new(
-                            url.to_socket_addrs().unwrap().next().unwrap())));
+                transports.insert(node::Transport::create(url).expect("Invalid router spec"));
                 node::Node::new(Address::null(), transports)
             }).collect()
         }
         None => vec![]
     };
 
-    let network = network::Network::new(address, servers, routers);
+    // Rejoin with the routing table we had last time, if we saved one
+    let routing_table_file = matches.value_of("routing-table");
+    let saved = routing_table_file.and_then(|path| {
+        routing_table::SavedRoutingTable::read_from_file(path)
+            .map_err(|error| info!("Not restoring routing table from {}: {}", path, error))
+            .ok()
+    });
+
+    let mut network = network::Network::new(identity, servers, routers, saved);
+    if matches.is_present("relay") {
+        network.volunteer_as_relay();
+    }
+    if let Some(path) = routing_table_file {
+        network.save_routing_table_to(path);
+    }
+    if let Some(paths) = matches.value_of("disjoint-paths") {
+        network.use_disjoint_paths(paths.parse().expect("Invalid number of disjoint paths"));
+    }
+    if let Some(size) = matches.value_of("max-packet-size") {
+        network.set_max_packet_size(size.parse().expect("Invalid maximum packet size"));
+    }
+    let mut diversity_limits = routing_table::DiversityLimits::default();
+    if let Some(limits) = matches.value_of("bucket-ip-limits") {
+        diversity_limits.bucket = limits.parse().expect("Invalid bucket IP limits");
+    }
+    if let Some(limits) = matches.value_of("table-ip-limits") {
+        diversity_limits.table = limits.parse().expect("Invalid table IP limits");
+    }
+    network.set_diversity_limits(diversity_limits);
     let mut client = client::Client::new(address);
     let (event_sender, events) = mpsc::channel();
     client.register_event_listener(event_sender);
@@ -103,6 +195,36 @@ fn main() {
     loop {
         let mut line = String::new();
         io::stdin().read_line(&mut line).unwrap();
+
+        // Print what the routing table knows, as JSON or a Graphviz digraph
+        if line.trim() == "/routes" || line.trim() == "/routes.dot" {
+            let (reply, snapshots) = mpsc::channel();
+            client_channel.send(Task::SnapshotRoutingTable(reply)).expect("Couldn't send SnapshotRoutingTable");
+            if let Ok(snapshot) = snapshots.recv() {
+                if line.trim() == "/routes" {
+                    println!("{}", snapshot.to_json().pretty());
+                } else {
+                    print!("{}", snapshot.to_graphviz());
+                }
+            }
+            continue
+        }
+
+        // Look up the nodes nearest to an address, or punch a hole to the node at one
+        if line.starts_with("/find ") || line.starts_with("/punch ") {
+            let parts: Vec<&str> = line.trim().splitn(2, ' ').collect();
+            let target = match Address::from_str(parts[1].trim()) {
+                Ok(target) => target,
+                Err(_) => {
+                    println!("Invalid address {}", parts[1].trim());
+                    continue
+                }
+            };
+            let task = if parts[0] == "/find" { Task::FindNode(target) } else { Task::PunchHole(target) };
+            client_channel.send(task).expect("Couldn't send task");
+            continue
+        }
+
         let parts: Vec<&str> = line.splitn(2, ' ').collect();
         match parts.len() {
             1 => {
@@ -1,100 +1,300 @@
 pub mod protobufs;
 
+/// The version of the protocol we speak, which every envelope we send is marked with. Version 3
+/// sends addresses as 20 bytes rather than hexadecimal strings, and version 4 splits packets too
+/// large for one datagram into fragments.
+pub const PROTOCOL_VERSION: u32 = 4;
+
+/// The oldest version of the protocol we understand. Messages in version 1 aren't signed.
+pub const MIN_PROTOCOL_VERSION: u32 = 2;
+
 pub mod incoming {
     use address::Address;
+    use fragment::Fragment;
+    use identity;
     use node::Node;
-    use node;
     use protobuf;
+    use std::fmt;
     use std::io::Read;
-    use super::protobufs;
+    use super::{protobufs, MIN_PROTOCOL_VERSION};
     use transaction::TransactionId;
 
     #[derive(Debug)]
     pub enum Query {
         FindNode(Address),
-        Packet(Vec<u8>),
-        Ping
+        Packet(Vec<u8>, Option<Fragment>),
+        Ping,
+        RegisterRelay,
+        Rendezvous(Address, Option<Node>)
     }
 
     #[derive(Debug)]
     pub enum Response {
         FindNode(Vec<Node>),
         Packet,
-        Ping
+        Ping,
+        RegisterRelay(bool),
+        Rendezvous(Option<Node>)
     }
 
     #[derive(Debug)]
     pub enum Message {
         Query(TransactionId, Node, Query),
-        Response(TransactionId, Node, Response)
-    }
-
-    pub fn parse_from_reader(reader: &mut Read) -> Result<Message, &str> {
-        match protobuf::parse_from_reader::<protobufs::Envelope>(reader) {
-            Ok(message) => {
-                let transaction_id = message.get_transaction_id();
-                match message.get_message_type() {
-                    protobufs::Envelope_Type::FIND_NODE_QUERY => {
-                        let find_node_query = message.get_find_node_query();
-                        let origin = node::Node::deserialize(find_node_query.get_origin());
-                        let target = Address::from_str(find_node_query.get_target()).unwrap();
-                        Ok(Message::Query(transaction_id, origin, Query::FindNode(target)))
-                    }
-                    protobufs::Envelope_Type::FIND_NODE_RESPONSE => {
-                        let find_node_response = message.get_find_node_response();
-                        let origin = node::Node::deserialize(find_node_response.get_origin());
-                        let nodes = find_node_response.get_nodes();
-                        let nodes: Vec<Node> = nodes.iter().map(|n| {
-                            let node: Node = node::Node::deserialize(n);
-                            node
-                        }).collect();
-                        Ok(Message::Response(transaction_id, origin, Response::FindNode(nodes)))
-                    }
-                    protobufs::Envelope_Type::PING_QUERY => {
-                        let ping_query = message.get_ping_query();
-                        let origin = node::Node::deserialize(ping_query.get_origin());
-                        Ok(Message::Query(transaction_id, origin, Query::Ping))
-                    },
-                    protobufs::Envelope_Type::PING_RESPONSE => {
-                        let ping_response = message.get_ping_response();
-                        let origin = node::Node::deserialize(ping_response.get_origin());
-                        Ok(Message::Response(transaction_id, origin, Response::Ping))
-                    },
-                    protobufs::Envelope_Type::PACKET_QUERY => {
-                        let packet_query = message.get_packet_query();
-                        let origin = node::Node::deserialize(packet_query.get_origin());
-                        let payload = packet_query.get_payload();
-                        Ok(Message::Query(transaction_id, origin, Query::Packet(payload.to_vec())))
-                    },
-                    protobufs::Envelope_Type::PACKET_RESPONSE => {
-                        let response = message.get_packet_response();
-                        let origin = node::Node::deserialize(response.get_origin());
-                        Ok(Message::Response(transaction_id, origin, Response::Packet))
+        Response(TransactionId, Node, Response),
+        Relay(Address, Vec<u8>)
+    }
+
+    /// Why an incoming message couldn't be parsed. Messages that can't be are dropped.
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum ParseError {
+        /// It isn't an envelope, or is missing fields every envelope has.
+        Malformed,
+
+        /// It's in a version of the protocol older than `MIN_PROTOCOL_VERSION`.
+        UnsupportedVersion(u32),
+
+        /// It's of a type from a later version of the protocol than ours.
+        UnsupportedMessageType,
+
+        /// An address in it is neither 20 bytes nor a hexadecimal string.
+        InvalidAddress,
+
+        /// It's a query or response that isn't signed by its origin.
+        BadSignature
+    }
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ParseError::Malformed => write!(f, "Failed to parse protobuf"),
+                ParseError::UnsupportedVersion(version) => write!(f, "Unsupported protocol version {}", version),
+                ParseError::UnsupportedMessageType => write!(f, "Unsupported message type"),
+                ParseError::InvalidAddress => write!(f, "Invalid address"),
+                ParseError::BadSignature => write!(f, "Message isn't signed by its origin")
+            }
+        }
+    }
+
+    /// The number of the `signature` field of an `Envelope`.
+    const SIGNATURE_FIELD: u64 = 14;
+
+    /// Whether `envelope`, received as `bytes`, carries `origin`'s signature over the rest of it,
+    /// and `origin`'s address is its own to claim.
+    fn is_signed_by(bytes: &[u8], envelope: &protobufs::Envelope, origin: &Node) -> bool {
+        let public_key = match origin.public_key() {
+            Some(public_key) if origin.has_verifiable_address() => public_key,
+            _ => return false
+        };
+        match unsigned_bytes(bytes) {
+            Some(message) => identity::verify(public_key, &message, envelope.get_signature()),
+            None => false
+        }
+    }
+
+    /// Cuts the signature out of an envelope as it was received, leaving the bytes its origin
+    /// signed. These are checked rather than the envelope as we'd serialize it again, which needn't
+    /// come out the same. Returns `None` if `bytes` aren't made up of whole fields.
+    fn unsigned_bytes(bytes: &[u8]) -> Option<Vec<u8>> {
+        let mut unsigned = Vec::with_capacity(bytes.len());
+        let mut position = 0;
+        while position < bytes.len() {
+            let start = position;
+            let tag = read_varint(bytes, &mut position)?;
+            match tag & 7 {
+                0 => {
+                    read_varint(bytes, &mut position)?;
+                }
+                1 => position += 8,
+                2 => {
+                    let length = read_varint(bytes, &mut position)?;
+                    if length > (bytes.len() - position) as u64 {
+                        return None
                     }
+                    position += length as usize;
                 }
+                5 => position += 4,
+                _ => return None
+            }
+            if position > bytes.len() {
+                return None
+            }
+            if tag >> 3 != SIGNATURE_FIELD {
+                unsigned.extend_from_slice(&bytes[start..position]);
             }
-            Err(_) => { Err("Failed to parse protobuf") }
+        }
+        Some(unsigned)
+    }
+
+    /// Reads the protobuf varint at `position` in `bytes`, moving `position` past it.
+    fn read_varint(bytes: &[u8], position: &mut usize) -> Option<u64> {
+        let mut value = 0;
+        for shift in 0..10 {
+            let byte = *bytes.get(*position)?;
+            *position += 1;
+            value |= ((byte & 0x7F) as u64) << (7 * shift);
+            if byte & 0x80 == 0 {
+                return Some(value)
+            }
+        }
+        None
+    }
+
+    fn parse_address(bytes: &[u8]) -> Result<Address, ParseError> {
+        Address::from_wire(bytes).ok_or(ParseError::InvalidAddress)
+    }
+
+    fn parse_node(message: &protobufs::Node) -> Result<Node, ParseError> {
+        Node::deserialize(message).ok_or(ParseError::InvalidAddress)
+    }
+
+    /// Parses a message off the wire. Anything could arrive from anyone, so whatever is wrong with
+    /// it is returned as a `ParseError` rather than panicking.
+    pub fn parse_from_reader(reader: &mut Read) -> Result<Message, ParseError> {
+        // The bytes are kept as they are to check the signature over
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).map_err(|_| ParseError::Malformed)?;
+        let message = protobuf::parse_from_bytes::<protobufs::Envelope>(&bytes)
+            .map_err(|_| ParseError::Malformed)?;
+        let version = if message.has_protocol_version() {
+            message.get_protocol_version()
+        } else {
+            1
+        };
+        if version < MIN_PROTOCOL_VERSION {
+            return Err(ParseError::UnsupportedVersion(version));
+        }
+        // A type from a later version than ours
+        if !message.has_message_type() {
+            return Err(ParseError::UnsupportedMessageType);
+        }
+        let transaction_id = message.get_transaction_id();
+        let parsed = match message.get_message_type() {
+            protobufs::Envelope_Type::FIND_NODE_QUERY => {
+                let find_node_query = message.get_find_node_query();
+                let origin = parse_node(find_node_query.get_origin())?;
+                let target = parse_address(find_node_query.get_target())?;
+                Message::Query(transaction_id, origin, Query::FindNode(target))
+            }
+            protobufs::Envelope_Type::FIND_NODE_RESPONSE => {
+                let find_node_response = message.get_find_node_response();
+                let origin = parse_node(find_node_response.get_origin())?;
+                // Nodes we can't make sense of are left out, like transports are
+                let nodes: Vec<Node> = find_node_response.get_nodes()
+                    .iter()
+                    .filter_map(Node::deserialize)
+                    .collect();
+                Message::Response(transaction_id, origin, Response::FindNode(nodes))
+            }
+            protobufs::Envelope_Type::PING_QUERY => {
+                let ping_query = message.get_ping_query();
+                let origin = parse_node(ping_query.get_origin())?;
+                Message::Query(transaction_id, origin, Query::Ping)
+            },
+            protobufs::Envelope_Type::PING_RESPONSE => {
+                let ping_response = message.get_ping_response();
+                let origin = parse_node(ping_response.get_origin())?;
+                Message::Response(transaction_id, origin, Response::Ping)
+            },
+            protobufs::Envelope_Type::PACKET_QUERY => {
+                let packet_query = message.get_packet_query();
+                let origin = parse_node(packet_query.get_origin())?;
+                let payload = packet_query.get_payload();
+                let fragment = if packet_query.has_fragment() {
+                    let fragment = packet_query.get_fragment();
+                    Some(Fragment {
+                        id: fragment.get_id(),
+                        index: fragment.get_index() as usize,
+                        count: fragment.get_count() as usize
+                    })
+                } else {
+                    None
+                };
+                Message::Query(transaction_id, origin, Query::Packet(payload.to_vec(), fragment))
+            },
+            protobufs::Envelope_Type::PACKET_RESPONSE => {
+                let response = message.get_packet_response();
+                let origin = parse_node(response.get_origin())?;
+                Message::Response(transaction_id, origin, Response::Packet)
+            }
+            protobufs::Envelope_Type::RENDEZVOUS_QUERY => {
+                let query = message.get_rendezvous_query();
+                let origin = parse_node(query.get_origin())?;
+                let target = parse_address(query.get_target())?;
+                let peer = if query.has_peer() {
+                    Some(parse_node(query.get_peer())?)
+                } else {
+                    None
+                };
+                Message::Query(transaction_id, origin, Query::Rendezvous(target, peer))
+            }
+            protobufs::Envelope_Type::RENDEZVOUS_RESPONSE => {
+                let response = message.get_rendezvous_response();
+                let origin = parse_node(response.get_origin())?;
+                let peer = if response.has_peer() {
+                    Some(parse_node(response.get_peer())?)
+                } else {
+                    None
+                };
+                Message::Response(transaction_id, origin, Response::Rendezvous(peer))
+            }
+            protobufs::Envelope_Type::REGISTER_RELAY_QUERY => {
+                let query = message.get_register_relay_query();
+                let origin = parse_node(query.get_origin())?;
+                Message::Query(transaction_id, origin, Query::RegisterRelay)
+            }
+            protobufs::Envelope_Type::REGISTER_RELAY_RESPONSE => {
+                let response = message.get_register_relay_response();
+                let origin = parse_node(response.get_origin())?;
+                let accepted = response.get_accepted();
+                Message::Response(transaction_id, origin, Response::RegisterRelay(accepted))
+            }
+            protobufs::Envelope_Type::RELAY => {
+                let relay = message.get_relay();
+                let target = parse_address(relay.get_target())?;
+                Message::Relay(target, relay.get_payload().to_vec())
+            }
+        };
+        // Relayed messages are checked by whoever they're relayed to
+        let is_signed = match parsed {
+            Message::Query(_, ref origin, _) | Message::Response(_, ref origin, _) => is_signed_by(&bytes, &message, origin),
+            _ => true
+        };
+        if is_signed {
+            Ok(parsed)
+        } else {
+            Err(ParseError::BadSignature)
         }
     }
 }
 
 pub mod outgoing {
     use address::Address;
+    use fragment::Fragment;
     use node::{Node, Serialize};
     use protobuf::Message;
     use protobuf;
-    use super::protobufs;
+    use super::{protobufs, PROTOCOL_VERSION};
     use transaction::TransactionId;
 
+    /// Serializes `envelope`, signed by `origin` if it's our own node.
+    fn seal(mut envelope: protobufs::Envelope, origin: &Node) -> Vec<u8> {
+        envelope.set_protocol_version(PROTOCOL_VERSION);
+        if let Some(identity) = origin.identity() {
+            let signature = identity.sign(&envelope.write_to_bytes().unwrap());
+            envelope.set_signature(signature.to_vec());
+        }
+        envelope.write_to_bytes().unwrap()
+    }
+
     pub fn create_find_node_query(transaction_id: TransactionId, origin: &Node, target: &Address) -> Vec<u8> {
         let mut envelope = protobufs::Envelope::new();
         envelope.set_transaction_id(transaction_id);
         envelope.set_message_type(protobufs::Envelope_Type::FIND_NODE_QUERY);
         let mut query = protobufs::FindNodeQuery::new();
         query.set_origin(origin.serialize());
-        query.set_target(target.to_str());
+        query.set_target(target.to_bytes().to_vec());
         envelope.set_find_node_query(query);
-        envelope.write_to_bytes().unwrap()
+        seal(envelope, origin)
     }
 
     pub fn create_find_node_response(transaction_id: TransactionId, origin: &Node, nodes: Vec<&mut Node>) -> Vec<u8> {
@@ -110,7 +310,7 @@ pub mod outgoing {
         response.set_nodes(protobuf::RepeatedField::from_slice(
                 nodes.as_slice()));
         envelope.set_find_node_response(response);
-        envelope.write_to_bytes().unwrap()
+        seal(envelope, origin)
     }
 
     pub fn create_ping_query(transaction_id: TransactionId, origin: &Node) -> Vec<u8> {
@@ -120,7 +320,7 @@ pub mod outgoing {
         let mut query = protobufs::PingQuery::new();
         query.set_origin(origin.serialize());
         envelope.set_ping_query(query);
-        envelope.write_to_bytes().unwrap()
+        seal(envelope, origin)
     }
 
     pub fn create_ping_response(transaction_id: TransactionId, origin: &Node) -> Vec<u8> {
@@ -130,7 +330,7 @@ pub mod outgoing {
         let mut response = protobufs::PingResponse::new();
         response.set_origin(origin.serialize());
         envelope.set_ping_response(response);
-        envelope.write_to_bytes().unwrap()
+        seal(envelope, origin)
     }
 
     pub fn create_packet_query(transaction_id: TransactionId, origin: &Node, payload: Vec<u8>) -> Vec<u8> {
@@ -141,7 +341,24 @@ pub mod outgoing {
         query.set_origin(origin.serialize());
         query.set_payload(payload);
         envelope.set_packet_query(query);
-        envelope.write_to_bytes().unwrap()
+        seal(envelope, origin)
+    }
+
+    /// A packet query carrying `payload`, the `fragment` of a larger payload.
+    pub fn create_packet_fragment_query(transaction_id: TransactionId, origin: &Node, fragment: &Fragment, payload: Vec<u8>) -> Vec<u8> {
+        let mut envelope = protobufs::Envelope::new();
+        envelope.set_transaction_id(transaction_id);
+        envelope.set_message_type(protobufs::Envelope_Type::PACKET_QUERY);
+        let mut query = protobufs::PacketQuery::new();
+        query.set_origin(origin.serialize());
+        query.set_payload(payload);
+        let mut encoded = protobufs::Fragment::new();
+        encoded.set_id(fragment.id);
+        encoded.set_index(fragment.index as u32);
+        encoded.set_count(fragment.count as u32);
+        query.set_fragment(encoded);
+        envelope.set_packet_query(query);
+        seal(envelope, origin)
     }
 
     pub fn create_packet_response(transaction_id: TransactionId, origin: &Node) -> Vec<u8> {
@@ -151,7 +368,146 @@ pub mod outgoing {
         let mut response = protobufs::PacketResponse::new();
         response.set_origin(origin.serialize());
         envelope.set_packet_response(response);
+        seal(envelope, origin)
+    }
+
+    pub fn create_rendezvous_query(transaction_id: TransactionId, origin: &Node, target: &Address, peer: Option<&Node>) -> Vec<u8> {
+        let mut envelope = protobufs::Envelope::new();
+        envelope.set_transaction_id(transaction_id);
+        envelope.set_message_type(protobufs::Envelope_Type::RENDEZVOUS_QUERY);
+        let mut query = protobufs::RendezvousQuery::new();
+        query.set_origin(origin.serialize());
+        query.set_target(target.to_bytes().to_vec());
+        if let Some(peer) = peer {
+            query.set_peer(peer.serialize());
+        }
+        envelope.set_rendezvous_query(query);
+        seal(envelope, origin)
+    }
+
+    pub fn create_rendezvous_response(transaction_id: TransactionId, origin: &Node, peer: Option<&Node>) -> Vec<u8> {
+        let mut envelope = protobufs::Envelope::new();
+        envelope.set_transaction_id(transaction_id);
+        envelope.set_message_type(protobufs::Envelope_Type::RENDEZVOUS_RESPONSE);
+        let mut response = protobufs::RendezvousResponse::new();
+        response.set_origin(origin.serialize());
+        if let Some(peer) = peer {
+            response.set_peer(peer.serialize());
+        }
+        envelope.set_rendezvous_response(response);
+        seal(envelope, origin)
+    }
+
+    pub fn create_register_relay_query(transaction_id: TransactionId, origin: &Node) -> Vec<u8> {
+        let mut envelope = protobufs::Envelope::new();
+        envelope.set_transaction_id(transaction_id);
+        envelope.set_message_type(protobufs::Envelope_Type::REGISTER_RELAY_QUERY);
+        let mut query = protobufs::RegisterRelayQuery::new();
+        query.set_origin(origin.serialize());
+        envelope.set_register_relay_query(query);
+        seal(envelope, origin)
+    }
+
+    pub fn create_register_relay_response(transaction_id: TransactionId, origin: &Node, accepted: bool) -> Vec<u8> {
+        let mut envelope = protobufs::Envelope::new();
+        envelope.set_transaction_id(transaction_id);
+        envelope.set_message_type(protobufs::Envelope_Type::REGISTER_RELAY_RESPONSE);
+        let mut response = protobufs::RegisterRelayResponse::new();
+        response.set_origin(origin.serialize());
+        response.set_accepted(accepted);
+        envelope.set_register_relay_response(response);
+        seal(envelope, origin)
+    }
+
+    /// Wraps `message` for a relay to forward to `target`.
+    pub fn create_relay(target: &Address, message: Vec<u8>) -> Vec<u8> {
+        let mut envelope = protobufs::Envelope::new();
+        envelope.set_transaction_id(0);
+        envelope.set_message_type(protobufs::Envelope_Type::RELAY);
+        envelope.set_protocol_version(PROTOCOL_VERSION);
+        let mut relay = protobufs::Relay::new();
+        relay.set_target(target.to_bytes().to_vec());
+        relay.set_payload(message);
+        envelope.set_relay(relay);
         envelope.write_to_bytes().unwrap()
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use address::Address;
+    use identity::Identity;
+    use node::Node;
+    use protobuf;
+    use protobuf::Message;
+    use std::collections::HashSet;
+    use std::io::Cursor;
+    use super::incoming::{self, ParseError};
+    use super::{outgoing, protobufs};
+
+    fn parse(data: &[u8]) -> Result<incoming::Message, ParseError> {
+        incoming::parse_from_reader(&mut Cursor::new(data))
+    }
+
+    /// Decodes `data` into its envelope, so that a test can tamper with it.
+    fn envelope(data: &[u8]) -> protobufs::Envelope {
+        protobuf::parse_from_bytes::<protobufs::Envelope>(data).unwrap()
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let origin = Node::for_identity(Identity::for_secret("alpha"), HashSet::new());
+        let query = outgoing::create_find_node_query(1, &origin, &Address::for_content("beta"));
+        assert!(parse(&query).is_ok());
+
+        assert_eq!(parse(b"\xff\xff\xff\xff").err(), Some(ParseError::Malformed));
+        assert_eq!(parse(&query[..query.len() / 2]).err(), Some(ParseError::Malformed));
+
+        let mut old = envelope(&query);
+        old.clear_protocol_version();
+        assert_eq!(parse(&old.write_to_bytes().unwrap()).err(), Some(ParseError::UnsupportedVersion(1)));
+
+        let mut unknown = envelope(&query);
+        unknown.clear_message_type();
+        assert_eq!(parse(&unknown.write_to_bytes().unwrap()).err(), Some(ParseError::UnsupportedMessageType));
+
+        let mut bad_target = envelope(&query);
+        bad_target.mut_find_node_query().set_target(b"not hexadecimal".to_vec());
+        assert_eq!(parse(&bad_target.write_to_bytes().unwrap()).err(), Some(ParseError::InvalidAddress));
+
+        let mut bad_origin = envelope(&query);
+        bad_origin.mut_find_node_query().mut_origin().set_id(b"not hexadecimal".to_vec());
+        assert_eq!(parse(&bad_origin.write_to_bytes().unwrap()).err(), Some(ParseError::InvalidAddress));
+
+        let mut forged = envelope(&query);
+        forged.set_transaction_id(2);
+        assert_eq!(parse(&forged.write_to_bytes().unwrap()).err(), Some(ParseError::BadSignature));
+    }
+
+    #[test]
+    fn test_signature_over_bytes_received() {
+        let identity = Identity::for_secret("alpha");
+        let origin = Node::for_identity(Identity::for_secret("alpha"), HashSet::new());
+        let query = outgoing::create_find_node_query(1, &origin, &Address::for_content("beta"));
+        let mut unsigned = envelope(&query);
+        unsigned.clear_signature();
+        let unsigned = unsigned.write_to_bytes().unwrap();
+
+        // Signed with the protocol version first, out of the order we'd serialize it in
+        let version = [0x78, 0x04];
+        assert!(unsigned.ends_with(&version));
+        let mut reordered = version.to_vec();
+        reordered.extend_from_slice(&unsigned[..unsigned.len() - version.len()]);
+        let signature = identity.sign(&reordered);
+        reordered.extend_from_slice(&[0x72, signature.len() as u8]);
+        reordered.extend_from_slice(&signature);
+        assert!(parse(&reordered).is_ok());
+
+        // Whereas the fields it was signed with in the order we'd serialize them in aren't signed
+        let mut rearranged = unsigned.clone();
+        rearranged.extend_from_slice(&[0x72, signature.len() as u8]);
+        rearranged.extend_from_slice(&signature);
+        assert_eq!(parse(&rearranged).err(), Some(ParseError::BadSignature));
+    }
+}
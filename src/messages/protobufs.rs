@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
@@ -20,14 +21,14 @@
 use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
-#[derive(Clone,Default)]
+#[derive(PartialEq,Clone,Default)]
 pub struct UdpTransport {
     // message fields
     ip_address: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     port: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
@@ -44,14 +45,7 @@ impl UdpTransport {
             ptr: 0 as *const UdpTransport,
         };
         unsafe {
-            instance.get(|| {
-                UdpTransport {
-                    ip_address: ::protobuf::SingularField::none(),
-                    port: ::std::option::Option::None,
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(UdpTransport::new)
         }
     }
 
@@ -75,7 +69,7 @@ impl UdpTransport {
     pub fn mut_ip_address(&mut self) -> &mut ::std::vec::Vec<u8> {
         if self.ip_address.is_none() {
             self.ip_address.set_default();
-        };
+        }
         self.ip_address.as_mut().unwrap()
     }
 
@@ -91,6 +85,14 @@ impl UdpTransport {
         }
     }
 
+    fn get_ip_address_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.ip_address
+    }
+
+    fn mut_ip_address_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.ip_address
+    }
+
     // required uint32 port = 2;
 
     pub fn clear_port(&mut self) {
@@ -109,35 +111,43 @@ impl UdpTransport {
     pub fn get_port(&self) -> u32 {
         self.port.unwrap_or(0)
     }
+
+    fn get_port_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.port
+    }
+
+    fn mut_port_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.port
+    }
 }
 
 impl ::protobuf::Message for UdpTransport {
     fn is_initialized(&self) -> bool {
         if self.ip_address.is_none() {
             return false;
-        };
+        }
         if self.port.is_none() {
             return false;
-        };
+        }
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ip_address));
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ip_address)?;
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint32());
+                    }
+                    let tmp = is.read_uint32()?;
                     self.port = ::std::option::Option::Some(tmp);
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -148,25 +158,25 @@ impl ::protobuf::Message for UdpTransport {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.ip_address.iter() {
-            my_size += ::protobuf::rt::bytes_size(1, &value);
-        };
-        for value in self.port.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
+        if let Some(ref v) = self.ip_address.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        if let Some(v) = self.port {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.ip_address.as_ref() {
-            try!(os.write_bytes(1, &v));
-        };
+        if let Some(ref v) = self.ip_address.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
         if let Some(v) = self.port {
-            try!(os.write_uint32(2, v));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -182,13 +192,15 @@ impl ::protobuf::Message for UdpTransport {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<UdpTransport>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
@@ -208,15 +220,15 @@ impl ::protobuf::MessageStatic for UdpTransport {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "ip_address",
-                    UdpTransport::has_ip_address,
-                    UdpTransport::get_ip_address,
+                    UdpTransport::get_ip_address_for_reflect,
+                    UdpTransport::mut_ip_address_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
                     "port",
-                    UdpTransport::has_port,
-                    UdpTransport::get_port,
+                    UdpTransport::get_port_for_reflect,
+                    UdpTransport::mut_port_for_reflect,
                 ));
                 ::protobuf::reflect::MessageDescriptor::new::<UdpTransport>(
                     "UdpTransport",
@@ -236,132 +248,145 @@ impl ::protobuf::Clear for UdpTransport {
     }
 }
 
-impl ::std::cmp::PartialEq for UdpTransport {
-    fn eq(&self, other: &UdpTransport) -> bool {
-        self.ip_address == other.ip_address &&
-        self.port == other.port &&
-        self.unknown_fields == other.unknown_fields
-    }
-}
-
 impl ::std::fmt::Debug for UdpTransport {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-#[derive(Clone,Default)]
-pub struct Transport {
+impl ::protobuf::reflect::ProtobufValue for UdpTransport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TcpTransport {
     // message fields
-    transport_type: ::std::option::Option<Transport_Type>,
-    udp_transport: ::protobuf::SingularPtrField<UdpTransport>,
+    ip_address: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    port: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Transport {}
+unsafe impl ::std::marker::Sync for TcpTransport {}
 
-impl Transport {
-    pub fn new() -> Transport {
+impl TcpTransport {
+    pub fn new() -> TcpTransport {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Transport {
-        static mut instance: ::protobuf::lazy::Lazy<Transport> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static TcpTransport {
+        static mut instance: ::protobuf::lazy::Lazy<TcpTransport> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Transport,
+            ptr: 0 as *const TcpTransport,
         };
         unsafe {
-            instance.get(|| {
-                Transport {
-                    transport_type: ::std::option::Option::None,
-                    udp_transport: ::protobuf::SingularPtrField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(TcpTransport::new)
         }
     }
 
-    // required .Transport.Type transport_type = 1;
+    // required bytes ip_address = 1;
 
-    pub fn clear_transport_type(&mut self) {
-        self.transport_type = ::std::option::Option::None;
+    pub fn clear_ip_address(&mut self) {
+        self.ip_address.clear();
     }
 
-    pub fn has_transport_type(&self) -> bool {
-        self.transport_type.is_some()
+    pub fn has_ip_address(&self) -> bool {
+        self.ip_address.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_transport_type(&mut self, v: Transport_Type) {
-        self.transport_type = ::std::option::Option::Some(v);
+    pub fn set_ip_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ip_address = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_transport_type(&self) -> Transport_Type {
-        self.transport_type.unwrap_or(Transport_Type::UDP)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ip_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.ip_address.is_none() {
+            self.ip_address.set_default();
+        }
+        self.ip_address.as_mut().unwrap()
     }
 
-    // optional .UdpTransport udp_transport = 2;
+    // Take field
+    pub fn take_ip_address(&mut self) -> ::std::vec::Vec<u8> {
+        self.ip_address.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
 
-    pub fn clear_udp_transport(&mut self) {
-        self.udp_transport.clear();
+    pub fn get_ip_address(&self) -> &[u8] {
+        match self.ip_address.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
     }
 
-    pub fn has_udp_transport(&self) -> bool {
-        self.udp_transport.is_some()
+    fn get_ip_address_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.ip_address
+    }
+
+    fn mut_ip_address_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.ip_address
+    }
+
+    // required uint32 port = 2;
+
+    pub fn clear_port(&mut self) {
+        self.port = ::std::option::Option::None;
+    }
+
+    pub fn has_port(&self) -> bool {
+        self.port.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_udp_transport(&mut self, v: UdpTransport) {
-        self.udp_transport = ::protobuf::SingularPtrField::some(v);
+    pub fn set_port(&mut self, v: u32) {
+        self.port = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_udp_transport(&mut self) -> &mut UdpTransport {
-        if self.udp_transport.is_none() {
-            self.udp_transport.set_default();
-        };
-        self.udp_transport.as_mut().unwrap()
+    pub fn get_port(&self) -> u32 {
+        self.port.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_udp_transport(&mut self) -> UdpTransport {
-        self.udp_transport.take().unwrap_or_else(|| UdpTransport::new())
+    fn get_port_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.port
     }
 
-    pub fn get_udp_transport(&self) -> &UdpTransport {
-        self.udp_transport.as_ref().unwrap_or_else(|| UdpTransport::default_instance())
+    fn mut_port_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.port
     }
 }
 
-impl ::protobuf::Message for Transport {
+impl ::protobuf::Message for TcpTransport {
     fn is_initialized(&self) -> bool {
-        if self.transport_type.is_none() {
+        if self.ip_address.is_none() {
             return false;
-        };
+        }
+        if self.port.is_none() {
+            return false;
+        }
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_enum());
-                    self.transport_type = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ip_address)?;
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.udp_transport));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.port = ::std::option::Option::Some(tmp);
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -372,28 +397,25 @@ impl ::protobuf::Message for Transport {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.transport_type.iter() {
-            my_size += ::protobuf::rt::enum_size(1, *value);
-        };
-        for value in self.udp_transport.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        if let Some(ref v) = self.ip_address.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        if let Some(v) = self.port {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.transport_type {
-            try!(os.write_enum(1, v.value()));
-        };
-        if let Some(v) = self.udp_transport.as_ref() {
-            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.ip_address.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        if let Some(v) = self.port {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -409,25 +431,27 @@ impl ::protobuf::Message for Transport {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Transport>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for Transport {
-    fn new() -> Transport {
-        Transport::new()
+impl ::protobuf::MessageStatic for TcpTransport {
+    fn new() -> TcpTransport {
+        TcpTransport::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Transport>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<TcpTransport>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -435,18 +459,18 @@ impl ::protobuf::MessageStatic for Transport {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
-                    "transport_type",
-                    Transport::has_transport_type,
-                    Transport::get_transport_type,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "ip_address",
+                    TcpTransport::get_ip_address_for_reflect,
+                    TcpTransport::mut_ip_address_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "udp_transport",
-                    Transport::has_udp_transport,
-                    Transport::get_udp_transport,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "port",
+                    TcpTransport::get_port_for_reflect,
+                    TcpTransport::mut_port_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Transport>(
-                    "Transport",
+                ::protobuf::reflect::MessageDescriptor::new::<TcpTransport>(
+                    "TcpTransport",
                     fields,
                     file_descriptor_proto()
                 )
@@ -455,185 +479,115 @@ impl ::protobuf::MessageStatic for Transport {
     }
 }
 
-impl ::protobuf::Clear for Transport {
+impl ::protobuf::Clear for TcpTransport {
     fn clear(&mut self) {
-        self.clear_transport_type();
-        self.clear_udp_transport();
+        self.clear_ip_address();
+        self.clear_port();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for Transport {
-    fn eq(&self, other: &Transport) -> bool {
-        self.transport_type == other.transport_type &&
-        self.udp_transport == other.udp_transport &&
-        self.unknown_fields == other.unknown_fields
-    }
-}
-
-impl ::std::fmt::Debug for Transport {
+impl ::std::fmt::Debug for TcpTransport {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum Transport_Type {
-    UDP = 1,
-}
-
-impl ::protobuf::ProtobufEnum for Transport_Type {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
-
-    fn from_i32(value: i32) -> ::std::option::Option<Transport_Type> {
-        match value {
-            1 => ::std::option::Option::Some(Transport_Type::UDP),
-            _ => ::std::option::Option::None
-        }
-    }
-
-    fn values() -> &'static [Self] {
-        static values: &'static [Transport_Type] = &[
-            Transport_Type::UDP,
-        ];
-        values
-    }
-
-    fn enum_descriptor_static(_: Option<Transport_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Transport_Type", file_descriptor_proto())
-            })
-        }
+impl ::protobuf::reflect::ProtobufValue for TcpTransport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-impl ::std::marker::Copy for Transport_Type {
-}
-
-#[derive(Clone,Default)]
-pub struct Node {
+#[derive(PartialEq,Clone,Default)]
+pub struct MemoryTransport {
     // message fields
-    id: ::protobuf::SingularField<::std::string::String>,
-    transports: ::protobuf::RepeatedField<Transport>,
+    name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Node {}
+unsafe impl ::std::marker::Sync for MemoryTransport {}
 
-impl Node {
-    pub fn new() -> Node {
+impl MemoryTransport {
+    pub fn new() -> MemoryTransport {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Node {
-        static mut instance: ::protobuf::lazy::Lazy<Node> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static MemoryTransport {
+        static mut instance: ::protobuf::lazy::Lazy<MemoryTransport> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Node,
+            ptr: 0 as *const MemoryTransport,
         };
         unsafe {
-            instance.get(|| {
-                Node {
-                    id: ::protobuf::SingularField::none(),
-                    transports: ::protobuf::RepeatedField::new(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(MemoryTransport::new)
         }
     }
 
-    // required string id = 1;
+    // required string name = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id.clear();
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: ::std::string::String) {
-        self.id = ::protobuf::SingularField::some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&mut self) -> &mut ::std::string::String {
-        if self.id.is_none() {
-            self.id.set_default();
-        };
-        self.id.as_mut().unwrap()
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_id(&mut self) -> ::std::string::String {
-        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_id(&self) -> &str {
-        match self.id.as_ref() {
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    // repeated .Transport transports = 2;
-
-    pub fn clear_transports(&mut self) {
-        self.transports.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_transports(&mut self, v: ::protobuf::RepeatedField<Transport>) {
-        self.transports = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_transports(&mut self) -> &mut ::protobuf::RepeatedField<Transport> {
-        &mut self.transports
-    }
-
-    // Take field
-    pub fn take_transports(&mut self) -> ::protobuf::RepeatedField<Transport> {
-        ::std::mem::replace(&mut self.transports, ::protobuf::RepeatedField::new())
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
     }
 
-    pub fn get_transports(&self) -> &[Transport] {
-        &self.transports
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
     }
 }
 
-impl ::protobuf::Message for Node {
+impl ::protobuf::Message for MemoryTransport {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
+        if self.name.is_none() {
             return false;
-        };
+        }
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
-                },
-                2 => {
-                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.transports));
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -644,28 +598,19 @@ impl ::protobuf::Message for Node {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
-        };
-        for value in self.transports.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id.as_ref() {
-            try!(os.write_string(1, &v));
-        };
-        for v in self.transports.iter() {
-            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -681,25 +626,27 @@ impl ::protobuf::Message for Node {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Node>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for Node {
-    fn new() -> Node {
-        Node::new()
+impl ::protobuf::MessageStatic for MemoryTransport {
+    fn new() -> MemoryTransport {
+        MemoryTransport::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Node>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<MemoryTransport>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -707,17 +654,13 @@ impl ::protobuf::MessageStatic for Node {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "id",
-                    Node::has_id,
-                    Node::get_id,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    MemoryTransport::get_name_for_reflect,
+                    MemoryTransport::mut_name_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
-                    "transports",
-                    Node::get_transports,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Node>(
-                    "Node",
+                ::protobuf::reflect::MessageDescriptor::new::<MemoryTransport>(
+                    "MemoryTransport",
                     fields,
                     file_descriptor_proto()
                 )
@@ -726,156 +669,114 @@ impl ::protobuf::MessageStatic for Node {
     }
 }
 
-impl ::protobuf::Clear for Node {
+impl ::protobuf::Clear for MemoryTransport {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_transports();
+        self.clear_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for Node {
-    fn eq(&self, other: &Node) -> bool {
-        self.id == other.id &&
-        self.transports == other.transports &&
-        self.unknown_fields == other.unknown_fields
+impl ::std::fmt::Debug for MemoryTransport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::std::fmt::Debug for Node {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::protobuf::reflect::ProtobufValue for MemoryTransport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,Default)]
-pub struct FindNodeQuery {
+#[derive(PartialEq,Clone,Default)]
+pub struct UnixTransport {
     // message fields
-    origin: ::protobuf::SingularPtrField<Node>,
-    target: ::protobuf::SingularField<::std::string::String>,
+    path: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for FindNodeQuery {}
+unsafe impl ::std::marker::Sync for UnixTransport {}
 
-impl FindNodeQuery {
-    pub fn new() -> FindNodeQuery {
+impl UnixTransport {
+    pub fn new() -> UnixTransport {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static FindNodeQuery {
-        static mut instance: ::protobuf::lazy::Lazy<FindNodeQuery> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static UnixTransport {
+        static mut instance: ::protobuf::lazy::Lazy<UnixTransport> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const FindNodeQuery,
+            ptr: 0 as *const UnixTransport,
         };
         unsafe {
-            instance.get(|| {
-                FindNodeQuery {
-                    origin: ::protobuf::SingularPtrField::none(),
-                    target: ::protobuf::SingularField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(UnixTransport::new)
         }
     }
 
-    // required .Node origin = 1;
+    // required string path = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_path(&mut self) {
+        self.path.clear();
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_path(&self) -> bool {
+        self.path.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: Node) {
-        self.origin = ::protobuf::SingularPtrField::some(v);
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut Node {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        };
-        self.origin.as_mut().unwrap()
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        if self.path.is_none() {
+            self.path.set_default();
+        }
+        self.path.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin(&mut self) -> Node {
-        self.origin.take().unwrap_or_else(|| Node::new())
-    }
-
-    pub fn get_origin(&self) -> &Node {
-        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
-    }
-
-    // required string target = 2;
-
-    pub fn clear_target(&mut self) {
-        self.target.clear();
-    }
-
-    pub fn has_target(&self) -> bool {
-        self.target.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_target(&mut self, v: ::std::string::String) {
-        self.target = ::protobuf::SingularField::some(v);
+    pub fn take_path(&mut self) -> ::std::string::String {
+        self.path.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_target(&mut self) -> &mut ::std::string::String {
-        if self.target.is_none() {
-            self.target.set_default();
-        };
-        self.target.as_mut().unwrap()
+    pub fn get_path(&self) -> &str {
+        match self.path.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    // Take field
-    pub fn take_target(&mut self) -> ::std::string::String {
-        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_path_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.path
     }
 
-    pub fn get_target(&self) -> &str {
-        match self.target.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_path_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.path
     }
 }
 
-impl ::protobuf::Message for FindNodeQuery {
+impl ::protobuf::Message for UnixTransport {
     fn is_initialized(&self) -> bool {
-        if self.origin.is_none() {
-            return false;
-        };
-        if self.target.is_none() {
+        if self.path.is_none() {
             return false;
-        };
+        }
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin));
-                },
-                2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target));
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.path)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -886,28 +787,19 @@ impl ::protobuf::Message for FindNodeQuery {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.target.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
+        if let Some(ref v) = self.path.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin.as_ref() {
-            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.target.as_ref() {
-            try!(os.write_string(2, &v));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.path.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -923,25 +815,27 @@ impl ::protobuf::Message for FindNodeQuery {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<FindNodeQuery>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for FindNodeQuery {
-    fn new() -> FindNodeQuery {
-        FindNodeQuery::new()
+impl ::protobuf::MessageStatic for UnixTransport {
+    fn new() -> UnixTransport {
+        UnixTransport::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<FindNodeQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<UnixTransport>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -949,18 +843,13 @@ impl ::protobuf::MessageStatic for FindNodeQuery {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "origin",
-                    FindNodeQuery::has_origin,
-                    FindNodeQuery::get_origin,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "target",
-                    FindNodeQuery::has_target,
-                    FindNodeQuery::get_target,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    UnixTransport::get_path_for_reflect,
+                    UnixTransport::mut_path_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<FindNodeQuery>(
-                    "FindNodeQuery",
+                ::protobuf::reflect::MessageDescriptor::new::<UnixTransport>(
+                    "UnixTransport",
                     fields,
                     file_descriptor_proto()
                 )
@@ -969,142 +858,165 @@ impl ::protobuf::MessageStatic for FindNodeQuery {
     }
 }
 
-impl ::protobuf::Clear for FindNodeQuery {
+impl ::protobuf::Clear for UnixTransport {
     fn clear(&mut self) {
-        self.clear_origin();
-        self.clear_target();
+        self.clear_path();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for FindNodeQuery {
-    fn eq(&self, other: &FindNodeQuery) -> bool {
-        self.origin == other.origin &&
-        self.target == other.target &&
-        self.unknown_fields == other.unknown_fields
+impl ::std::fmt::Debug for UnixTransport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::std::fmt::Debug for FindNodeQuery {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::protobuf::reflect::ProtobufValue for UnixTransport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,Default)]
-pub struct FindNodeResponse {
+#[derive(PartialEq,Clone,Default)]
+pub struct WebSocketTransport {
     // message fields
-    origin: ::protobuf::SingularPtrField<Node>,
-    nodes: ::protobuf::RepeatedField<Node>,
+    host: ::protobuf::SingularField<::std::string::String>,
+    path: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for FindNodeResponse {}
+unsafe impl ::std::marker::Sync for WebSocketTransport {}
 
-impl FindNodeResponse {
-    pub fn new() -> FindNodeResponse {
+impl WebSocketTransport {
+    pub fn new() -> WebSocketTransport {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static FindNodeResponse {
-        static mut instance: ::protobuf::lazy::Lazy<FindNodeResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static WebSocketTransport {
+        static mut instance: ::protobuf::lazy::Lazy<WebSocketTransport> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const FindNodeResponse,
+            ptr: 0 as *const WebSocketTransport,
         };
         unsafe {
-            instance.get(|| {
-                FindNodeResponse {
-                    origin: ::protobuf::SingularPtrField::none(),
-                    nodes: ::protobuf::RepeatedField::new(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(WebSocketTransport::new)
         }
     }
 
-    // required .Node origin = 1;
+    // required string host = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_host(&mut self) {
+        self.host.clear();
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_host(&self) -> bool {
+        self.host.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: Node) {
-        self.origin = ::protobuf::SingularPtrField::some(v);
+    pub fn set_host(&mut self, v: ::std::string::String) {
+        self.host = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut Node {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        };
-        self.origin.as_mut().unwrap()
+    pub fn mut_host(&mut self) -> &mut ::std::string::String {
+        if self.host.is_none() {
+            self.host.set_default();
+        }
+        self.host.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin(&mut self) -> Node {
-        self.origin.take().unwrap_or_else(|| Node::new())
+    pub fn take_host(&mut self) -> ::std::string::String {
+        self.host.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_origin(&self) -> &Node {
-        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    pub fn get_host(&self) -> &str {
+        match self.host.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    // repeated .Node nodes = 2;
+    fn get_host_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.host
+    }
 
-    pub fn clear_nodes(&mut self) {
-        self.nodes.clear();
+    fn mut_host_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.host
+    }
+
+    // required string path = 2;
+
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    pub fn has_path(&self) -> bool {
+        self.path.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<Node>) {
-        self.nodes = v;
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
-        &mut self.nodes
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        if self.path.is_none() {
+            self.path.set_default();
+        }
+        self.path.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<Node> {
-        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    pub fn take_path(&mut self) -> ::std::string::String {
+        self.path.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_nodes(&self) -> &[Node] {
-        &self.nodes
+    pub fn get_path(&self) -> &str {
+        match self.path.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_path_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.path
+    }
+
+    fn mut_path_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.path
     }
 }
 
-impl ::protobuf::Message for FindNodeResponse {
+impl ::protobuf::Message for WebSocketTransport {
     fn is_initialized(&self) -> bool {
-        if self.origin.is_none() {
+        if self.host.is_none() {
             return false;
-        };
+        }
+        if self.path.is_none() {
+            return false;
+        }
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin));
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.host)?;
                 },
                 2 => {
-                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nodes));
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.path)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -1115,31 +1027,25 @@ impl ::protobuf::Message for FindNodeResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.nodes.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        if let Some(ref v) = self.host.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.path.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin.as_ref() {
-            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        for v in self.nodes.iter() {
-            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.host.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.path.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -1155,25 +1061,27 @@ impl ::protobuf::Message for FindNodeResponse {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<FindNodeResponse>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for FindNodeResponse {
-    fn new() -> FindNodeResponse {
-        FindNodeResponse::new()
+impl ::protobuf::MessageStatic for WebSocketTransport {
+    fn new() -> WebSocketTransport {
+        WebSocketTransport::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<FindNodeResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<WebSocketTransport>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1181,17 +1089,18 @@ impl ::protobuf::MessageStatic for FindNodeResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "origin",
-                    FindNodeResponse::has_origin,
-                    FindNodeResponse::get_origin,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "host",
+                    WebSocketTransport::get_host_for_reflect,
+                    WebSocketTransport::mut_host_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
-                    "nodes",
-                    FindNodeResponse::get_nodes,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    WebSocketTransport::get_path_for_reflect,
+                    WebSocketTransport::mut_path_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<FindNodeResponse>(
-                    "FindNodeResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<WebSocketTransport>(
+                    "WebSocketTransport",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1200,112 +1109,117 @@ impl ::protobuf::MessageStatic for FindNodeResponse {
     }
 }
 
-impl ::protobuf::Clear for FindNodeResponse {
+impl ::protobuf::Clear for WebSocketTransport {
     fn clear(&mut self) {
-        self.clear_origin();
-        self.clear_nodes();
+        self.clear_host();
+        self.clear_path();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for FindNodeResponse {
-    fn eq(&self, other: &FindNodeResponse) -> bool {
-        self.origin == other.origin &&
-        self.nodes == other.nodes &&
-        self.unknown_fields == other.unknown_fields
+impl ::std::fmt::Debug for WebSocketTransport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::std::fmt::Debug for FindNodeResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::protobuf::reflect::ProtobufValue for WebSocketTransport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,Default)]
-pub struct PingQuery {
+#[derive(PartialEq,Clone,Default)]
+pub struct RelayedTransport {
     // message fields
-    origin: ::protobuf::SingularPtrField<Node>,
+    relay: ::protobuf::SingularPtrField<Node>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for PingQuery {}
+unsafe impl ::std::marker::Sync for RelayedTransport {}
 
-impl PingQuery {
-    pub fn new() -> PingQuery {
+impl RelayedTransport {
+    pub fn new() -> RelayedTransport {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static PingQuery {
-        static mut instance: ::protobuf::lazy::Lazy<PingQuery> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static RelayedTransport {
+        static mut instance: ::protobuf::lazy::Lazy<RelayedTransport> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PingQuery,
+            ptr: 0 as *const RelayedTransport,
         };
         unsafe {
-            instance.get(|| {
-                PingQuery {
-                    origin: ::protobuf::SingularPtrField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(RelayedTransport::new)
         }
     }
 
-    // required .Node origin = 1;
+    // required .Node relay = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_relay(&mut self) {
+        self.relay.clear();
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_relay(&self) -> bool {
+        self.relay.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: Node) {
-        self.origin = ::protobuf::SingularPtrField::some(v);
+    pub fn set_relay(&mut self, v: Node) {
+        self.relay = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut Node {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        };
-        self.origin.as_mut().unwrap()
+    pub fn mut_relay(&mut self) -> &mut Node {
+        if self.relay.is_none() {
+            self.relay.set_default();
+        }
+        self.relay.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin(&mut self) -> Node {
-        self.origin.take().unwrap_or_else(|| Node::new())
+    pub fn take_relay(&mut self) -> Node {
+        self.relay.take().unwrap_or_else(|| Node::new())
     }
 
-    pub fn get_origin(&self) -> &Node {
-        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    pub fn get_relay(&self) -> &Node {
+        self.relay.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_relay_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.relay
+    }
+
+    fn mut_relay_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.relay
     }
 }
 
-impl ::protobuf::Message for PingQuery {
+impl ::protobuf::Message for RelayedTransport {
     fn is_initialized(&self) -> bool {
-        if self.origin.is_none() {
+        if self.relay.is_none() {
             return false;
+        }
+        for v in &self.relay {
+            if !v.is_initialized() {
+                return false;
+            }
         };
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin));
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.relay)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -1316,22 +1230,22 @@ impl ::protobuf::Message for PingQuery {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin.iter() {
-            let len = value.compute_size();
+        if let Some(ref v) = self.relay.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin.as_ref() {
-            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.relay.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -1347,25 +1261,27 @@ impl ::protobuf::Message for PingQuery {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<PingQuery>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for PingQuery {
-    fn new() -> PingQuery {
-        PingQuery::new()
+impl ::protobuf::MessageStatic for RelayedTransport {
+    fn new() -> RelayedTransport {
+        RelayedTransport::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<PingQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<RelayedTransport>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1373,13 +1289,13 @@ impl ::protobuf::MessageStatic for PingQuery {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "origin",
-                    PingQuery::has_origin,
-                    PingQuery::get_origin,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "relay",
+                    RelayedTransport::get_relay_for_reflect,
+                    RelayedTransport::mut_relay_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PingQuery>(
-                    "PingQuery",
+                ::protobuf::reflect::MessageDescriptor::new::<RelayedTransport>(
+                    "RelayedTransport",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1388,372 +1304,4837 @@ impl ::protobuf::MessageStatic for PingQuery {
     }
 }
 
-impl ::protobuf::Clear for PingQuery {
+impl ::protobuf::Clear for RelayedTransport {
     fn clear(&mut self) {
-        self.clear_origin();
+        self.clear_relay();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for PingQuery {
-    fn eq(&self, other: &PingQuery) -> bool {
-        self.origin == other.origin &&
-        self.unknown_fields == other.unknown_fields
+impl ::std::fmt::Debug for RelayedTransport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::std::fmt::Debug for PingQuery {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::protobuf::reflect::ProtobufValue for RelayedTransport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,Default)]
-pub struct PingResponse {
+#[derive(PartialEq,Clone,Default)]
+pub struct Transport {
     // message fields
-    origin: ::protobuf::SingularPtrField<Node>,
+    transport_type: ::std::option::Option<Transport_Type>,
+    udp_transport: ::protobuf::SingularPtrField<UdpTransport>,
+    tcp_transport: ::protobuf::SingularPtrField<TcpTransport>,
+    memory_transport: ::protobuf::SingularPtrField<MemoryTransport>,
+    unix_transport: ::protobuf::SingularPtrField<UnixTransport>,
+    websocket_transport: ::protobuf::SingularPtrField<WebSocketTransport>,
+    relayed_transport: ::protobuf::SingularPtrField<RelayedTransport>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for PingResponse {}
+unsafe impl ::std::marker::Sync for Transport {}
 
-impl PingResponse {
-    pub fn new() -> PingResponse {
+impl Transport {
+    pub fn new() -> Transport {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static PingResponse {
-        static mut instance: ::protobuf::lazy::Lazy<PingResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static Transport {
+        static mut instance: ::protobuf::lazy::Lazy<Transport> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PingResponse,
+            ptr: 0 as *const Transport,
         };
         unsafe {
-            instance.get(|| {
-                PingResponse {
-                    origin: ::protobuf::SingularPtrField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(Transport::new)
         }
     }
 
-    // required .Node origin = 1;
+    // optional .Transport.Type transport_type = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_transport_type(&mut self) {
+        self.transport_type = ::std::option::Option::None;
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_transport_type(&self) -> bool {
+        self.transport_type.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: Node) {
-        self.origin = ::protobuf::SingularPtrField::some(v);
+    pub fn set_transport_type(&mut self, v: Transport_Type) {
+        self.transport_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_transport_type(&self) -> Transport_Type {
+        self.transport_type.unwrap_or(Transport_Type::UDP)
+    }
+
+    fn get_transport_type_for_reflect(&self) -> &::std::option::Option<Transport_Type> {
+        &self.transport_type
+    }
+
+    fn mut_transport_type_for_reflect(&mut self) -> &mut ::std::option::Option<Transport_Type> {
+        &mut self.transport_type
+    }
+
+    // optional .UdpTransport udp_transport = 2;
+
+    pub fn clear_udp_transport(&mut self) {
+        self.udp_transport.clear();
+    }
+
+    pub fn has_udp_transport(&self) -> bool {
+        self.udp_transport.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_udp_transport(&mut self, v: UdpTransport) {
+        self.udp_transport = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut Node {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        };
-        self.origin.as_mut().unwrap()
+    pub fn mut_udp_transport(&mut self) -> &mut UdpTransport {
+        if self.udp_transport.is_none() {
+            self.udp_transport.set_default();
+        }
+        self.udp_transport.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin(&mut self) -> Node {
-        self.origin.take().unwrap_or_else(|| Node::new())
+    pub fn take_udp_transport(&mut self) -> UdpTransport {
+        self.udp_transport.take().unwrap_or_else(|| UdpTransport::new())
     }
 
-    pub fn get_origin(&self) -> &Node {
-        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    pub fn get_udp_transport(&self) -> &UdpTransport {
+        self.udp_transport.as_ref().unwrap_or_else(|| UdpTransport::default_instance())
     }
-}
 
-impl ::protobuf::Message for PingResponse {
-    fn is_initialized(&self) -> bool {
-        if self.origin.is_none() {
-            return false;
-        };
-        true
+    fn get_udp_transport_for_reflect(&self) -> &::protobuf::SingularPtrField<UdpTransport> {
+        &self.udp_transport
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
-            match field_number {
-                1 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin));
-                },
-                _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    fn mut_udp_transport_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<UdpTransport> {
+        &mut self.udp_transport
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        for value in self.origin.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    // optional .TcpTransport tcp_transport = 3;
+
+    pub fn clear_tcp_transport(&mut self) {
+        self.tcp_transport.clear();
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin.as_ref() {
-            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
-        ::std::result::Result::Ok(())
+    pub fn has_tcp_transport(&self) -> bool {
+        self.tcp_transport.is_some()
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Param is passed by value, moved
+    pub fn set_tcp_transport(&mut self, v: TcpTransport) {
+        self.tcp_transport = ::protobuf::SingularPtrField::some(v);
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tcp_transport(&mut self) -> &mut TcpTransport {
+        if self.tcp_transport.is_none() {
+            self.tcp_transport.set_default();
+        }
+        self.tcp_transport.as_mut().unwrap()
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    // Take field
+    pub fn take_tcp_transport(&mut self) -> TcpTransport {
+        self.tcp_transport.take().unwrap_or_else(|| TcpTransport::new())
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<PingResponse>()
+    pub fn get_tcp_transport(&self) -> &TcpTransport {
+        self.tcp_transport.as_ref().unwrap_or_else(|| TcpTransport::default_instance())
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn get_tcp_transport_for_reflect(&self) -> &::protobuf::SingularPtrField<TcpTransport> {
+        &self.tcp_transport
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    fn mut_tcp_transport_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<TcpTransport> {
+        &mut self.tcp_transport
     }
-}
 
-impl ::protobuf::MessageStatic for PingResponse {
-    fn new() -> PingResponse {
-        PingResponse::new()
+    // optional .MemoryTransport memory_transport = 4;
+
+    pub fn clear_memory_transport(&mut self) {
+        self.memory_transport.clear();
     }
 
-    fn descriptor_static(_: ::std::option::Option<PingResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "origin",
-                    PingResponse::has_origin,
-                    PingResponse::get_origin,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<PingResponse>(
-                    "PingResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
+    pub fn has_memory_transport(&self) -> bool {
+        self.memory_transport.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_transport(&mut self, v: MemoryTransport) {
+        self.memory_transport = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_memory_transport(&mut self) -> &mut MemoryTransport {
+        if self.memory_transport.is_none() {
+            self.memory_transport.set_default();
         }
+        self.memory_transport.as_mut().unwrap()
     }
-}
 
-impl ::protobuf::Clear for PingResponse {
-    fn clear(&mut self) {
-        self.clear_origin();
-        self.unknown_fields.clear();
+    // Take field
+    pub fn take_memory_transport(&mut self) -> MemoryTransport {
+        self.memory_transport.take().unwrap_or_else(|| MemoryTransport::new())
     }
-}
 
-impl ::std::cmp::PartialEq for PingResponse {
-    fn eq(&self, other: &PingResponse) -> bool {
-        self.origin == other.origin &&
-        self.unknown_fields == other.unknown_fields
+    pub fn get_memory_transport(&self) -> &MemoryTransport {
+        self.memory_transport.as_ref().unwrap_or_else(|| MemoryTransport::default_instance())
     }
-}
 
-impl ::std::fmt::Debug for PingResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    fn get_memory_transport_for_reflect(&self) -> &::protobuf::SingularPtrField<MemoryTransport> {
+        &self.memory_transport
     }
-}
 
-#[derive(Clone,Default)]
-pub struct PacketQuery {
-    // message fields
-    origin: ::protobuf::SingularPtrField<Node>,
-    payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
-}
+    fn mut_memory_transport_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<MemoryTransport> {
+        &mut self.memory_transport
+    }
 
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for PacketQuery {}
+    // optional .UnixTransport unix_transport = 5;
 
-impl PacketQuery {
-    pub fn new() -> PacketQuery {
-        ::std::default::Default::default()
+    pub fn clear_unix_transport(&mut self) {
+        self.unix_transport.clear();
     }
 
-    pub fn default_instance() -> &'static PacketQuery {
-        static mut instance: ::protobuf::lazy::Lazy<PacketQuery> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PacketQuery,
-        };
-        unsafe {
-            instance.get(|| {
-                PacketQuery {
-                    origin: ::protobuf::SingularPtrField::none(),
-                    payload: ::protobuf::SingularField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+    pub fn has_unix_transport(&self) -> bool {
+        self.unix_transport.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unix_transport(&mut self, v: UnixTransport) {
+        self.unix_transport = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_unix_transport(&mut self) -> &mut UnixTransport {
+        if self.unix_transport.is_none() {
+            self.unix_transport.set_default();
         }
+        self.unix_transport.as_mut().unwrap()
     }
 
-    // required .Node origin = 1;
+    // Take field
+    pub fn take_unix_transport(&mut self) -> UnixTransport {
+        self.unix_transport.take().unwrap_or_else(|| UnixTransport::new())
+    }
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn get_unix_transport(&self) -> &UnixTransport {
+        self.unix_transport.as_ref().unwrap_or_else(|| UnixTransport::default_instance())
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    fn get_unix_transport_for_reflect(&self) -> &::protobuf::SingularPtrField<UnixTransport> {
+        &self.unix_transport
+    }
+
+    fn mut_unix_transport_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<UnixTransport> {
+        &mut self.unix_transport
+    }
+
+    // optional .WebSocketTransport websocket_transport = 6;
+
+    pub fn clear_websocket_transport(&mut self) {
+        self.websocket_transport.clear();
+    }
+
+    pub fn has_websocket_transport(&self) -> bool {
+        self.websocket_transport.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: Node) {
-        self.origin = ::protobuf::SingularPtrField::some(v);
+    pub fn set_websocket_transport(&mut self, v: WebSocketTransport) {
+        self.websocket_transport = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut Node {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        };
-        self.origin.as_mut().unwrap()
+    pub fn mut_websocket_transport(&mut self) -> &mut WebSocketTransport {
+        if self.websocket_transport.is_none() {
+            self.websocket_transport.set_default();
+        }
+        self.websocket_transport.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin(&mut self) -> Node {
-        self.origin.take().unwrap_or_else(|| Node::new())
+    pub fn take_websocket_transport(&mut self) -> WebSocketTransport {
+        self.websocket_transport.take().unwrap_or_else(|| WebSocketTransport::new())
     }
 
-    pub fn get_origin(&self) -> &Node {
-        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    pub fn get_websocket_transport(&self) -> &WebSocketTransport {
+        self.websocket_transport.as_ref().unwrap_or_else(|| WebSocketTransport::default_instance())
     }
 
-    // required bytes payload = 2;
+    fn get_websocket_transport_for_reflect(&self) -> &::protobuf::SingularPtrField<WebSocketTransport> {
+        &self.websocket_transport
+    }
 
-    pub fn clear_payload(&mut self) {
-        self.payload.clear();
+    fn mut_websocket_transport_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<WebSocketTransport> {
+        &mut self.websocket_transport
     }
 
-    pub fn has_payload(&self) -> bool {
-        self.payload.is_some()
+    // optional .RelayedTransport relayed_transport = 7;
+
+    pub fn clear_relayed_transport(&mut self) {
+        self.relayed_transport.clear();
+    }
+
+    pub fn has_relayed_transport(&self) -> bool {
+        self.relayed_transport.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
-        self.payload = ::protobuf::SingularField::some(v);
+    pub fn set_relayed_transport(&mut self, v: RelayedTransport) {
+        self.relayed_transport = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.payload.is_none() {
-            self.payload.set_default();
-        };
-        self.payload.as_mut().unwrap()
+    pub fn mut_relayed_transport(&mut self) -> &mut RelayedTransport {
+        if self.relayed_transport.is_none() {
+            self.relayed_transport.set_default();
+        }
+        self.relayed_transport.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
-        self.payload.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn take_relayed_transport(&mut self) -> RelayedTransport {
+        self.relayed_transport.take().unwrap_or_else(|| RelayedTransport::new())
     }
 
-    pub fn get_payload(&self) -> &[u8] {
-        match self.payload.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+    pub fn get_relayed_transport(&self) -> &RelayedTransport {
+        self.relayed_transport.as_ref().unwrap_or_else(|| RelayedTransport::default_instance())
+    }
+
+    fn get_relayed_transport_for_reflect(&self) -> &::protobuf::SingularPtrField<RelayedTransport> {
+        &self.relayed_transport
+    }
+
+    fn mut_relayed_transport_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<RelayedTransport> {
+        &mut self.relayed_transport
     }
 }
 
-impl ::protobuf::Message for PacketQuery {
+impl ::protobuf::Message for Transport {
     fn is_initialized(&self) -> bool {
-        if self.origin.is_none() {
-            return false;
+        for v in &self.udp_transport {
+            if !v.is_initialized() {
+                return false;
+            }
         };
-        if self.payload.is_none() {
-            return false;
+        for v in &self.tcp_transport {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.memory_transport {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.unix_transport {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.websocket_transport {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.relayed_transport {
+            if !v.is_initialized() {
+                return false;
+            }
         };
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin));
+                    ::protobuf::rt::read_proto2_enum_with_unknown_fields_into(wire_type, is, &mut self.transport_type, 1, &mut self.unknown_fields)?
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload));
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.udp_transport)?;
                 },
-                _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.tcp_transport)?;
                 },
-            };
-        }
-        ::std::result::Result::Ok(())
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.memory_transport)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.unix_transport)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.websocket_transport)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.relayed_transport)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.transport_type {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        }
+        if let Some(ref v) = self.udp_transport.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.tcp_transport.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.memory_transport.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.unix_transport.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.websocket_transport.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.relayed_transport.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.transport_type {
+            os.write_enum(1, v.value())?;
+        }
+        if let Some(ref v) = self.udp_transport.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.tcp_transport.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.memory_transport.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.unix_transport.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.websocket_transport.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.relayed_transport.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Transport {
+    fn new() -> Transport {
+        Transport::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Transport>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Transport_Type>>(
+                    "transport_type",
+                    Transport::get_transport_type_for_reflect,
+                    Transport::mut_transport_type_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UdpTransport>>(
+                    "udp_transport",
+                    Transport::get_udp_transport_for_reflect,
+                    Transport::mut_udp_transport_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TcpTransport>>(
+                    "tcp_transport",
+                    Transport::get_tcp_transport_for_reflect,
+                    Transport::mut_tcp_transport_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MemoryTransport>>(
+                    "memory_transport",
+                    Transport::get_memory_transport_for_reflect,
+                    Transport::mut_memory_transport_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnixTransport>>(
+                    "unix_transport",
+                    Transport::get_unix_transport_for_reflect,
+                    Transport::mut_unix_transport_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WebSocketTransport>>(
+                    "websocket_transport",
+                    Transport::get_websocket_transport_for_reflect,
+                    Transport::mut_websocket_transport_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RelayedTransport>>(
+                    "relayed_transport",
+                    Transport::get_relayed_transport_for_reflect,
+                    Transport::mut_relayed_transport_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Transport>(
+                    "Transport",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Transport {
+    fn clear(&mut self) {
+        self.clear_transport_type();
+        self.clear_udp_transport();
+        self.clear_tcp_transport();
+        self.clear_memory_transport();
+        self.clear_unix_transport();
+        self.clear_websocket_transport();
+        self.clear_relayed_transport();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Transport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Transport {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Transport_Type {
+    UDP = 1,
+    TCP = 2,
+    MEMORY = 3,
+    UNIX = 4,
+    WEBSOCKET = 5,
+    RELAYED = 6,
+}
+
+impl ::protobuf::ProtobufEnum for Transport_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Transport_Type> {
+        match value {
+            1 => ::std::option::Option::Some(Transport_Type::UDP),
+            2 => ::std::option::Option::Some(Transport_Type::TCP),
+            3 => ::std::option::Option::Some(Transport_Type::MEMORY),
+            4 => ::std::option::Option::Some(Transport_Type::UNIX),
+            5 => ::std::option::Option::Some(Transport_Type::WEBSOCKET),
+            6 => ::std::option::Option::Some(Transport_Type::RELAYED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Transport_Type] = &[
+            Transport_Type::UDP,
+            Transport_Type::TCP,
+            Transport_Type::MEMORY,
+            Transport_Type::UNIX,
+            Transport_Type::WEBSOCKET,
+            Transport_Type::RELAYED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<Transport_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Transport_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Transport_Type {
+}
+
+impl ::protobuf::reflect::ProtobufValue for Transport_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Node {
+    // message fields
+    id: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    transports: ::protobuf::RepeatedField<Transport>,
+    public_key: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    capabilities: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Node {}
+
+impl Node {
+    pub fn new() -> Node {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Node {
+        static mut instance: ::protobuf::lazy::Lazy<Node> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Node,
+        };
+        unsafe {
+            instance.get(Node::new)
+        }
+    }
+
+    // required bytes id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.id.is_none() {
+            self.id.set_default();
+        }
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::vec::Vec<u8> {
+        self.id.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_id(&self) -> &[u8] {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_id_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.id
+    }
+
+    // repeated .Transport transports = 2;
+
+    pub fn clear_transports(&mut self) {
+        self.transports.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transports(&mut self, v: ::protobuf::RepeatedField<Transport>) {
+        self.transports = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_transports(&mut self) -> &mut ::protobuf::RepeatedField<Transport> {
+        &mut self.transports
+    }
+
+    // Take field
+    pub fn take_transports(&mut self) -> ::protobuf::RepeatedField<Transport> {
+        ::std::mem::replace(&mut self.transports, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_transports(&self) -> &[Transport] {
+        &self.transports
+    }
+
+    fn get_transports_for_reflect(&self) -> &::protobuf::RepeatedField<Transport> {
+        &self.transports
+    }
+
+    fn mut_transports_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Transport> {
+        &mut self.transports
+    }
+
+    // optional bytes public_key = 3;
+
+    pub fn clear_public_key(&mut self) {
+        self.public_key.clear();
+    }
+
+    pub fn has_public_key(&self) -> bool {
+        self.public_key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.public_key = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.public_key.is_none() {
+            self.public_key.set_default();
+        }
+        self.public_key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        self.public_key.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_public_key(&self) -> &[u8] {
+        match self.public_key.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_public_key_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.public_key
+    }
+
+    fn mut_public_key_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.public_key
+    }
+
+    // optional uint64 capabilities = 4;
+
+    pub fn clear_capabilities(&mut self) {
+        self.capabilities = ::std::option::Option::None;
+    }
+
+    pub fn has_capabilities(&self) -> bool {
+        self.capabilities.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capabilities(&mut self, v: u64) {
+        self.capabilities = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_capabilities(&self) -> u64 {
+        self.capabilities.unwrap_or(0)
+    }
+
+    fn get_capabilities_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.capabilities
+    }
+
+    fn mut_capabilities_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.capabilities
+    }
+}
+
+impl ::protobuf::Message for Node {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        }
+        for v in &self.transports {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.transports)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.public_key)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.capabilities = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.id.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        for value in &self.transports {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.public_key.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(3, &v);
+        }
+        if let Some(v) = self.capabilities {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.id.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        for v in &self.transports {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.public_key.as_ref() {
+            os.write_bytes(3, &v)?;
+        }
+        if let Some(v) = self.capabilities {
+            os.write_uint64(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Node {
+    fn new() -> Node {
+        Node::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Node>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "id",
+                    Node::get_id_for_reflect,
+                    Node::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Transport>>(
+                    "transports",
+                    Node::get_transports_for_reflect,
+                    Node::mut_transports_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "public_key",
+                    Node::get_public_key_for_reflect,
+                    Node::mut_public_key_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "capabilities",
+                    Node::get_capabilities_for_reflect,
+                    Node::mut_capabilities_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Node>(
+                    "Node",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Node {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_transports();
+        self.clear_public_key();
+        self.clear_capabilities();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Node {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FindNodeQuery {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    target: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for FindNodeQuery {}
+
+impl FindNodeQuery {
+    pub fn new() -> FindNodeQuery {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static FindNodeQuery {
+        static mut instance: ::protobuf::lazy::Lazy<FindNodeQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FindNodeQuery,
+        };
+        unsafe {
+            instance.get(FindNodeQuery::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+
+    // required bytes target = 2;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::vec::Vec<u8>) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::vec::Vec<u8> {
+        self.target.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_target(&self) -> &[u8] {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.target
+    }
+}
+
+impl ::protobuf::Message for FindNodeQuery {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        if self.target.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.target)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for FindNodeQuery {
+    fn new() -> FindNodeQuery {
+        FindNodeQuery::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<FindNodeQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    FindNodeQuery::get_origin_for_reflect,
+                    FindNodeQuery::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "target",
+                    FindNodeQuery::get_target_for_reflect,
+                    FindNodeQuery::mut_target_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FindNodeQuery>(
+                    "FindNodeQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for FindNodeQuery {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_target();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FindNodeQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindNodeQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FindNodeResponse {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    nodes: ::protobuf::RepeatedField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for FindNodeResponse {}
+
+impl FindNodeResponse {
+    pub fn new() -> FindNodeResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static FindNodeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<FindNodeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FindNodeResponse,
+        };
+        unsafe {
+            instance.get(FindNodeResponse::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+
+    // repeated .Node nodes = 2;
+
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    fn get_nodes_for_reflect(&self) -> &::protobuf::RepeatedField<Node> {
+        &self.nodes
+    }
+
+    fn mut_nodes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+}
+
+impl ::protobuf::Message for FindNodeResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.nodes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nodes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.nodes {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for FindNodeResponse {
+    fn new() -> FindNodeResponse {
+        FindNodeResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<FindNodeResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    FindNodeResponse::get_origin_for_reflect,
+                    FindNodeResponse::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "nodes",
+                    FindNodeResponse::get_nodes_for_reflect,
+                    FindNodeResponse::mut_nodes_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FindNodeResponse>(
+                    "FindNodeResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for FindNodeResponse {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_nodes();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FindNodeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindNodeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PingQuery {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PingQuery {}
+
+impl PingQuery {
+    pub fn new() -> PingQuery {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PingQuery {
+        static mut instance: ::protobuf::lazy::Lazy<PingQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PingQuery,
+        };
+        unsafe {
+            instance.get(PingQuery::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for PingQuery {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PingQuery {
+    fn new() -> PingQuery {
+        PingQuery::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PingQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    PingQuery::get_origin_for_reflect,
+                    PingQuery::mut_origin_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PingQuery>(
+                    "PingQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PingQuery {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PingQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PingQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PingResponse {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PingResponse {}
+
+impl PingResponse {
+    pub fn new() -> PingResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PingResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PingResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PingResponse,
+        };
+        unsafe {
+            instance.get(PingResponse::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for PingResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PingResponse {
+    fn new() -> PingResponse {
+        PingResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PingResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    PingResponse::get_origin_for_reflect,
+                    PingResponse::mut_origin_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PingResponse>(
+                    "PingResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PingResponse {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PingResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PingResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Fragment {
+    // message fields
+    id: ::std::option::Option<u32>,
+    index: ::std::option::Option<u32>,
+    count: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Fragment {}
+
+impl Fragment {
+    pub fn new() -> Fragment {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Fragment {
+        static mut instance: ::protobuf::lazy::Lazy<Fragment> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Fragment,
+        };
+        unsafe {
+            instance.get(Fragment::new)
+        }
+    }
+
+    // required uint32 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u32) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.id
+    }
+
+    // required uint32 index = 2;
+
+    pub fn clear_index(&mut self) {
+        self.index = ::std::option::Option::None;
+    }
+
+    pub fn has_index(&self) -> bool {
+        self.index.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index.unwrap_or(0)
+    }
+
+    fn get_index_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.index
+    }
+
+    fn mut_index_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.index
+    }
+
+    // required uint32 count = 3;
+
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_count(&self) -> u32 {
+        self.count.unwrap_or(0)
+    }
+
+    fn get_count_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.count
+    }
+
+    fn mut_count_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.count
+    }
+}
+
+impl ::protobuf::Message for Fragment {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        }
+        if self.index.is_none() {
+            return false;
+        }
+        if self.count.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.index = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.index {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.count {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.index {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.count {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Fragment {
+    fn new() -> Fragment {
+        Fragment::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Fragment>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "id",
+                    Fragment::get_id_for_reflect,
+                    Fragment::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "index",
+                    Fragment::get_index_for_reflect,
+                    Fragment::mut_index_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "count",
+                    Fragment::get_count_for_reflect,
+                    Fragment::mut_count_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Fragment>(
+                    "Fragment",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Fragment {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_index();
+        self.clear_count();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Fragment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Fragment {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PacketQuery {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    fragment: ::protobuf::SingularPtrField<Fragment>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PacketQuery {}
+
+impl PacketQuery {
+    pub fn new() -> PacketQuery {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PacketQuery {
+        static mut instance: ::protobuf::lazy::Lazy<PacketQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PacketQuery,
+        };
+        unsafe {
+            instance.get(PacketQuery::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+
+    // required bytes payload = 2;
+
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
+        self.payload = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.payload.is_none() {
+            self.payload.set_default();
+        }
+        self.payload.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
+        self.payload.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        match self.payload.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_payload_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.payload
+    }
+
+    fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.payload
+    }
+
+    // optional .Fragment fragment = 3;
+
+    pub fn clear_fragment(&mut self) {
+        self.fragment.clear();
+    }
+
+    pub fn has_fragment(&self) -> bool {
+        self.fragment.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fragment(&mut self, v: Fragment) {
+        self.fragment = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fragment(&mut self) -> &mut Fragment {
+        if self.fragment.is_none() {
+            self.fragment.set_default();
+        }
+        self.fragment.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_fragment(&mut self) -> Fragment {
+        self.fragment.take().unwrap_or_else(|| Fragment::new())
+    }
+
+    pub fn get_fragment(&self) -> &Fragment {
+        self.fragment.as_ref().unwrap_or_else(|| Fragment::default_instance())
+    }
+
+    fn get_fragment_for_reflect(&self) -> &::protobuf::SingularPtrField<Fragment> {
+        &self.fragment
+    }
+
+    fn mut_fragment_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Fragment> {
+        &mut self.fragment
+    }
+}
+
+impl ::protobuf::Message for PacketQuery {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        if self.payload.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.fragment {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.fragment)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.payload.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        if let Some(ref v) = self.fragment.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.payload.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        if let Some(ref v) = self.fragment.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PacketQuery {
+    fn new() -> PacketQuery {
+        PacketQuery::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PacketQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    PacketQuery::get_origin_for_reflect,
+                    PacketQuery::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "payload",
+                    PacketQuery::get_payload_for_reflect,
+                    PacketQuery::mut_payload_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Fragment>>(
+                    "fragment",
+                    PacketQuery::get_fragment_for_reflect,
+                    PacketQuery::mut_fragment_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PacketQuery>(
+                    "PacketQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PacketQuery {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_payload();
+        self.clear_fragment();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PacketQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PacketQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PacketResponse {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PacketResponse {}
+
+impl PacketResponse {
+    pub fn new() -> PacketResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PacketResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PacketResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PacketResponse,
+        };
+        unsafe {
+            instance.get(PacketResponse::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for PacketResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PacketResponse {
+    fn new() -> PacketResponse {
+        PacketResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PacketResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    PacketResponse::get_origin_for_reflect,
+                    PacketResponse::mut_origin_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PacketResponse>(
+                    "PacketResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PacketResponse {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PacketResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PacketResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RendezvousQuery {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    target: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    peer: ::protobuf::SingularPtrField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RendezvousQuery {}
+
+impl RendezvousQuery {
+    pub fn new() -> RendezvousQuery {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RendezvousQuery {
+        static mut instance: ::protobuf::lazy::Lazy<RendezvousQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RendezvousQuery,
+        };
+        unsafe {
+            instance.get(RendezvousQuery::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+
+    // required bytes target = 2;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::vec::Vec<u8>) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::vec::Vec<u8> {
+        self.target.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_target(&self) -> &[u8] {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.target
+    }
+
+    // optional .Node peer = 3;
+
+    pub fn clear_peer(&mut self) {
+        self.peer.clear();
+    }
+
+    pub fn has_peer(&self) -> bool {
+        self.peer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peer(&mut self, v: Node) {
+        self.peer = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_peer(&mut self) -> &mut Node {
+        if self.peer.is_none() {
+            self.peer.set_default();
+        }
+        self.peer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_peer(&mut self) -> Node {
+        self.peer.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_peer(&self) -> &Node {
+        self.peer.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_peer_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.peer
+    }
+
+    fn mut_peer_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.peer
+    }
+}
+
+impl ::protobuf::Message for RendezvousQuery {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        if self.target.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.peer {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.target)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.peer)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        if let Some(ref v) = self.peer.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        if let Some(ref v) = self.peer.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RendezvousQuery {
+    fn new() -> RendezvousQuery {
+        RendezvousQuery::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RendezvousQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    RendezvousQuery::get_origin_for_reflect,
+                    RendezvousQuery::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "target",
+                    RendezvousQuery::get_target_for_reflect,
+                    RendezvousQuery::mut_target_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "peer",
+                    RendezvousQuery::get_peer_for_reflect,
+                    RendezvousQuery::mut_peer_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RendezvousQuery>(
+                    "RendezvousQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RendezvousQuery {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_target();
+        self.clear_peer();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RendezvousQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RendezvousQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RendezvousResponse {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    peer: ::protobuf::SingularPtrField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RendezvousResponse {}
+
+impl RendezvousResponse {
+    pub fn new() -> RendezvousResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RendezvousResponse {
+        static mut instance: ::protobuf::lazy::Lazy<RendezvousResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RendezvousResponse,
+        };
+        unsafe {
+            instance.get(RendezvousResponse::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+
+    // optional .Node peer = 2;
+
+    pub fn clear_peer(&mut self) {
+        self.peer.clear();
+    }
+
+    pub fn has_peer(&self) -> bool {
+        self.peer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peer(&mut self, v: Node) {
+        self.peer = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_peer(&mut self) -> &mut Node {
+        if self.peer.is_none() {
+            self.peer.set_default();
+        }
+        self.peer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_peer(&mut self) -> Node {
+        self.peer.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_peer(&self) -> &Node {
+        self.peer.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_peer_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.peer
+    }
+
+    fn mut_peer_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.peer
+    }
+}
+
+impl ::protobuf::Message for RendezvousResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.peer {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.peer)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.peer.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.peer.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RendezvousResponse {
+    fn new() -> RendezvousResponse {
+        RendezvousResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RendezvousResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    RendezvousResponse::get_origin_for_reflect,
+                    RendezvousResponse::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "peer",
+                    RendezvousResponse::get_peer_for_reflect,
+                    RendezvousResponse::mut_peer_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RendezvousResponse>(
+                    "RendezvousResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RendezvousResponse {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_peer();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RendezvousResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RendezvousResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RegisterRelayQuery {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RegisterRelayQuery {}
+
+impl RegisterRelayQuery {
+    pub fn new() -> RegisterRelayQuery {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RegisterRelayQuery {
+        static mut instance: ::protobuf::lazy::Lazy<RegisterRelayQuery> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RegisterRelayQuery,
+        };
+        unsafe {
+            instance.get(RegisterRelayQuery::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for RegisterRelayQuery {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RegisterRelayQuery {
+    fn new() -> RegisterRelayQuery {
+        RegisterRelayQuery::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RegisterRelayQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    RegisterRelayQuery::get_origin_for_reflect,
+                    RegisterRelayQuery::mut_origin_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RegisterRelayQuery>(
+                    "RegisterRelayQuery",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RegisterRelayQuery {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RegisterRelayQuery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RegisterRelayQuery {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RegisterRelayResponse {
+    // message fields
+    origin: ::protobuf::SingularPtrField<Node>,
+    accepted: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RegisterRelayResponse {}
+
+impl RegisterRelayResponse {
+    pub fn new() -> RegisterRelayResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RegisterRelayResponse {
+        static mut instance: ::protobuf::lazy::Lazy<RegisterRelayResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RegisterRelayResponse,
+        };
+        unsafe {
+            instance.get(RegisterRelayResponse::new)
+        }
+    }
+
+    // required .Node origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: Node) {
+        self.origin = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut Node {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> Node {
+        self.origin.take().unwrap_or_else(|| Node::new())
+    }
+
+    pub fn get_origin(&self) -> &Node {
+        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.origin
+    }
+
+    // required bool accepted = 2;
+
+    pub fn clear_accepted(&mut self) {
+        self.accepted = ::std::option::Option::None;
+    }
+
+    pub fn has_accepted(&self) -> bool {
+        self.accepted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepted(&mut self, v: bool) {
+        self.accepted = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_accepted(&self) -> bool {
+        self.accepted.unwrap_or(false)
+    }
+
+    fn get_accepted_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.accepted
+    }
+
+    fn mut_accepted_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.accepted
+    }
+}
+
+impl ::protobuf::Message for RegisterRelayResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        }
+        if self.accepted.is_none() {
+            return false;
+        }
+        for v in &self.origin {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.accepted = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.accepted {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.accepted {
+            os.write_bool(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RegisterRelayResponse {
+    fn new() -> RegisterRelayResponse {
+        RegisterRelayResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RegisterRelayResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "origin",
+                    RegisterRelayResponse::get_origin_for_reflect,
+                    RegisterRelayResponse::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "accepted",
+                    RegisterRelayResponse::get_accepted_for_reflect,
+                    RegisterRelayResponse::mut_accepted_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RegisterRelayResponse>(
+                    "RegisterRelayResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RegisterRelayResponse {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_accepted();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RegisterRelayResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RegisterRelayResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Relay {
+    // message fields
+    target: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Relay {}
+
+impl Relay {
+    pub fn new() -> Relay {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Relay {
+        static mut instance: ::protobuf::lazy::Lazy<Relay> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Relay,
+        };
+        unsafe {
+            instance.get(Relay::new)
+        }
+    }
+
+    // required bytes target = 1;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::vec::Vec<u8>) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::vec::Vec<u8> {
+        self.target.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_target(&self) -> &[u8] {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.target
+    }
+
+    // required bytes payload = 2;
+
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
+        self.payload = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.payload.is_none() {
+            self.payload.set_default();
+        }
+        self.payload.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
+        self.payload.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        match self.payload.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_payload_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.payload
+    }
+
+    fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.payload
+    }
+}
+
+impl ::protobuf::Message for Relay {
+    fn is_initialized(&self) -> bool {
+        if self.target.is_none() {
+            return false;
+        }
+        if self.payload.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.target)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        if let Some(ref v) = self.payload.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        if let Some(ref v) = self.payload.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Relay {
+    fn new() -> Relay {
+        Relay::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Relay>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "target",
+                    Relay::get_target_for_reflect,
+                    Relay::mut_target_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "payload",
+                    Relay::get_payload_for_reflect,
+                    Relay::mut_payload_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Relay>(
+                    "Relay",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Relay {
+    fn clear(&mut self) {
+        self.clear_target();
+        self.clear_payload();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Relay {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Relay {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Envelope {
+    // message fields
+    message_type: ::std::option::Option<Envelope_Type>,
+    transaction_id: ::std::option::Option<u32>,
+    find_node_query: ::protobuf::SingularPtrField<FindNodeQuery>,
+    find_node_response: ::protobuf::SingularPtrField<FindNodeResponse>,
+    ping_query: ::protobuf::SingularPtrField<PingQuery>,
+    ping_response: ::protobuf::SingularPtrField<PingResponse>,
+    packet_query: ::protobuf::SingularPtrField<PacketQuery>,
+    packet_response: ::protobuf::SingularPtrField<PacketResponse>,
+    rendezvous_query: ::protobuf::SingularPtrField<RendezvousQuery>,
+    rendezvous_response: ::protobuf::SingularPtrField<RendezvousResponse>,
+    register_relay_query: ::protobuf::SingularPtrField<RegisterRelayQuery>,
+    register_relay_response: ::protobuf::SingularPtrField<RegisterRelayResponse>,
+    relay: ::protobuf::SingularPtrField<Relay>,
+    signature: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    protocol_version: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Envelope {}
+
+impl Envelope {
+    pub fn new() -> Envelope {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Envelope {
+        static mut instance: ::protobuf::lazy::Lazy<Envelope> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Envelope,
+        };
+        unsafe {
+            instance.get(Envelope::new)
+        }
+    }
+
+    // optional .Envelope.Type message_type = 1;
+
+    pub fn clear_message_type(&mut self) {
+        self.message_type = ::std::option::Option::None;
+    }
+
+    pub fn has_message_type(&self) -> bool {
+        self.message_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_type(&mut self, v: Envelope_Type) {
+        self.message_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_message_type(&self) -> Envelope_Type {
+        self.message_type.unwrap_or(Envelope_Type::FIND_NODE_QUERY)
+    }
+
+    fn get_message_type_for_reflect(&self) -> &::std::option::Option<Envelope_Type> {
+        &self.message_type
+    }
+
+    fn mut_message_type_for_reflect(&mut self) -> &mut ::std::option::Option<Envelope_Type> {
+        &mut self.message_type
+    }
+
+    // required uint32 transaction_id = 2;
+
+    pub fn clear_transaction_id(&mut self) {
+        self.transaction_id = ::std::option::Option::None;
+    }
+
+    pub fn has_transaction_id(&self) -> bool {
+        self.transaction_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transaction_id(&mut self, v: u32) {
+        self.transaction_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_transaction_id(&self) -> u32 {
+        self.transaction_id.unwrap_or(0)
+    }
+
+    fn get_transaction_id_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.transaction_id
+    }
+
+    fn mut_transaction_id_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.transaction_id
+    }
+
+    // optional .FindNodeQuery find_node_query = 3;
+
+    pub fn clear_find_node_query(&mut self) {
+        self.find_node_query.clear();
+    }
+
+    pub fn has_find_node_query(&self) -> bool {
+        self.find_node_query.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_find_node_query(&mut self, v: FindNodeQuery) {
+        self.find_node_query = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_find_node_query(&mut self) -> &mut FindNodeQuery {
+        if self.find_node_query.is_none() {
+            self.find_node_query.set_default();
+        }
+        self.find_node_query.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_find_node_query(&mut self) -> FindNodeQuery {
+        self.find_node_query.take().unwrap_or_else(|| FindNodeQuery::new())
+    }
+
+    pub fn get_find_node_query(&self) -> &FindNodeQuery {
+        self.find_node_query.as_ref().unwrap_or_else(|| FindNodeQuery::default_instance())
+    }
+
+    fn get_find_node_query_for_reflect(&self) -> &::protobuf::SingularPtrField<FindNodeQuery> {
+        &self.find_node_query
+    }
+
+    fn mut_find_node_query_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<FindNodeQuery> {
+        &mut self.find_node_query
+    }
+
+    // optional .FindNodeResponse find_node_response = 4;
+
+    pub fn clear_find_node_response(&mut self) {
+        self.find_node_response.clear();
+    }
+
+    pub fn has_find_node_response(&self) -> bool {
+        self.find_node_response.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_find_node_response(&mut self, v: FindNodeResponse) {
+        self.find_node_response = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_find_node_response(&mut self) -> &mut FindNodeResponse {
+        if self.find_node_response.is_none() {
+            self.find_node_response.set_default();
+        }
+        self.find_node_response.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_find_node_response(&mut self) -> FindNodeResponse {
+        self.find_node_response.take().unwrap_or_else(|| FindNodeResponse::new())
+    }
+
+    pub fn get_find_node_response(&self) -> &FindNodeResponse {
+        self.find_node_response.as_ref().unwrap_or_else(|| FindNodeResponse::default_instance())
+    }
+
+    fn get_find_node_response_for_reflect(&self) -> &::protobuf::SingularPtrField<FindNodeResponse> {
+        &self.find_node_response
+    }
+
+    fn mut_find_node_response_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<FindNodeResponse> {
+        &mut self.find_node_response
+    }
+
+    // optional .PingQuery ping_query = 5;
+
+    pub fn clear_ping_query(&mut self) {
+        self.ping_query.clear();
+    }
+
+    pub fn has_ping_query(&self) -> bool {
+        self.ping_query.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping_query(&mut self, v: PingQuery) {
+        self.ping_query = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ping_query(&mut self) -> &mut PingQuery {
+        if self.ping_query.is_none() {
+            self.ping_query.set_default();
+        }
+        self.ping_query.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ping_query(&mut self) -> PingQuery {
+        self.ping_query.take().unwrap_or_else(|| PingQuery::new())
+    }
+
+    pub fn get_ping_query(&self) -> &PingQuery {
+        self.ping_query.as_ref().unwrap_or_else(|| PingQuery::default_instance())
+    }
+
+    fn get_ping_query_for_reflect(&self) -> &::protobuf::SingularPtrField<PingQuery> {
+        &self.ping_query
+    }
+
+    fn mut_ping_query_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<PingQuery> {
+        &mut self.ping_query
+    }
+
+    // optional .PingResponse ping_response = 6;
+
+    pub fn clear_ping_response(&mut self) {
+        self.ping_response.clear();
+    }
+
+    pub fn has_ping_response(&self) -> bool {
+        self.ping_response.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping_response(&mut self, v: PingResponse) {
+        self.ping_response = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ping_response(&mut self) -> &mut PingResponse {
+        if self.ping_response.is_none() {
+            self.ping_response.set_default();
+        }
+        self.ping_response.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ping_response(&mut self) -> PingResponse {
+        self.ping_response.take().unwrap_or_else(|| PingResponse::new())
+    }
+
+    pub fn get_ping_response(&self) -> &PingResponse {
+        self.ping_response.as_ref().unwrap_or_else(|| PingResponse::default_instance())
+    }
+
+    fn get_ping_response_for_reflect(&self) -> &::protobuf::SingularPtrField<PingResponse> {
+        &self.ping_response
+    }
+
+    fn mut_ping_response_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<PingResponse> {
+        &mut self.ping_response
+    }
+
+    // optional .PacketQuery packet_query = 7;
+
+    pub fn clear_packet_query(&mut self) {
+        self.packet_query.clear();
+    }
+
+    pub fn has_packet_query(&self) -> bool {
+        self.packet_query.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packet_query(&mut self, v: PacketQuery) {
+        self.packet_query = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_packet_query(&mut self) -> &mut PacketQuery {
+        if self.packet_query.is_none() {
+            self.packet_query.set_default();
+        }
+        self.packet_query.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_packet_query(&mut self) -> PacketQuery {
+        self.packet_query.take().unwrap_or_else(|| PacketQuery::new())
+    }
+
+    pub fn get_packet_query(&self) -> &PacketQuery {
+        self.packet_query.as_ref().unwrap_or_else(|| PacketQuery::default_instance())
+    }
+
+    fn get_packet_query_for_reflect(&self) -> &::protobuf::SingularPtrField<PacketQuery> {
+        &self.packet_query
+    }
+
+    fn mut_packet_query_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<PacketQuery> {
+        &mut self.packet_query
+    }
+
+    // optional .PacketResponse packet_response = 8;
+
+    pub fn clear_packet_response(&mut self) {
+        self.packet_response.clear();
+    }
+
+    pub fn has_packet_response(&self) -> bool {
+        self.packet_response.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packet_response(&mut self, v: PacketResponse) {
+        self.packet_response = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_packet_response(&mut self) -> &mut PacketResponse {
+        if self.packet_response.is_none() {
+            self.packet_response.set_default();
+        }
+        self.packet_response.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_packet_response(&mut self) -> PacketResponse {
+        self.packet_response.take().unwrap_or_else(|| PacketResponse::new())
+    }
+
+    pub fn get_packet_response(&self) -> &PacketResponse {
+        self.packet_response.as_ref().unwrap_or_else(|| PacketResponse::default_instance())
+    }
+
+    fn get_packet_response_for_reflect(&self) -> &::protobuf::SingularPtrField<PacketResponse> {
+        &self.packet_response
+    }
+
+    fn mut_packet_response_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<PacketResponse> {
+        &mut self.packet_response
+    }
+
+    // optional .RendezvousQuery rendezvous_query = 9;
+
+    pub fn clear_rendezvous_query(&mut self) {
+        self.rendezvous_query.clear();
+    }
+
+    pub fn has_rendezvous_query(&self) -> bool {
+        self.rendezvous_query.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rendezvous_query(&mut self, v: RendezvousQuery) {
+        self.rendezvous_query = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rendezvous_query(&mut self) -> &mut RendezvousQuery {
+        if self.rendezvous_query.is_none() {
+            self.rendezvous_query.set_default();
+        }
+        self.rendezvous_query.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rendezvous_query(&mut self) -> RendezvousQuery {
+        self.rendezvous_query.take().unwrap_or_else(|| RendezvousQuery::new())
+    }
+
+    pub fn get_rendezvous_query(&self) -> &RendezvousQuery {
+        self.rendezvous_query.as_ref().unwrap_or_else(|| RendezvousQuery::default_instance())
+    }
+
+    fn get_rendezvous_query_for_reflect(&self) -> &::protobuf::SingularPtrField<RendezvousQuery> {
+        &self.rendezvous_query
+    }
+
+    fn mut_rendezvous_query_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<RendezvousQuery> {
+        &mut self.rendezvous_query
+    }
+
+    // optional .RendezvousResponse rendezvous_response = 10;
+
+    pub fn clear_rendezvous_response(&mut self) {
+        self.rendezvous_response.clear();
+    }
+
+    pub fn has_rendezvous_response(&self) -> bool {
+        self.rendezvous_response.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rendezvous_response(&mut self, v: RendezvousResponse) {
+        self.rendezvous_response = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rendezvous_response(&mut self) -> &mut RendezvousResponse {
+        if self.rendezvous_response.is_none() {
+            self.rendezvous_response.set_default();
+        }
+        self.rendezvous_response.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rendezvous_response(&mut self) -> RendezvousResponse {
+        self.rendezvous_response.take().unwrap_or_else(|| RendezvousResponse::new())
+    }
+
+    pub fn get_rendezvous_response(&self) -> &RendezvousResponse {
+        self.rendezvous_response.as_ref().unwrap_or_else(|| RendezvousResponse::default_instance())
+    }
+
+    fn get_rendezvous_response_for_reflect(&self) -> &::protobuf::SingularPtrField<RendezvousResponse> {
+        &self.rendezvous_response
+    }
+
+    fn mut_rendezvous_response_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<RendezvousResponse> {
+        &mut self.rendezvous_response
+    }
+
+    // optional .RegisterRelayQuery register_relay_query = 11;
+
+    pub fn clear_register_relay_query(&mut self) {
+        self.register_relay_query.clear();
+    }
+
+    pub fn has_register_relay_query(&self) -> bool {
+        self.register_relay_query.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_register_relay_query(&mut self, v: RegisterRelayQuery) {
+        self.register_relay_query = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_register_relay_query(&mut self) -> &mut RegisterRelayQuery {
+        if self.register_relay_query.is_none() {
+            self.register_relay_query.set_default();
+        }
+        self.register_relay_query.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_register_relay_query(&mut self) -> RegisterRelayQuery {
+        self.register_relay_query.take().unwrap_or_else(|| RegisterRelayQuery::new())
+    }
+
+    pub fn get_register_relay_query(&self) -> &RegisterRelayQuery {
+        self.register_relay_query.as_ref().unwrap_or_else(|| RegisterRelayQuery::default_instance())
+    }
+
+    fn get_register_relay_query_for_reflect(&self) -> &::protobuf::SingularPtrField<RegisterRelayQuery> {
+        &self.register_relay_query
+    }
+
+    fn mut_register_relay_query_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<RegisterRelayQuery> {
+        &mut self.register_relay_query
+    }
+
+    // optional .RegisterRelayResponse register_relay_response = 12;
+
+    pub fn clear_register_relay_response(&mut self) {
+        self.register_relay_response.clear();
+    }
+
+    pub fn has_register_relay_response(&self) -> bool {
+        self.register_relay_response.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_register_relay_response(&mut self, v: RegisterRelayResponse) {
+        self.register_relay_response = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_register_relay_response(&mut self) -> &mut RegisterRelayResponse {
+        if self.register_relay_response.is_none() {
+            self.register_relay_response.set_default();
+        }
+        self.register_relay_response.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_register_relay_response(&mut self) -> RegisterRelayResponse {
+        self.register_relay_response.take().unwrap_or_else(|| RegisterRelayResponse::new())
+    }
+
+    pub fn get_register_relay_response(&self) -> &RegisterRelayResponse {
+        self.register_relay_response.as_ref().unwrap_or_else(|| RegisterRelayResponse::default_instance())
+    }
+
+    fn get_register_relay_response_for_reflect(&self) -> &::protobuf::SingularPtrField<RegisterRelayResponse> {
+        &self.register_relay_response
+    }
+
+    fn mut_register_relay_response_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<RegisterRelayResponse> {
+        &mut self.register_relay_response
+    }
+
+    // optional .Relay relay = 13;
+
+    pub fn clear_relay(&mut self) {
+        self.relay.clear();
+    }
+
+    pub fn has_relay(&self) -> bool {
+        self.relay.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_relay(&mut self, v: Relay) {
+        self.relay = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_relay(&mut self) -> &mut Relay {
+        if self.relay.is_none() {
+            self.relay.set_default();
+        }
+        self.relay.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_relay(&mut self) -> Relay {
+        self.relay.take().unwrap_or_else(|| Relay::new())
+    }
+
+    pub fn get_relay(&self) -> &Relay {
+        self.relay.as_ref().unwrap_or_else(|| Relay::default_instance())
+    }
+
+    fn get_relay_for_reflect(&self) -> &::protobuf::SingularPtrField<Relay> {
+        &self.relay
+    }
+
+    fn mut_relay_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Relay> {
+        &mut self.relay
+    }
+
+    // optional bytes signature = 14;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        }
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        self.signature.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_signature(&self) -> &[u8] {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_signature_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.signature
+    }
+
+    fn mut_signature_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
+
+    // optional uint32 protocol_version = 15;
+
+    pub fn clear_protocol_version(&mut self) {
+        self.protocol_version = ::std::option::Option::None;
+    }
+
+    pub fn has_protocol_version(&self) -> bool {
+        self.protocol_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_protocol_version(&mut self, v: u32) {
+        self.protocol_version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.unwrap_or(0)
+    }
+
+    fn get_protocol_version_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.protocol_version
+    }
+
+    fn mut_protocol_version_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.protocol_version
+    }
+}
+
+impl ::protobuf::Message for Envelope {
+    fn is_initialized(&self) -> bool {
+        if self.transaction_id.is_none() {
+            return false;
+        }
+        for v in &self.find_node_query {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.find_node_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ping_query {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ping_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.packet_query {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.packet_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rendezvous_query {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rendezvous_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.register_relay_query {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.register_relay_response {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.relay {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto2_enum_with_unknown_fields_into(wire_type, is, &mut self.message_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.transaction_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.find_node_query)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.find_node_response)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ping_query)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ping_response)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.packet_query)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.packet_response)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rendezvous_query)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rendezvous_response)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.register_relay_query)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.register_relay_response)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.relay)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.signature)?;
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.protocol_version = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin.iter() {
-            let len = value.compute_size();
+        if let Some(v) = self.message_type {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        }
+        if let Some(v) = self.transaction_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.find_node_query.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.payload.iter() {
-            my_size += ::protobuf::rt::bytes_size(2, &value);
-        };
+        }
+        if let Some(ref v) = self.find_node_response.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.ping_query.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.ping_response.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.packet_query.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.packet_response.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.rendezvous_query.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.rendezvous_response.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.register_relay_query.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.register_relay_response.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.relay.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(14, &v);
+        }
+        if let Some(v) = self.protocol_version {
+            my_size += ::protobuf::rt::value_size(15, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin.as_ref() {
-            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.payload.as_ref() {
-            try!(os.write_bytes(2, &v));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(v) = self.message_type {
+            os.write_enum(1, v.value())?;
+        }
+        if let Some(v) = self.transaction_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.find_node_query.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.find_node_response.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.ping_query.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.ping_response.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.packet_query.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.packet_response.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.rendezvous_query.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.rendezvous_response.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.register_relay_query.as_ref() {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.register_relay_response.as_ref() {
+            os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.relay.as_ref() {
+            os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.signature.as_ref() {
+            os.write_bytes(14, &v)?;
+        }
+        if let Some(v) = self.protocol_version {
+            os.write_uint32(15, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -1769,25 +6150,27 @@ impl ::protobuf::Message for PacketQuery {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<PacketQuery>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for PacketQuery {
-    fn new() -> PacketQuery {
-        PacketQuery::new()
+impl ::protobuf::MessageStatic for Envelope {
+    fn new() -> Envelope {
+        Envelope::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<PacketQuery>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<Envelope>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1795,18 +6178,83 @@ impl ::protobuf::MessageStatic for PacketQuery {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "origin",
-                    PacketQuery::has_origin,
-                    PacketQuery::get_origin,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Envelope_Type>>(
+                    "message_type",
+                    Envelope::get_message_type_for_reflect,
+                    Envelope::mut_message_type_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "payload",
-                    PacketQuery::has_payload,
-                    PacketQuery::get_payload,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "transaction_id",
+                    Envelope::get_transaction_id_for_reflect,
+                    Envelope::mut_transaction_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PacketQuery>(
-                    "PacketQuery",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FindNodeQuery>>(
+                    "find_node_query",
+                    Envelope::get_find_node_query_for_reflect,
+                    Envelope::mut_find_node_query_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FindNodeResponse>>(
+                    "find_node_response",
+                    Envelope::get_find_node_response_for_reflect,
+                    Envelope::mut_find_node_response_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PingQuery>>(
+                    "ping_query",
+                    Envelope::get_ping_query_for_reflect,
+                    Envelope::mut_ping_query_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PingResponse>>(
+                    "ping_response",
+                    Envelope::get_ping_response_for_reflect,
+                    Envelope::mut_ping_response_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PacketQuery>>(
+                    "packet_query",
+                    Envelope::get_packet_query_for_reflect,
+                    Envelope::mut_packet_query_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PacketResponse>>(
+                    "packet_response",
+                    Envelope::get_packet_response_for_reflect,
+                    Envelope::mut_packet_response_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RendezvousQuery>>(
+                    "rendezvous_query",
+                    Envelope::get_rendezvous_query_for_reflect,
+                    Envelope::mut_rendezvous_query_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RendezvousResponse>>(
+                    "rendezvous_response",
+                    Envelope::get_rendezvous_response_for_reflect,
+                    Envelope::mut_rendezvous_response_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RegisterRelayQuery>>(
+                    "register_relay_query",
+                    Envelope::get_register_relay_query_for_reflect,
+                    Envelope::mut_register_relay_query_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RegisterRelayResponse>>(
+                    "register_relay_response",
+                    Envelope::get_register_relay_response_for_reflect,
+                    Envelope::mut_register_relay_response_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Relay>>(
+                    "relay",
+                    Envelope::get_relay_for_reflect,
+                    Envelope::mut_relay_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "signature",
+                    Envelope::get_signature_for_reflect,
+                    Envelope::mut_signature_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "protocol_version",
+                    Envelope::get_protocol_version_for_reflect,
+                    Envelope::mut_protocol_version_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Envelope>(
+                    "Envelope",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1815,112 +6263,282 @@ impl ::protobuf::MessageStatic for PacketQuery {
     }
 }
 
-impl ::protobuf::Clear for PacketQuery {
+impl ::protobuf::Clear for Envelope {
     fn clear(&mut self) {
-        self.clear_origin();
-        self.clear_payload();
+        self.clear_message_type();
+        self.clear_transaction_id();
+        self.clear_find_node_query();
+        self.clear_find_node_response();
+        self.clear_ping_query();
+        self.clear_ping_response();
+        self.clear_packet_query();
+        self.clear_packet_response();
+        self.clear_rendezvous_query();
+        self.clear_rendezvous_response();
+        self.clear_register_relay_query();
+        self.clear_register_relay_response();
+        self.clear_relay();
+        self.clear_signature();
+        self.clear_protocol_version();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for PacketQuery {
-    fn eq(&self, other: &PacketQuery) -> bool {
-        self.origin == other.origin &&
-        self.payload == other.payload &&
-        self.unknown_fields == other.unknown_fields
-    }
+impl ::std::fmt::Debug for Envelope {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Envelope {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Envelope_Type {
+    FIND_NODE_QUERY = 1,
+    FIND_NODE_RESPONSE = 2,
+    PING_QUERY = 3,
+    PING_RESPONSE = 4,
+    PACKET_QUERY = 5,
+    PACKET_RESPONSE = 6,
+    RENDEZVOUS_QUERY = 7,
+    RENDEZVOUS_RESPONSE = 8,
+    REGISTER_RELAY_QUERY = 9,
+    REGISTER_RELAY_RESPONSE = 10,
+    RELAY = 11,
+}
+
+impl ::protobuf::ProtobufEnum for Envelope_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Envelope_Type> {
+        match value {
+            1 => ::std::option::Option::Some(Envelope_Type::FIND_NODE_QUERY),
+            2 => ::std::option::Option::Some(Envelope_Type::FIND_NODE_RESPONSE),
+            3 => ::std::option::Option::Some(Envelope_Type::PING_QUERY),
+            4 => ::std::option::Option::Some(Envelope_Type::PING_RESPONSE),
+            5 => ::std::option::Option::Some(Envelope_Type::PACKET_QUERY),
+            6 => ::std::option::Option::Some(Envelope_Type::PACKET_RESPONSE),
+            7 => ::std::option::Option::Some(Envelope_Type::RENDEZVOUS_QUERY),
+            8 => ::std::option::Option::Some(Envelope_Type::RENDEZVOUS_RESPONSE),
+            9 => ::std::option::Option::Some(Envelope_Type::REGISTER_RELAY_QUERY),
+            10 => ::std::option::Option::Some(Envelope_Type::REGISTER_RELAY_RESPONSE),
+            11 => ::std::option::Option::Some(Envelope_Type::RELAY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Envelope_Type] = &[
+            Envelope_Type::FIND_NODE_QUERY,
+            Envelope_Type::FIND_NODE_RESPONSE,
+            Envelope_Type::PING_QUERY,
+            Envelope_Type::PING_RESPONSE,
+            Envelope_Type::PACKET_QUERY,
+            Envelope_Type::PACKET_RESPONSE,
+            Envelope_Type::RENDEZVOUS_QUERY,
+            Envelope_Type::RENDEZVOUS_RESPONSE,
+            Envelope_Type::REGISTER_RELAY_QUERY,
+            Envelope_Type::REGISTER_RELAY_RESPONSE,
+            Envelope_Type::RELAY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<Envelope_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Envelope_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Envelope_Type {
 }
 
-impl ::std::fmt::Debug for PacketQuery {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::protobuf::reflect::ProtobufValue for Envelope_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
     }
 }
 
-#[derive(Clone,Default)]
-pub struct PacketResponse {
+#[derive(PartialEq,Clone,Default)]
+pub struct SavedNode {
     // message fields
-    origin: ::protobuf::SingularPtrField<Node>,
+    node: ::protobuf::SingularPtrField<Node>,
+    last_seen: ::std::option::Option<i64>,
+    has_responded: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for PacketResponse {}
+unsafe impl ::std::marker::Sync for SavedNode {}
 
-impl PacketResponse {
-    pub fn new() -> PacketResponse {
+impl SavedNode {
+    pub fn new() -> SavedNode {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static PacketResponse {
-        static mut instance: ::protobuf::lazy::Lazy<PacketResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static SavedNode {
+        static mut instance: ::protobuf::lazy::Lazy<SavedNode> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PacketResponse,
+            ptr: 0 as *const SavedNode,
         };
         unsafe {
-            instance.get(|| {
-                PacketResponse {
-                    origin: ::protobuf::SingularPtrField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(SavedNode::new)
         }
     }
 
-    // required .Node origin = 1;
+    // required .Node node = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_node(&mut self) {
+        self.node.clear();
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: Node) {
-        self.origin = ::protobuf::SingularPtrField::some(v);
+    pub fn set_node(&mut self, v: Node) {
+        self.node = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut Node {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        };
-        self.origin.as_mut().unwrap()
+    pub fn mut_node(&mut self) -> &mut Node {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin(&mut self) -> Node {
-        self.origin.take().unwrap_or_else(|| Node::new())
+    pub fn take_node(&mut self) -> Node {
+        self.node.take().unwrap_or_else(|| Node::new())
     }
 
-    pub fn get_origin(&self) -> &Node {
-        self.origin.as_ref().unwrap_or_else(|| Node::default_instance())
+    pub fn get_node(&self) -> &Node {
+        self.node.as_ref().unwrap_or_else(|| Node::default_instance())
+    }
+
+    fn get_node_for_reflect(&self) -> &::protobuf::SingularPtrField<Node> {
+        &self.node
+    }
+
+    fn mut_node_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Node> {
+        &mut self.node
+    }
+
+    // required int64 last_seen = 2;
+
+    pub fn clear_last_seen(&mut self) {
+        self.last_seen = ::std::option::Option::None;
+    }
+
+    pub fn has_last_seen(&self) -> bool {
+        self.last_seen.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_seen(&mut self, v: i64) {
+        self.last_seen = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_last_seen(&self) -> i64 {
+        self.last_seen.unwrap_or(0)
+    }
+
+    fn get_last_seen_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.last_seen
+    }
+
+    fn mut_last_seen_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.last_seen
+    }
+
+    // required bool has_responded = 3;
+
+    pub fn clear_has_responded(&mut self) {
+        self.has_responded = ::std::option::Option::None;
+    }
+
+    pub fn has_has_responded(&self) -> bool {
+        self.has_responded.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_responded(&mut self, v: bool) {
+        self.has_responded = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_has_responded(&self) -> bool {
+        self.has_responded.unwrap_or(false)
+    }
+
+    fn get_has_responded_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.has_responded
+    }
+
+    fn mut_has_responded_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.has_responded
     }
 }
 
-impl ::protobuf::Message for PacketResponse {
+impl ::protobuf::Message for SavedNode {
     fn is_initialized(&self) -> bool {
-        if self.origin.is_none() {
+        if self.node.is_none() {
+            return false;
+        }
+        if self.last_seen.is_none() {
+            return false;
+        }
+        if self.has_responded.is_none() {
             return false;
+        }
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
         };
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.origin));
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_seen = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_responded = ::std::option::Option::Some(tmp);
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -1931,22 +6549,34 @@ impl ::protobuf::Message for PacketResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin.iter() {
-            let len = value.compute_size();
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        }
+        if let Some(v) = self.last_seen {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.has_responded {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin.as_ref() {
-            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.last_seen {
+            os.write_int64(2, v)?;
+        }
+        if let Some(v) = self.has_responded {
+            os.write_bool(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -1962,25 +6592,27 @@ impl ::protobuf::Message for PacketResponse {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<PacketResponse>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for PacketResponse {
-    fn new() -> PacketResponse {
-        PacketResponse::new()
+impl ::protobuf::MessageStatic for SavedNode {
+    fn new() -> SavedNode {
+        SavedNode::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<PacketResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<SavedNode>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1988,13 +6620,23 @@ impl ::protobuf::MessageStatic for PacketResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "origin",
-                    PacketResponse::has_origin,
-                    PacketResponse::get_origin,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "node",
+                    SavedNode::get_node_for_reflect,
+                    SavedNode::mut_node_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PacketResponse>(
-                    "PacketResponse",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "last_seen",
+                    SavedNode::get_last_seen_for_reflect,
+                    SavedNode::mut_last_seen_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "has_responded",
+                    SavedNode::get_has_responded_for_reflect,
+                    SavedNode::mut_has_responded_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SavedNode>(
+                    "SavedNode",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2003,359 +6645,441 @@ impl ::protobuf::MessageStatic for PacketResponse {
     }
 }
 
-impl ::protobuf::Clear for PacketResponse {
+impl ::protobuf::Clear for SavedNode {
     fn clear(&mut self) {
-        self.clear_origin();
+        self.clear_node();
+        self.clear_last_seen();
+        self.clear_has_responded();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for PacketResponse {
-    fn eq(&self, other: &PacketResponse) -> bool {
-        self.origin == other.origin &&
-        self.unknown_fields == other.unknown_fields
+impl ::std::fmt::Debug for SavedNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::std::fmt::Debug for PacketResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::protobuf::reflect::ProtobufValue for SavedNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,Default)]
-pub struct Envelope {
+#[derive(PartialEq,Clone,Default)]
+pub struct SavedBucket {
     // message fields
-    message_type: ::std::option::Option<Envelope_Type>,
-    transaction_id: ::std::option::Option<u32>,
-    find_node_query: ::protobuf::SingularPtrField<FindNodeQuery>,
-    find_node_response: ::protobuf::SingularPtrField<FindNodeResponse>,
-    ping_query: ::protobuf::SingularPtrField<PingQuery>,
-    ping_response: ::protobuf::SingularPtrField<PingResponse>,
-    packet_query: ::protobuf::SingularPtrField<PacketQuery>,
-    packet_response: ::protobuf::SingularPtrField<PacketResponse>,
+    prefix: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    depth: ::std::option::Option<u32>,
+    nodes: ::protobuf::RepeatedField<SavedNode>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::std::cell::Cell<u32>,
+    cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Envelope {}
+unsafe impl ::std::marker::Sync for SavedBucket {}
 
-impl Envelope {
-    pub fn new() -> Envelope {
+impl SavedBucket {
+    pub fn new() -> SavedBucket {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Envelope {
-        static mut instance: ::protobuf::lazy::Lazy<Envelope> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static SavedBucket {
+        static mut instance: ::protobuf::lazy::Lazy<SavedBucket> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Envelope,
+            ptr: 0 as *const SavedBucket,
         };
         unsafe {
-            instance.get(|| {
-                Envelope {
-                    message_type: ::std::option::Option::None,
-                    transaction_id: ::std::option::Option::None,
-                    find_node_query: ::protobuf::SingularPtrField::none(),
-                    find_node_response: ::protobuf::SingularPtrField::none(),
-                    ping_query: ::protobuf::SingularPtrField::none(),
-                    ping_response: ::protobuf::SingularPtrField::none(),
-                    packet_query: ::protobuf::SingularPtrField::none(),
-                    packet_response: ::protobuf::SingularPtrField::none(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+            instance.get(SavedBucket::new)
         }
     }
 
-    // required .Envelope.Type message_type = 1;
+    // required bytes prefix = 1;
 
-    pub fn clear_message_type(&mut self) {
-        self.message_type = ::std::option::Option::None;
+    pub fn clear_prefix(&mut self) {
+        self.prefix.clear();
     }
 
-    pub fn has_message_type(&self) -> bool {
-        self.message_type.is_some()
+    pub fn has_prefix(&self) -> bool {
+        self.prefix.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_message_type(&mut self, v: Envelope_Type) {
-        self.message_type = ::std::option::Option::Some(v);
+    pub fn set_prefix(&mut self, v: ::std::vec::Vec<u8>) {
+        self.prefix = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_message_type(&self) -> Envelope_Type {
-        self.message_type.unwrap_or(Envelope_Type::FIND_NODE_QUERY)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefix(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.prefix.is_none() {
+            self.prefix.set_default();
+        }
+        self.prefix.as_mut().unwrap()
     }
 
-    // required uint32 transaction_id = 2;
-
-    pub fn clear_transaction_id(&mut self) {
-        self.transaction_id = ::std::option::Option::None;
+    // Take field
+    pub fn take_prefix(&mut self) -> ::std::vec::Vec<u8> {
+        self.prefix.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn has_transaction_id(&self) -> bool {
-        self.transaction_id.is_some()
+    pub fn get_prefix(&self) -> &[u8] {
+        match self.prefix.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
     }
 
-    // Param is passed by value, moved
-    pub fn set_transaction_id(&mut self, v: u32) {
-        self.transaction_id = ::std::option::Option::Some(v);
+    fn get_prefix_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.prefix
     }
 
-    pub fn get_transaction_id(&self) -> u32 {
-        self.transaction_id.unwrap_or(0)
+    fn mut_prefix_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.prefix
     }
 
-    // optional .FindNodeQuery find_node_query = 3;
+    // required uint32 depth = 2;
 
-    pub fn clear_find_node_query(&mut self) {
-        self.find_node_query.clear();
+    pub fn clear_depth(&mut self) {
+        self.depth = ::std::option::Option::None;
     }
 
-    pub fn has_find_node_query(&self) -> bool {
-        self.find_node_query.is_some()
+    pub fn has_depth(&self) -> bool {
+        self.depth.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_find_node_query(&mut self, v: FindNodeQuery) {
-        self.find_node_query = ::protobuf::SingularPtrField::some(v);
+    pub fn set_depth(&mut self, v: u32) {
+        self.depth = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_find_node_query(&mut self) -> &mut FindNodeQuery {
-        if self.find_node_query.is_none() {
-            self.find_node_query.set_default();
-        };
-        self.find_node_query.as_mut().unwrap()
+    pub fn get_depth(&self) -> u32 {
+        self.depth.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_find_node_query(&mut self) -> FindNodeQuery {
-        self.find_node_query.take().unwrap_or_else(|| FindNodeQuery::new())
+    fn get_depth_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.depth
     }
 
-    pub fn get_find_node_query(&self) -> &FindNodeQuery {
-        self.find_node_query.as_ref().unwrap_or_else(|| FindNodeQuery::default_instance())
+    fn mut_depth_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.depth
     }
 
-    // optional .FindNodeResponse find_node_response = 4;
-
-    pub fn clear_find_node_response(&mut self) {
-        self.find_node_response.clear();
-    }
+    // repeated .SavedNode nodes = 3;
 
-    pub fn has_find_node_response(&self) -> bool {
-        self.find_node_response.is_some()
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_find_node_response(&mut self, v: FindNodeResponse) {
-        self.find_node_response = ::protobuf::SingularPtrField::some(v);
+    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<SavedNode>) {
+        self.nodes = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_find_node_response(&mut self) -> &mut FindNodeResponse {
-        if self.find_node_response.is_none() {
-            self.find_node_response.set_default();
-        };
-        self.find_node_response.as_mut().unwrap()
+    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<SavedNode> {
+        &mut self.nodes
     }
 
     // Take field
-    pub fn take_find_node_response(&mut self) -> FindNodeResponse {
-        self.find_node_response.take().unwrap_or_else(|| FindNodeResponse::new())
+    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<SavedNode> {
+        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_find_node_response(&self) -> &FindNodeResponse {
-        self.find_node_response.as_ref().unwrap_or_else(|| FindNodeResponse::default_instance())
+    pub fn get_nodes(&self) -> &[SavedNode] {
+        &self.nodes
     }
 
-    // optional .PingQuery ping_query = 5;
+    fn get_nodes_for_reflect(&self) -> &::protobuf::RepeatedField<SavedNode> {
+        &self.nodes
+    }
 
-    pub fn clear_ping_query(&mut self) {
-        self.ping_query.clear();
+    fn mut_nodes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<SavedNode> {
+        &mut self.nodes
     }
+}
 
-    pub fn has_ping_query(&self) -> bool {
-        self.ping_query.is_some()
+impl ::protobuf::Message for SavedBucket {
+    fn is_initialized(&self) -> bool {
+        if self.prefix.is_none() {
+            return false;
+        }
+        if self.depth.is_none() {
+            return false;
+        }
+        for v in &self.nodes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.prefix)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.depth = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nodes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.prefix.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        if let Some(v) = self.depth {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.prefix.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        if let Some(v) = self.depth {
+            os.write_uint32(2, v)?;
+        }
+        for v in &self.nodes {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // Param is passed by value, moved
-    pub fn set_ping_query(&mut self, v: PingQuery) {
-        self.ping_query = ::protobuf::SingularPtrField::some(v);
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ping_query(&mut self) -> &mut PingQuery {
-        if self.ping_query.is_none() {
-            self.ping_query.set_default();
-        };
-        self.ping_query.as_mut().unwrap()
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // Take field
-    pub fn take_ping_query(&mut self) -> PingQuery {
-        self.ping_query.take().unwrap_or_else(|| PingQuery::new())
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    pub fn get_ping_query(&self) -> &PingQuery {
-        self.ping_query.as_ref().unwrap_or_else(|| PingQuery::default_instance())
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    // optional .PingResponse ping_response = 6;
+impl ::protobuf::MessageStatic for SavedBucket {
+    fn new() -> SavedBucket {
+        SavedBucket::new()
+    }
 
-    pub fn clear_ping_response(&mut self) {
-        self.ping_response.clear();
+    fn descriptor_static(_: ::std::option::Option<SavedBucket>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "prefix",
+                    SavedBucket::get_prefix_for_reflect,
+                    SavedBucket::mut_prefix_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "depth",
+                    SavedBucket::get_depth_for_reflect,
+                    SavedBucket::mut_depth_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SavedNode>>(
+                    "nodes",
+                    SavedBucket::get_nodes_for_reflect,
+                    SavedBucket::mut_nodes_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SavedBucket>(
+                    "SavedBucket",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
+}
 
-    pub fn has_ping_response(&self) -> bool {
-        self.ping_response.is_some()
+impl ::protobuf::Clear for SavedBucket {
+    fn clear(&mut self) {
+        self.clear_prefix();
+        self.clear_depth();
+        self.clear_nodes();
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_ping_response(&mut self, v: PingResponse) {
-        self.ping_response = ::protobuf::SingularPtrField::some(v);
+impl ::std::fmt::Debug for SavedBucket {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ping_response(&mut self) -> &mut PingResponse {
-        if self.ping_response.is_none() {
-            self.ping_response.set_default();
-        };
-        self.ping_response.as_mut().unwrap()
+impl ::protobuf::reflect::ProtobufValue for SavedBucket {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    // Take field
-    pub fn take_ping_response(&mut self) -> PingResponse {
-        self.ping_response.take().unwrap_or_else(|| PingResponse::new())
+#[derive(PartialEq,Clone,Default)]
+pub struct SavedRoutingTable {
+    // message fields
+    k: ::std::option::Option<u32>,
+    buckets: ::protobuf::RepeatedField<SavedBucket>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SavedRoutingTable {}
+
+impl SavedRoutingTable {
+    pub fn new() -> SavedRoutingTable {
+        ::std::default::Default::default()
     }
 
-    pub fn get_ping_response(&self) -> &PingResponse {
-        self.ping_response.as_ref().unwrap_or_else(|| PingResponse::default_instance())
+    pub fn default_instance() -> &'static SavedRoutingTable {
+        static mut instance: ::protobuf::lazy::Lazy<SavedRoutingTable> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SavedRoutingTable,
+        };
+        unsafe {
+            instance.get(SavedRoutingTable::new)
+        }
     }
 
-    // optional .PacketQuery packet_query = 7;
+    // required uint32 k = 1;
 
-    pub fn clear_packet_query(&mut self) {
-        self.packet_query.clear();
+    pub fn clear_k(&mut self) {
+        self.k = ::std::option::Option::None;
     }
 
-    pub fn has_packet_query(&self) -> bool {
-        self.packet_query.is_some()
+    pub fn has_k(&self) -> bool {
+        self.k.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_packet_query(&mut self, v: PacketQuery) {
-        self.packet_query = ::protobuf::SingularPtrField::some(v);
+    pub fn set_k(&mut self, v: u32) {
+        self.k = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_packet_query(&mut self) -> &mut PacketQuery {
-        if self.packet_query.is_none() {
-            self.packet_query.set_default();
-        };
-        self.packet_query.as_mut().unwrap()
+    pub fn get_k(&self) -> u32 {
+        self.k.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_packet_query(&mut self) -> PacketQuery {
-        self.packet_query.take().unwrap_or_else(|| PacketQuery::new())
+    fn get_k_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.k
     }
 
-    pub fn get_packet_query(&self) -> &PacketQuery {
-        self.packet_query.as_ref().unwrap_or_else(|| PacketQuery::default_instance())
+    fn mut_k_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.k
     }
 
-    // optional .PacketResponse packet_response = 8;
-
-    pub fn clear_packet_response(&mut self) {
-        self.packet_response.clear();
-    }
+    // repeated .SavedBucket buckets = 2;
 
-    pub fn has_packet_response(&self) -> bool {
-        self.packet_response.is_some()
+    pub fn clear_buckets(&mut self) {
+        self.buckets.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_packet_response(&mut self, v: PacketResponse) {
-        self.packet_response = ::protobuf::SingularPtrField::some(v);
+    pub fn set_buckets(&mut self, v: ::protobuf::RepeatedField<SavedBucket>) {
+        self.buckets = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_packet_response(&mut self) -> &mut PacketResponse {
-        if self.packet_response.is_none() {
-            self.packet_response.set_default();
-        };
-        self.packet_response.as_mut().unwrap()
+    pub fn mut_buckets(&mut self) -> &mut ::protobuf::RepeatedField<SavedBucket> {
+        &mut self.buckets
     }
 
     // Take field
-    pub fn take_packet_response(&mut self) -> PacketResponse {
-        self.packet_response.take().unwrap_or_else(|| PacketResponse::new())
+    pub fn take_buckets(&mut self) -> ::protobuf::RepeatedField<SavedBucket> {
+        ::std::mem::replace(&mut self.buckets, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_packet_response(&self) -> &PacketResponse {
-        self.packet_response.as_ref().unwrap_or_else(|| PacketResponse::default_instance())
+    pub fn get_buckets(&self) -> &[SavedBucket] {
+        &self.buckets
+    }
+
+    fn get_buckets_for_reflect(&self) -> &::protobuf::RepeatedField<SavedBucket> {
+        &self.buckets
+    }
+
+    fn mut_buckets_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<SavedBucket> {
+        &mut self.buckets
     }
 }
 
-impl ::protobuf::Message for Envelope {
+impl ::protobuf::Message for SavedRoutingTable {
     fn is_initialized(&self) -> bool {
-        if self.message_type.is_none() {
-            return false;
-        };
-        if self.transaction_id.is_none() {
+        if self.k.is_none() {
             return false;
+        }
+        for v in &self.buckets {
+            if !v.is_initialized() {
+                return false;
+            }
         };
         true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_enum());
-                    self.message_type = ::std::option::Option::Some(tmp);
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.k = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint32());
-                    self.transaction_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.find_node_query));
-                },
-                4 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.find_node_response));
-                },
-                5 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ping_query));
-                },
-                6 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ping_response));
-                },
-                7 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.packet_query));
-                },
-                8 => {
-                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.packet_response));
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.buckets)?;
                 },
                 _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
@@ -2366,33 +7090,10 @@ impl ::protobuf::Message for Envelope {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.message_type.iter() {
-            my_size += ::protobuf::rt::enum_size(1, *value);
-        };
-        for value in self.transaction_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.find_node_query.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.find_node_response.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.ping_query.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.ping_response.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.packet_query.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in self.packet_response.iter() {
+        if let Some(v) = self.k {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.buckets {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
@@ -2402,43 +7103,15 @@ impl ::protobuf::Message for Envelope {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.message_type {
-            try!(os.write_enum(1, v.value()));
+        if let Some(v) = self.k {
+            os.write_uint32(1, v)?;
+        }
+        for v in &self.buckets {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         };
-        if let Some(v) = self.transaction_id {
-            try!(os.write_uint32(2, v));
-        };
-        if let Some(v) = self.find_node_query.as_ref() {
-            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.find_node_response.as_ref() {
-            try!(os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.ping_query.as_ref() {
-            try!(os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.ping_response.as_ref() {
-            try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.packet_query.as_ref() {
-            try!(os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        if let Some(v) = self.packet_response.as_ref() {
-            try!(os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
-        };
-        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
@@ -2454,25 +7127,27 @@ impl ::protobuf::Message for Envelope {
         &mut self.unknown_fields
     }
 
-    fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Envelope>()
-    }
-
     fn as_any(&self) -> &::std::any::Any {
         self as &::std::any::Any
     }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
 }
 
-impl ::protobuf::MessageStatic for Envelope {
-    fn new() -> Envelope {
-        Envelope::new()
+impl ::protobuf::MessageStatic for SavedRoutingTable {
+    fn new() -> SavedRoutingTable {
+        SavedRoutingTable::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Envelope>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<SavedRoutingTable>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2480,48 +7155,18 @@ impl ::protobuf::MessageStatic for Envelope {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
-                    "message_type",
-                    Envelope::has_message_type,
-                    Envelope::get_message_type,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
-                    "transaction_id",
-                    Envelope::has_transaction_id,
-                    Envelope::get_transaction_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "find_node_query",
-                    Envelope::has_find_node_query,
-                    Envelope::get_find_node_query,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "find_node_response",
-                    Envelope::has_find_node_response,
-                    Envelope::get_find_node_response,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "ping_query",
-                    Envelope::has_ping_query,
-                    Envelope::get_ping_query,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "ping_response",
-                    Envelope::has_ping_response,
-                    Envelope::get_ping_response,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "packet_query",
-                    Envelope::has_packet_query,
-                    Envelope::get_packet_query,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "k",
+                    SavedRoutingTable::get_k_for_reflect,
+                    SavedRoutingTable::mut_k_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "packet_response",
-                    Envelope::has_packet_response,
-                    Envelope::get_packet_response,
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SavedBucket>>(
+                    "buckets",
+                    SavedRoutingTable::get_buckets_for_reflect,
+                    SavedRoutingTable::mut_buckets_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Envelope>(
-                    "Envelope",
+                ::protobuf::reflect::MessageDescriptor::new::<SavedRoutingTable>(
+                    "SavedRoutingTable",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2530,321 +7175,449 @@ impl ::protobuf::MessageStatic for Envelope {
     }
 }
 
-impl ::protobuf::Clear for Envelope {
+impl ::protobuf::Clear for SavedRoutingTable {
     fn clear(&mut self) {
-        self.clear_message_type();
-        self.clear_transaction_id();
-        self.clear_find_node_query();
-        self.clear_find_node_response();
-        self.clear_ping_query();
-        self.clear_ping_response();
-        self.clear_packet_query();
-        self.clear_packet_response();
+        self.clear_k();
+        self.clear_buckets();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for Envelope {
-    fn eq(&self, other: &Envelope) -> bool {
-        self.message_type == other.message_type &&
-        self.transaction_id == other.transaction_id &&
-        self.find_node_query == other.find_node_query &&
-        self.find_node_response == other.find_node_response &&
-        self.ping_query == other.ping_query &&
-        self.ping_response == other.ping_response &&
-        self.packet_query == other.packet_query &&
-        self.packet_response == other.packet_response &&
-        self.unknown_fields == other.unknown_fields
-    }
-}
-
-impl ::std::fmt::Debug for Envelope {
+impl ::std::fmt::Debug for SavedRoutingTable {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum Envelope_Type {
-    FIND_NODE_QUERY = 1,
-    FIND_NODE_RESPONSE = 2,
-    PING_QUERY = 3,
-    PING_RESPONSE = 4,
-    PACKET_QUERY = 5,
-    PACKET_RESPONSE = 6,
-}
-
-impl ::protobuf::ProtobufEnum for Envelope_Type {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
-
-    fn from_i32(value: i32) -> ::std::option::Option<Envelope_Type> {
-        match value {
-            1 => ::std::option::Option::Some(Envelope_Type::FIND_NODE_QUERY),
-            2 => ::std::option::Option::Some(Envelope_Type::FIND_NODE_RESPONSE),
-            3 => ::std::option::Option::Some(Envelope_Type::PING_QUERY),
-            4 => ::std::option::Option::Some(Envelope_Type::PING_RESPONSE),
-            5 => ::std::option::Option::Some(Envelope_Type::PACKET_QUERY),
-            6 => ::std::option::Option::Some(Envelope_Type::PACKET_RESPONSE),
-            _ => ::std::option::Option::None
-        }
-    }
-
-    fn values() -> &'static [Self] {
-        static values: &'static [Envelope_Type] = &[
-            Envelope_Type::FIND_NODE_QUERY,
-            Envelope_Type::FIND_NODE_RESPONSE,
-            Envelope_Type::PING_QUERY,
-            Envelope_Type::PING_RESPONSE,
-            Envelope_Type::PACKET_QUERY,
-            Envelope_Type::PACKET_RESPONSE,
-        ];
-        values
-    }
-
-    fn enum_descriptor_static(_: Option<Envelope_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Envelope_Type", file_descriptor_proto())
-            })
-        }
+impl ::protobuf::reflect::ProtobufValue for SavedRoutingTable {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-impl ::std::marker::Copy for Envelope_Type {
-}
-
-static file_descriptor_proto_data: &'static [u8] = &[
-    0x0a, 0x1c, 0x73, 0x72, 0x63, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x2f, 0x70,
-    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x41,
-    0x0a, 0x0c, 0x55, 0x64, 0x70, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x1d,
-    0x0a, 0x0a, 0x69, 0x70, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x0c, 0x52, 0x09, 0x69, 0x70, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x12, 0x0a,
-    0x04, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0d, 0x52, 0x04, 0x70, 0x6f, 0x72,
-    0x74, 0x22, 0x88, 0x01, 0x0a, 0x09, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72, 0x74, 0x12,
-    0x36, 0x0a, 0x0e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x74, 0x79, 0x70,
-    0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x70,
-    0x6f, 0x72, 0x74, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x0d, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x70,
-    0x6f, 0x72, 0x74, 0x54, 0x79, 0x70, 0x65, 0x12, 0x32, 0x0a, 0x0d, 0x75, 0x64, 0x70, 0x5f, 0x74,
-    0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d,
-    0x2e, 0x55, 0x64, 0x70, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x0c, 0x75,
-    0x64, 0x70, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72, 0x74, 0x22, 0x0f, 0x0a, 0x04, 0x54,
-    0x79, 0x70, 0x65, 0x12, 0x07, 0x0a, 0x03, 0x55, 0x44, 0x50, 0x10, 0x01, 0x22, 0x42, 0x0a, 0x04,
-    0x4e, 0x6f, 0x64, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09,
-    0x52, 0x02, 0x69, 0x64, 0x12, 0x2a, 0x0a, 0x0a, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72,
-    0x74, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73,
-    0x70, 0x6f, 0x72, 0x74, 0x52, 0x0a, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72, 0x74, 0x73,
-    0x22, 0x46, 0x0a, 0x0d, 0x46, 0x69, 0x6e, 0x64, 0x4e, 0x6f, 0x64, 0x65, 0x51, 0x75, 0x65, 0x72,
-    0x79, 0x12, 0x1d, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28,
-    0x0b, 0x32, 0x05, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x52, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x12, 0x16, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09,
-    0x52, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x22, 0x4e, 0x0a, 0x10, 0x46, 0x69, 0x6e, 0x64,
-    0x4e, 0x6f, 0x64, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1d, 0x0a, 0x06,
-    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x4e,
-    0x6f, 0x64, 0x65, 0x52, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x12, 0x1b, 0x0a, 0x05, 0x6e,
-    0x6f, 0x64, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x4e, 0x6f, 0x64,
-    0x65, 0x52, 0x05, 0x6e, 0x6f, 0x64, 0x65, 0x73, 0x22, 0x2a, 0x0a, 0x09, 0x50, 0x69, 0x6e, 0x67,
-    0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x1d, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18,
-    0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x52, 0x06, 0x6f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x22, 0x2d, 0x0a, 0x0c, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x73, 0x70,
-    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1d, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01,
-    0x20, 0x02, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x52, 0x06, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x22, 0x46, 0x0a, 0x0b, 0x50, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x51, 0x75, 0x65,
-    0x72, 0x79, 0x12, 0x1d, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x0b, 0x32, 0x05, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x52, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x02, 0x20, 0x02,
-    0x28, 0x0c, 0x52, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0x2f, 0x0a, 0x0e, 0x50,
-    0x61, 0x63, 0x6b, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1d, 0x0a,
-    0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x05, 0x2e,
-    0x4e, 0x6f, 0x64, 0x65, 0x52, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x22, 0xa6, 0x04, 0x0a,
-    0x08, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x31, 0x0a, 0x0c, 0x6d, 0x65, 0x73,
-    0x73, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32,
-    0x0e, 0x2e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52,
-    0x0b, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x25, 0x0a, 0x0e,
-    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02,
-    0x20, 0x02, 0x28, 0x0d, 0x52, 0x0d, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
-    0x6e, 0x49, 0x64, 0x12, 0x36, 0x0a, 0x0f, 0x66, 0x69, 0x6e, 0x64, 0x5f, 0x6e, 0x6f, 0x64, 0x65,
-    0x5f, 0x71, 0x75, 0x65, 0x72, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x46,
-    0x69, 0x6e, 0x64, 0x4e, 0x6f, 0x64, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x0d, 0x66, 0x69,
-    0x6e, 0x64, 0x4e, 0x6f, 0x64, 0x65, 0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x3f, 0x0a, 0x12, 0x66,
-    0x69, 0x6e, 0x64, 0x5f, 0x6e, 0x6f, 0x64, 0x65, 0x5f, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-    0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x46, 0x69, 0x6e, 0x64, 0x4e, 0x6f,
-    0x64, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x10, 0x66, 0x69, 0x6e, 0x64,
-    0x4e, 0x6f, 0x64, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x29, 0x0a, 0x0a,
-    0x70, 0x69, 0x6e, 0x67, 0x5f, 0x71, 0x75, 0x65, 0x72, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b,
-    0x32, 0x0a, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x09, 0x70, 0x69,
-    0x6e, 0x67, 0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x32, 0x0a, 0x0d, 0x70, 0x69, 0x6e, 0x67, 0x5f,
-    0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d,
-    0x2e, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x0c, 0x70,
-    0x69, 0x6e, 0x67, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2f, 0x0a, 0x0c, 0x70,
-    0x61, 0x63, 0x6b, 0x65, 0x74, 0x5f, 0x71, 0x75, 0x65, 0x72, 0x79, 0x18, 0x07, 0x20, 0x01, 0x28,
-    0x0b, 0x32, 0x0c, 0x2e, 0x50, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52,
-    0x0b, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x38, 0x0a, 0x0f,
-    0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x5f, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x18,
-    0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x50, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x52, 0x65,
-    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x0e, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x52, 0x65,
-    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x7d, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x13,
-    0x0a, 0x0f, 0x46, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x4f, 0x44, 0x45, 0x5f, 0x51, 0x55, 0x45, 0x52,
-    0x59, 0x10, 0x01, 0x12, 0x16, 0x0a, 0x12, 0x46, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x4f, 0x44, 0x45,
-    0x5f, 0x52, 0x45, 0x53, 0x50, 0x4f, 0x4e, 0x53, 0x45, 0x10, 0x02, 0x12, 0x0e, 0x0a, 0x0a, 0x50,
-    0x49, 0x4e, 0x47, 0x5f, 0x51, 0x55, 0x45, 0x52, 0x59, 0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x50,
-    0x49, 0x4e, 0x47, 0x5f, 0x52, 0x45, 0x53, 0x50, 0x4f, 0x4e, 0x53, 0x45, 0x10, 0x04, 0x12, 0x10,
-    0x0a, 0x0c, 0x50, 0x41, 0x43, 0x4b, 0x45, 0x54, 0x5f, 0x51, 0x55, 0x45, 0x52, 0x59, 0x10, 0x05,
-    0x12, 0x13, 0x0a, 0x0f, 0x50, 0x41, 0x43, 0x4b, 0x45, 0x54, 0x5f, 0x52, 0x45, 0x53, 0x50, 0x4f,
-    0x4e, 0x53, 0x45, 0x10, 0x06, 0x4a, 0xb3, 0x12, 0x0a, 0x06, 0x12, 0x04, 0x02, 0x00, 0x46, 0x01,
-    0x0a, 0x13, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x05, 0x01, 0x32, 0x07, 0x20, 0x54,
-    0x79, 0x70, 0x65, 0x73, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08,
-    0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x04, 0x22, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0d, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x13, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x03, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03,
-    0x04, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x04, 0x04,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x04, 0x0d, 0x13, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x14, 0x18, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x01, 0x12, 0x04, 0x07, 0x00, 0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03,
-    0x07, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x04, 0x08, 0x04, 0x0a,
-    0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x03, 0x08, 0x09, 0x0d, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x09, 0x08, 0x10, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x08, 0x0b, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x09, 0x0e, 0x0f, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x0b, 0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x0b, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x0b, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x2c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0c, 0x04, 0x0c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0c, 0x0d, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x1a, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x2a, 0x2b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04,
-    0x0f, 0x00, 0x12, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x08, 0x0c,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x04, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x10, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x10, 0x14, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x10, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x11,
-    0x04, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x11, 0x04, 0x0c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x11, 0x0d, 0x16, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11, 0x17, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x11, 0x24, 0x25, 0x0a, 0x16, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x16, 0x00, 0x19, 0x01, 0x32, 0x0a, 0x20, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
-    0x73, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x16, 0x08, 0x15, 0x0a, 0x1c,
-    0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x17, 0x04, 0x1d, 0x22, 0x0f, 0x20, 0x51, 0x75,
-    0x65, 0x72, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6e, 0x6f, 0x64, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x17, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x17, 0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x17, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x17, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x18, 0x04,
-    0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x18, 0x04, 0x0c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x0d, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18, 0x14, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x18, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12,
-    0x04, 0x1b, 0x00, 0x1e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1b, 0x08,
-    0x18, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x04, 0x1d, 0x22, 0x11,
-    0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6e, 0x6f, 0x64, 0x65,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1c, 0x04, 0x0c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1c, 0x0d, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1c, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
-    0x01, 0x12, 0x03, 0x1d, 0x04, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x1d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x1d,
-    0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1d, 0x12, 0x17,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1d, 0x1a, 0x1b, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x20, 0x00, 0x22, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05,
-    0x01, 0x12, 0x03, 0x20, 0x08, 0x11, 0x0a, 0x1c, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03,
-    0x21, 0x04, 0x1d, 0x22, 0x0f, 0x20, 0x51, 0x75, 0x65, 0x72, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6e,
-    0x6f, 0x64, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x21,
-    0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x21, 0x0d, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21, 0x12, 0x18, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x21, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x06, 0x12, 0x04, 0x24, 0x00, 0x26, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12,
-    0x03, 0x24, 0x08, 0x14, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x25, 0x04,
-    0x1d, 0x22, 0x11, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6e,
-    0x6f, 0x64, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x25,
-    0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03, 0x25, 0x0d, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x12, 0x18, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x25, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x07, 0x12, 0x04, 0x28, 0x00, 0x2b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12,
-    0x03, 0x28, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x29, 0x04,
-    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x29, 0x04, 0x0c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x29, 0x0d, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x29, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x29, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02,
-    0x01, 0x12, 0x03, 0x2a, 0x04, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x2a, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2a,
-    0x0d, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2a, 0x13, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2a, 0x1d, 0x1e, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x2d, 0x00, 0x2f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08,
-    0x01, 0x12, 0x03, 0x2d, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03,
-    0x2e, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2e, 0x04,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x06, 0x12, 0x03, 0x2e, 0x0d, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x18, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a, 0x15, 0x0a, 0x02, 0x04,
-    0x09, 0x12, 0x04, 0x33, 0x00, 0x46, 0x01, 0x32, 0x09, 0x20, 0x57, 0x72, 0x61, 0x70, 0x70, 0x65,
-    0x72, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x33, 0x08, 0x10, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x09, 0x04, 0x00, 0x12, 0x04, 0x34, 0x04, 0x3b, 0x05, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x04, 0x00, 0x01, 0x12, 0x03, 0x34, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09,
-    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x35, 0x08, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x35, 0x08, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x35, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04,
-    0x00, 0x02, 0x01, 0x12, 0x03, 0x36, 0x08, 0x1f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x36, 0x08, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00,
-    0x02, 0x01, 0x02, 0x12, 0x03, 0x36, 0x1d, 0x1e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00,
-    0x02, 0x02, 0x12, 0x03, 0x37, 0x08, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x37, 0x08, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02,
-    0x02, 0x02, 0x12, 0x03, 0x37, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02,
-    0x03, 0x12, 0x03, 0x38, 0x08, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x03,
-    0x01, 0x12, 0x03, 0x38, 0x08, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x03,
-    0x02, 0x12, 0x03, 0x38, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x04,
-    0x12, 0x03, 0x39, 0x08, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x04, 0x01,
-    0x12, 0x03, 0x39, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x04, 0x02,
-    0x12, 0x03, 0x39, 0x17, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x05, 0x12,
-    0x03, 0x3a, 0x08, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12,
-    0x03, 0x3a, 0x08, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12,
-    0x03, 0x3a, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x3c, 0x04,
-    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3c, 0x04, 0x0c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3c, 0x0d, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3c, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3c, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02,
-    0x01, 0x12, 0x03, 0x3d, 0x04, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x3d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3d,
-    0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3d, 0x14, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3d, 0x25, 0x26, 0x0a, 0x2a,
-    0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x40, 0x04, 0x2f, 0x1a, 0x1d, 0x20, 0x52, 0x65,
-    0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x73, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68,
-    0x20, 0x41, 0x6e, 0x79, 0x20, 0x74, 0x79, 0x70, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x02, 0x04, 0x12, 0x03, 0x40, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02,
-    0x06, 0x12, 0x03, 0x40, 0x0d, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x40, 0x1b, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x40,
-    0x2d, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x41, 0x04, 0x35, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x04, 0x12, 0x03, 0x41, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x41, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x41, 0x1e, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x03, 0x03, 0x12, 0x03, 0x41, 0x33, 0x34, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x04, 0x12,
-    0x03, 0x42, 0x04, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x04, 0x12, 0x03, 0x42,
-    0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x06, 0x12, 0x03, 0x42, 0x0d, 0x16,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x03, 0x42, 0x17, 0x21, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03, 0x42, 0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x09, 0x02, 0x05, 0x12, 0x03, 0x43, 0x04, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x05, 0x04, 0x12, 0x03, 0x43, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x06,
-    0x12, 0x03, 0x43, 0x0d, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x01, 0x12, 0x03,
-    0x43, 0x1a, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x03, 0x12, 0x03, 0x43, 0x2a,
-    0x2b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x06, 0x12, 0x03, 0x44, 0x04, 0x2a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x04, 0x12, 0x03, 0x44, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x02, 0x06, 0x06, 0x12, 0x03, 0x44, 0x0d, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x06, 0x01, 0x12, 0x03, 0x44, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06,
-    0x03, 0x12, 0x03, 0x44, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x07, 0x12, 0x03,
-    0x45, 0x04, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x07, 0x04, 0x12, 0x03, 0x45, 0x04,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x07, 0x06, 0x12, 0x03, 0x45, 0x0d, 0x1b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x07, 0x01, 0x12, 0x03, 0x45, 0x1c, 0x2b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x07, 0x03, 0x12, 0x03, 0x45, 0x2e, 0x2f,
-];
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1csrc/messages/protobufs.proto\"A\n\x0cUdpTransport\x12\x1d\n\nip_ad\
+    dress\x18\x01\x20\x02(\x0cR\tipAddress\x12\x12\n\x04port\x18\x02\x20\x02\
+    (\rR\x04port\"A\n\x0cTcpTransport\x12\x1d\n\nip_address\x18\x01\x20\x02(\
+    \x0cR\tipAddress\x12\x12\n\x04port\x18\x02\x20\x02(\rR\x04port\"%\n\x0fM\
+    emoryTransport\x12\x12\n\x04name\x18\x01\x20\x02(\tR\x04name\"#\n\rUnixT\
+    ransport\x12\x12\n\x04path\x18\x01\x20\x02(\tR\x04path\"<\n\x12WebSocket\
+    Transport\x12\x12\n\x04host\x18\x01\x20\x02(\tR\x04host\x12\x12\n\x04pat\
+    h\x18\x02\x20\x02(\tR\x04path\"/\n\x10RelayedTransport\x12\x1b\n\x05rela\
+    y\x18\x01\x20\x02(\x0b2\x05.NodeR\x05relay\"\xf1\x03\n\tTransport\x126\n\
+    \x0etransport_type\x18\x01\x20\x01(\x0e2\x0f.Transport.TypeR\rtransportT\
+    ype\x122\n\rudp_transport\x18\x02\x20\x01(\x0b2\r.UdpTransportR\x0cudpTr\
+    ansport\x122\n\rtcp_transport\x18\x03\x20\x01(\x0b2\r.TcpTransportR\x0ct\
+    cpTransport\x12;\n\x10memory_transport\x18\x04\x20\x01(\x0b2\x10.MemoryT\
+    ransportR\x0fmemoryTransport\x125\n\x0eunix_transport\x18\x05\x20\x01(\
+    \x0b2\x0e.UnixTransportR\runixTransport\x12D\n\x13websocket_transport\
+    \x18\x06\x20\x01(\x0b2\x13.WebSocketTransportR\x12websocketTransport\x12\
+    >\n\x11relayed_transport\x18\x07\x20\x01(\x0b2\x11.RelayedTransportR\x10\
+    relayedTransport\"J\n\x04Type\x12\x07\n\x03UDP\x10\x01\x12\x07\n\x03TCP\
+    \x10\x02\x12\n\n\x06MEMORY\x10\x03\x12\x08\n\x04UNIX\x10\x04\x12\r\n\tWE\
+    BSOCKET\x10\x05\x12\x0b\n\x07RELAYED\x10\x06\"\x85\x01\n\x04Node\x12\x0e\
+    \n\x02id\x18\x01\x20\x02(\x0cR\x02id\x12*\n\ntransports\x18\x02\x20\x03(\
+    \x0b2\n.TransportR\ntransports\x12\x1d\n\npublic_key\x18\x03\x20\x01(\
+    \x0cR\tpublicKey\x12\"\n\x0ccapabilities\x18\x04\x20\x01(\x04R\x0ccapabi\
+    lities\"F\n\rFindNodeQuery\x12\x1d\n\x06origin\x18\x01\x20\x02(\x0b2\x05\
+    .NodeR\x06origin\x12\x16\n\x06target\x18\x02\x20\x02(\x0cR\x06target\"N\
+    \n\x10FindNodeResponse\x12\x1d\n\x06origin\x18\x01\x20\x02(\x0b2\x05.Nod\
+    eR\x06origin\x12\x1b\n\x05nodes\x18\x02\x20\x03(\x0b2\x05.NodeR\x05nodes\
+    \"*\n\tPingQuery\x12\x1d\n\x06origin\x18\x01\x20\x02(\x0b2\x05.NodeR\x06\
+    origin\"-\n\x0cPingResponse\x12\x1d\n\x06origin\x18\x01\x20\x02(\x0b2\
+    \x05.NodeR\x06origin\"F\n\x08Fragment\x12\x0e\n\x02id\x18\x01\x20\x02(\r\
+    R\x02id\x12\x14\n\x05index\x18\x02\x20\x02(\rR\x05index\x12\x14\n\x05cou\
+    nt\x18\x03\x20\x02(\rR\x05count\"m\n\x0bPacketQuery\x12\x1d\n\x06origin\
+    \x18\x01\x20\x02(\x0b2\x05.NodeR\x06origin\x12\x18\n\x07payload\x18\x02\
+    \x20\x02(\x0cR\x07payload\x12%\n\x08fragment\x18\x03\x20\x01(\x0b2\t.Fra\
+    gmentR\x08fragment\"/\n\x0ePacketResponse\x12\x1d\n\x06origin\x18\x01\
+    \x20\x02(\x0b2\x05.NodeR\x06origin\"c\n\x0fRendezvousQuery\x12\x1d\n\x06\
+    origin\x18\x01\x20\x02(\x0b2\x05.NodeR\x06origin\x12\x16\n\x06target\x18\
+    \x02\x20\x02(\x0cR\x06target\x12\x19\n\x04peer\x18\x03\x20\x01(\x0b2\x05\
+    .NodeR\x04peer\"N\n\x12RendezvousResponse\x12\x1d\n\x06origin\x18\x01\
+    \x20\x02(\x0b2\x05.NodeR\x06origin\x12\x19\n\x04peer\x18\x02\x20\x01(\
+    \x0b2\x05.NodeR\x04peer\"3\n\x12RegisterRelayQuery\x12\x1d\n\x06origin\
+    \x18\x01\x20\x02(\x0b2\x05.NodeR\x06origin\"R\n\x15RegisterRelayResponse\
+    \x12\x1d\n\x06origin\x18\x01\x20\x02(\x0b2\x05.NodeR\x06origin\x12\x1a\n\
+    \x08accepted\x18\x02\x20\x02(\x08R\x08accepted\"9\n\x05Relay\x12\x16\n\
+    \x06target\x18\x01\x20\x02(\x0cR\x06target\x12\x18\n\x07payload\x18\x02\
+    \x20\x02(\x0cR\x07payload\"\x99\x08\n\x08Envelope\x121\n\x0cmessage_type\
+    \x18\x01\x20\x01(\x0e2\x0e.Envelope.TypeR\x0bmessageType\x12%\n\x0etrans\
+    action_id\x18\x02\x20\x02(\rR\rtransactionId\x126\n\x0ffind_node_query\
+    \x18\x03\x20\x01(\x0b2\x0e.FindNodeQueryR\rfindNodeQuery\x12?\n\x12find_\
+    node_response\x18\x04\x20\x01(\x0b2\x11.FindNodeResponseR\x10findNodeRes\
+    ponse\x12)\n\nping_query\x18\x05\x20\x01(\x0b2\n.PingQueryR\tpingQuery\
+    \x122\n\rping_response\x18\x06\x20\x01(\x0b2\r.PingResponseR\x0cpingResp\
+    onse\x12/\n\x0cpacket_query\x18\x07\x20\x01(\x0b2\x0c.PacketQueryR\x0bpa\
+    cketQuery\x128\n\x0fpacket_response\x18\x08\x20\x01(\x0b2\x0f.PacketResp\
+    onseR\x0epacketResponse\x12;\n\x10rendezvous_query\x18\t\x20\x01(\x0b2\
+    \x10.RendezvousQueryR\x0frendezvousQuery\x12D\n\x13rendezvous_response\
+    \x18\n\x20\x01(\x0b2\x13.RendezvousResponseR\x12rendezvousResponse\x12E\
+    \n\x14register_relay_query\x18\x0b\x20\x01(\x0b2\x13.RegisterRelayQueryR\
+    \x12registerRelayQuery\x12N\n\x17register_relay_response\x18\x0c\x20\x01\
+    (\x0b2\x16.RegisterRelayResponseR\x15registerRelayResponse\x12\x1c\n\x05\
+    relay\x18\r\x20\x01(\x0b2\x06.RelayR\x05relay\x12\x1c\n\tsignature\x18\
+    \x0e\x20\x01(\x0cR\tsignature\x12)\n\x10protocol_version\x18\x0f\x20\x01\
+    (\rR\x0fprotocolVersion\"\xee\x01\n\x04Type\x12\x13\n\x0fFIND_NODE_QUERY\
+    \x10\x01\x12\x16\n\x12FIND_NODE_RESPONSE\x10\x02\x12\x0e\n\nPING_QUERY\
+    \x10\x03\x12\x11\n\rPING_RESPONSE\x10\x04\x12\x10\n\x0cPACKET_QUERY\x10\
+    \x05\x12\x13\n\x0fPACKET_RESPONSE\x10\x06\x12\x14\n\x10RENDEZVOUS_QUERY\
+    \x10\x07\x12\x17\n\x13RENDEZVOUS_RESPONSE\x10\x08\x12\x18\n\x14REGISTER_\
+    RELAY_QUERY\x10\t\x12\x1b\n\x17REGISTER_RELAY_RESPONSE\x10\n\x12\t\n\x05\
+    RELAY\x10\x0b\"h\n\tSavedNode\x12\x19\n\x04node\x18\x01\x20\x02(\x0b2\
+    \x05.NodeR\x04node\x12\x1b\n\tlast_seen\x18\x02\x20\x02(\x03R\x08lastSee\
+    n\x12#\n\rhas_responded\x18\x03\x20\x02(\x08R\x0chasResponded\"]\n\x0bSa\
+    vedBucket\x12\x16\n\x06prefix\x18\x01\x20\x02(\x0cR\x06prefix\x12\x14\n\
+    \x05depth\x18\x02\x20\x02(\rR\x05depth\x12\x20\n\x05nodes\x18\x03\x20\
+    \x03(\x0b2\n.SavedNodeR\x05nodes\"I\n\x11SavedRoutingTable\x12\x0c\n\x01\
+    k\x18\x01\x20\x02(\rR\x01k\x12&\n\x07buckets\x18\x02\x20\x03(\x0b2\x0c.S\
+    avedBucketR\x07bucketsJ\xd2A\n\x07\x12\x05\x02\0\xbd\x01\x01\n\x13\n\x02\
+    \x04\0\x12\x04\x02\0\x05\x012\x07\x20Types\n\n\n\n\x03\x04\0\x01\x12\x03\
+    \x02\x08\x14\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\"\n\x0c\n\x05\x04\0\
+    \x02\0\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\r\
+    \x12\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x13\x1d\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\x03\x20!\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
+    \x1d\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x04\x04\x0c\n\x0c\n\x05\x04\0\
+    \x02\x01\x05\x12\x03\x04\r\x13\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\
+    \x14\x18\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x1b\x1c\n\n\n\x02\x04\
+    \x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x14\n\x0b\
+    \n\x04\x04\x01\x02\0\x12\x03\x08\x04\"\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
+    \x03\x08\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\r\x12\n\x0c\n\
+    \x05\x04\x01\x02\0\x01\x12\x03\x08\x13\x1d\n\x0c\n\x05\x04\x01\x02\0\x03\
+    \x12\x03\x08\x20!\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\x1d\n\x0c\n\
+    \x05\x04\x01\x02\x01\x04\x12\x03\t\x04\x0c\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03\t\r\x13\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x14\x18\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\x1b\x1c\n\n\n\x02\x04\x02\x12\
+    \x04\x0c\0\x0e\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x17\n\x0b\n\x04\
+    \x04\x02\x02\0\x12\x03\r\x04\x1d\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\r\
+    \x04\x0c\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\r\x13\n\x0c\n\x05\x04\
+    \x02\x02\0\x01\x12\x03\r\x14\x18\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\
+    \x1b\x1c\n\n\n\x02\x04\x03\x12\x04\x10\0\x12\x01\n\n\n\x03\x04\x03\x01\
+    \x12\x03\x10\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x11\x04\x1d\n\x0c\
+    \n\x05\x04\x03\x02\0\x04\x12\x03\x11\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\
+    \x05\x12\x03\x11\r\x13\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x11\x14\x18\
+    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x11\x1b\x1c\n\n\n\x02\x04\x04\x12\
+    \x04\x14\0\x17\x01\n\n\n\x03\x04\x04\x01\x12\x03\x14\x08\x1a\n4\n\x04\
+    \x04\x04\x02\0\x12\x03\x15\x04\x1d\"'\x20host:port,\x20which\x20may\x20b\
+    e\x20a\x20domain\x20name\n\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x15\x04\
+    \x0c\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x15\r\x13\n\x0c\n\x05\x04\x04\
+    \x02\0\x01\x12\x03\x15\x14\x18\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x15\
+    \x1b\x1c\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x16\x04\x1d\n\x0c\n\x05\x04\
+    \x04\x02\x01\x04\x12\x03\x16\x04\x0c\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\
+    \x03\x16\r\x13\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x16\x14\x18\n\x0c\
+    \n\x05\x04\x04\x02\x01\x03\x12\x03\x16\x1b\x1c\n\xf3\x01\n\x02\x04\x05\
+    \x12\x04\x1c\0\x1e\x01\x1a\xe6\x01\x20Reaches\x20a\x20node\x20that\x20ca\
+    n't\x20be\x20reached\x20directly,\x20e.g.\x20because\x20it's\x20behind\
+    \x20a\x20symmetric\x20NAT,\x20by\n\x20way\x20of\x20a\x20relay\x20it\x20k\
+    eeps\x20a\x20mapping\x20open\x20to.\x20Messages\x20for\x20the\x20node\
+    \x20are\x20wrapped\x20in\x20a\x20`Relay`\x20and\n\x20sent\x20to\x20`rela\
+    y`,\x20which\x20forwards\x20them\x20on.\n\n\n\n\x03\x04\x05\x01\x12\x03\
+    \x1c\x08\x18\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1d\x04\x1c\n\x0c\n\x05\
+    \x04\x05\x02\0\x04\x12\x03\x1d\x04\x0c\n\x0c\n\x05\x04\x05\x02\0\x06\x12\
+    \x03\x1d\r\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1d\x12\x17\n\x0c\n\
+    \x05\x04\x05\x02\0\x03\x12\x03\x1d\x1a\x1b\n\n\n\x02\x04\x06\x12\x04\x20\
+    \02\x01\n\n\n\x03\x04\x06\x01\x12\x03\x20\x08\x11\n\x0c\n\x04\x04\x06\
+    \x04\0\x12\x04!\x04(\x05\n\x0c\n\x05\x04\x06\x04\0\x01\x12\x03!\t\r\n\r\
+    \n\x06\x04\x06\x04\0\x02\0\x12\x03\"\x08\x10\n\x0e\n\x07\x04\x06\x04\0\
+    \x02\0\x01\x12\x03\"\x08\x0b\n\x0e\n\x07\x04\x06\x04\0\x02\0\x02\x12\x03\
+    \"\x0e\x0f\n\r\n\x06\x04\x06\x04\0\x02\x01\x12\x03#\x08\x10\n\x0e\n\x07\
+    \x04\x06\x04\0\x02\x01\x01\x12\x03#\x08\x0b\n\x0e\n\x07\x04\x06\x04\0\
+    \x02\x01\x02\x12\x03#\x0e\x0f\n\r\n\x06\x04\x06\x04\0\x02\x02\x12\x03$\
+    \x08\x13\n\x0e\n\x07\x04\x06\x04\0\x02\x02\x01\x12\x03$\x08\x0e\n\x0e\n\
+    \x07\x04\x06\x04\0\x02\x02\x02\x12\x03$\x11\x12\n\r\n\x06\x04\x06\x04\0\
+    \x02\x03\x12\x03%\x08\x11\n\x0e\n\x07\x04\x06\x04\0\x02\x03\x01\x12\x03%\
+    \x08\x0c\n\x0e\n\x07\x04\x06\x04\0\x02\x03\x02\x12\x03%\x0f\x10\n\r\n\
+    \x06\x04\x06\x04\0\x02\x04\x12\x03&\x08\x16\n\x0e\n\x07\x04\x06\x04\0\
+    \x02\x04\x01\x12\x03&\x08\x11\n\x0e\n\x07\x04\x06\x04\0\x02\x04\x02\x12\
+    \x03&\x14\x15\n\r\n\x06\x04\x06\x04\0\x02\x05\x12\x03'\x08\x14\n\x0e\n\
+    \x07\x04\x06\x04\0\x02\x05\x01\x12\x03'\x08\x0f\n\x0e\n\x07\x04\x06\x04\
+    \0\x02\x05\x02\x12\x03'\x12\x13\n\x98\x01\n\x04\x04\x06\x02\0\x12\x03+\
+    \x04%\x1a\x8a\x01\x20Optional\x20so\x20that\x20a\x20node\x20with\x20a\
+    \x20type\x20of\x20transport\x20from\x20a\x20later\x20version\x20than\x20\
+    ours\x20can\x20still\x20be\n\x20parsed,\x20without\x20the\x20transport\
+    \x20we\x20can't\x20use\n\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03+\x04\x0c\
+    \n\x0c\n\x05\x04\x06\x02\0\x06\x12\x03+\r\x11\n\x0c\n\x05\x04\x06\x02\0\
+    \x01\x12\x03+\x12\x20\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03+#$\n\x0b\n\
+    \x04\x04\x06\x02\x01\x12\x03,\x04,\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\
+    \x03,\x04\x0c\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\x03,\r\x19\n\x0c\n\x05\
+    \x04\x06\x02\x01\x01\x12\x03,\x1a'\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\
+    \x03,*+\n\x0b\n\x04\x04\x06\x02\x02\x12\x03-\x04,\n\x0c\n\x05\x04\x06\
+    \x02\x02\x04\x12\x03-\x04\x0c\n\x0c\n\x05\x04\x06\x02\x02\x06\x12\x03-\r\
+    \x19\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03-\x1a'\n\x0c\n\x05\x04\x06\
+    \x02\x02\x03\x12\x03-*+\n\x0b\n\x04\x04\x06\x02\x03\x12\x03.\x042\n\x0c\
+    \n\x05\x04\x06\x02\x03\x04\x12\x03.\x04\x0c\n\x0c\n\x05\x04\x06\x02\x03\
+    \x06\x12\x03.\r\x1c\n\x0c\n\x05\x04\x06\x02\x03\x01\x12\x03.\x1d-\n\x0c\
+    \n\x05\x04\x06\x02\x03\x03\x12\x03.01\n\x0b\n\x04\x04\x06\x02\x04\x12\
+    \x03/\x04.\n\x0c\n\x05\x04\x06\x02\x04\x04\x12\x03/\x04\x0c\n\x0c\n\x05\
+    \x04\x06\x02\x04\x06\x12\x03/\r\x1a\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\
+    \x03/\x1b)\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\x03/,-\n\x0b\n\x04\x04\
+    \x06\x02\x05\x12\x030\x048\n\x0c\n\x05\x04\x06\x02\x05\x04\x12\x030\x04\
+    \x0c\n\x0c\n\x05\x04\x06\x02\x05\x06\x12\x030\r\x1f\n\x0c\n\x05\x04\x06\
+    \x02\x05\x01\x12\x030\x203\n\x0c\n\x05\x04\x06\x02\x05\x03\x12\x03067\n\
+    \x0b\n\x04\x04\x06\x02\x06\x12\x031\x044\n\x0c\n\x05\x04\x06\x02\x06\x04\
+    \x12\x031\x04\x0c\n\x0c\n\x05\x04\x06\x02\x06\x06\x12\x031\r\x1d\n\x0c\n\
+    \x05\x04\x06\x02\x06\x01\x12\x031\x1e/\n\x0c\n\x05\x04\x06\x02\x06\x03\
+    \x12\x03123\n\xbc\x01\n\x02\x04\x07\x12\x046\0;\x01\x1a\xaf\x01\x20Addre\
+    sses\x20are\x2020\x20bytes.\x20Up\x20to\x20version\x202\x20they\x20were\
+    \x2040\x20character\x20hex\x20strings,\x20which\x20are\x20encoded\n\x20t\
+    he\x20same\x20as\x20`bytes`,\x20so\x20they're\x20still\x20decoded\x20whe\
+    n\x20they\x20come\x20from\x20older\x20nodes.\n\n\n\n\x03\x04\x07\x01\x12\
+    \x036\x08\x0c\n\x0b\n\x04\x04\x07\x02\0\x12\x037\x04\x1a\n\x0c\n\x05\x04\
+    \x07\x02\0\x04\x12\x037\x04\x0c\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x037\r\
+    \x12\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x037\x13\x15\n\x0c\n\x05\x04\x07\
+    \x02\0\x03\x12\x037\x18\x19\n\x0b\n\x04\x04\x07\x02\x01\x12\x038\x04&\n\
+    \x0c\n\x05\x04\x07\x02\x01\x04\x12\x038\x04\x0c\n\x0c\n\x05\x04\x07\x02\
+    \x01\x06\x12\x038\r\x16\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x038\x17!\n\
+    \x0c\n\x05\x04\x07\x02\x01\x03\x12\x038$%\n8\n\x04\x04\x07\x02\x02\x12\
+    \x039\x04\"\"+\x20Ed25519.\x20The\x20node's\x20id\x20is\x20derived\x20fr\
+    om\x20it\n\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\x039\x04\x0c\n\x0c\n\x05\
+    \x04\x07\x02\x02\x05\x12\x039\r\x12\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\
+    \x039\x13\x1d\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x039\x20!\nG\n\x04\x04\
+    \x07\x02\x03\x12\x03:\x04%\":\x20Bitmap\x20of\x20the\x20optional\x20part\
+    s\x20of\x20the\x20protocol\x20it\x20supports\n\n\x0c\n\x05\x04\x07\x02\
+    \x03\x04\x12\x03:\x04\x0c\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x03:\r\x13\
+    \n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x03:\x14\x20\n\x0c\n\x05\x04\x07\
+    \x02\x03\x03\x12\x03:#$\n\x16\n\x02\x04\x08\x12\x04?\0B\x012\n\x20Messag\
+    es\n\n\n\n\x03\x04\x08\x01\x12\x03?\x08\x15\n\x1c\n\x04\x04\x08\x02\0\
+    \x12\x03@\x04\x1d\"\x0f\x20Querying\x20node\n\n\x0c\n\x05\x04\x08\x02\0\
+    \x04\x12\x03@\x04\x0c\n\x0c\n\x05\x04\x08\x02\0\x06\x12\x03@\r\x11\n\x0c\
+    \n\x05\x04\x08\x02\0\x01\x12\x03@\x12\x18\n\x0c\n\x05\x04\x08\x02\0\x03\
+    \x12\x03@\x1b\x1c\n\x0b\n\x04\x04\x08\x02\x01\x12\x03A\x04\x1e\n\x0c\n\
+    \x05\x04\x08\x02\x01\x04\x12\x03A\x04\x0c\n\x0c\n\x05\x04\x08\x02\x01\
+    \x05\x12\x03A\r\x12\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03A\x13\x19\n\
+    \x0c\n\x05\x04\x08\x02\x01\x03\x12\x03A\x1c\x1d\n\n\n\x02\x04\t\x12\x04D\
+    \0G\x01\n\n\n\x03\x04\t\x01\x12\x03D\x08\x18\n\x1e\n\x04\x04\t\x02\0\x12\
+    \x03E\x04\x1d\"\x11\x20Responding\x20node\n\n\x0c\n\x05\x04\t\x02\0\x04\
+    \x12\x03E\x04\x0c\n\x0c\n\x05\x04\t\x02\0\x06\x12\x03E\r\x11\n\x0c\n\x05\
+    \x04\t\x02\0\x01\x12\x03E\x12\x18\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03E\
+    \x1b\x1c\n\x0b\n\x04\x04\t\x02\x01\x12\x03F\x04\x1c\n\x0c\n\x05\x04\t\
+    \x02\x01\x04\x12\x03F\x04\x0c\n\x0c\n\x05\x04\t\x02\x01\x06\x12\x03F\r\
+    \x11\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03F\x12\x17\n\x0c\n\x05\x04\t\
+    \x02\x01\x03\x12\x03F\x1a\x1b\n\n\n\x02\x04\n\x12\x04I\0K\x01\n\n\n\x03\
+    \x04\n\x01\x12\x03I\x08\x11\n\x1c\n\x04\x04\n\x02\0\x12\x03J\x04\x1d\"\
+    \x0f\x20Querying\x20node\n\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03J\x04\x0c\
+    \n\x0c\n\x05\x04\n\x02\0\x06\x12\x03J\r\x11\n\x0c\n\x05\x04\n\x02\0\x01\
+    \x12\x03J\x12\x18\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03J\x1b\x1c\n\n\n\x02\
+    \x04\x0b\x12\x04M\0O\x01\n\n\n\x03\x04\x0b\x01\x12\x03M\x08\x14\n\x1e\n\
+    \x04\x04\x0b\x02\0\x12\x03N\x04\x1d\"\x11\x20Responding\x20node\n\n\x0c\
+    \n\x05\x04\x0b\x02\0\x04\x12\x03N\x04\x0c\n\x0c\n\x05\x04\x0b\x02\0\x06\
+    \x12\x03N\r\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03N\x12\x18\n\x0c\n\
+    \x05\x04\x0b\x02\0\x03\x12\x03N\x1b\x1c\n\x8b\x01\n\x02\x04\x0c\x12\x04S\
+    \0W\x01\x1a\x7f\x20Where\x20a\x20packet\x20query's\x20payload\x20belongs\
+    \x20in\x20a\x20payload\x20too\x20large\x20for\x20one\x20datagram.\x20Fra\
+    gments\x20of\x20the\n\x20same\x20payload\x20share\x20an\x20`id`.\n\n\n\n\
+    \x03\x04\x0c\x01\x12\x03S\x08\x10\n\x0b\n\x04\x04\x0c\x02\0\x12\x03T\x04\
+    \x1b\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03T\x04\x0c\n\x0c\n\x05\x04\x0c\
+    \x02\0\x05\x12\x03T\r\x13\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03T\x14\x16\
+    \n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03T\x19\x1a\n\x0b\n\x04\x04\x0c\x02\
+    \x01\x12\x03U\x04\x1e\n\x0c\n\x05\x04\x0c\x02\x01\x04\x12\x03U\x04\x0c\n\
+    \x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03U\r\x13\n\x0c\n\x05\x04\x0c\x02\
+    \x01\x01\x12\x03U\x14\x19\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03U\x1c\
+    \x1d\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03V\x04\x1e\n\x0c\n\x05\x04\x0c\
+    \x02\x02\x04\x12\x03V\x04\x0c\n\x0c\n\x05\x04\x0c\x02\x02\x05\x12\x03V\r\
+    \x13\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03V\x14\x19\n\x0c\n\x05\x04\
+    \x0c\x02\x02\x03\x12\x03V\x1c\x1d\n\n\n\x02\x04\r\x12\x04Y\0]\x01\n\n\n\
+    \x03\x04\r\x01\x12\x03Y\x08\x13\n\x0b\n\x04\x04\r\x02\0\x12\x03Z\x04\x1d\
+    \n\x0c\n\x05\x04\r\x02\0\x04\x12\x03Z\x04\x0c\n\x0c\n\x05\x04\r\x02\0\
+    \x06\x12\x03Z\r\x11\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03Z\x12\x18\n\x0c\n\
+    \x05\x04\r\x02\0\x03\x12\x03Z\x1b\x1c\n\x0b\n\x04\x04\r\x02\x01\x12\x03[\
+    \x04\x1f\n\x0c\n\x05\x04\r\x02\x01\x04\x12\x03[\x04\x0c\n\x0c\n\x05\x04\
+    \r\x02\x01\x05\x12\x03[\r\x12\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03[\x13\
+    \x1a\n\x0c\n\x05\x04\r\x02\x01\x03\x12\x03[\x1d\x1e\n5\n\x04\x04\r\x02\
+    \x02\x12\x03\\\x04#\"(\x20Absent\x20if\x20`payload`\x20is\x20the\x20whol\
+    e\x20of\x20it\n\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03\\\x04\x0c\n\x0c\n\
+    \x05\x04\r\x02\x02\x06\x12\x03\\\r\x15\n\x0c\n\x05\x04\r\x02\x02\x01\x12\
+    \x03\\\x16\x1e\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03\\!\"\n\n\n\x02\x04\
+    \x0e\x12\x04_\0a\x01\n\n\n\x03\x04\x0e\x01\x12\x03_\x08\x16\n\x0b\n\x04\
+    \x04\x0e\x02\0\x12\x03`\x04\x1d\n\x0c\n\x05\x04\x0e\x02\0\x04\x12\x03`\
+    \x04\x0c\n\x0c\n\x05\x04\x0e\x02\0\x06\x12\x03`\r\x11\n\x0c\n\x05\x04\
+    \x0e\x02\0\x01\x12\x03`\x12\x18\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\x03`\
+    \x1b\x1c\n\x81\x02\n\x02\x04\x0f\x12\x04f\0j\x01\x1a\xf4\x01\x20Asks\x20\
+    a\x20peer\x20known\x20to\x20both\x20sides\x20to\x20introduce\x20us\x20to\
+    \x20`target`.\x20The\x20peer\x20forwards\x20the\x20query\x20to\n\x20`tar\
+    get`,\x20filling\x20in\x20`peer`\x20with\x20the\x20querying\x20node,\x20\
+    and\x20answers\x20with\x20`target`\x20as\x20`peer`.\x20Both\n\x20sides\
+    \x20then\x20ping\x20each\x20other\x20to\x20open\x20their\x20NAT\x20mappi\
+    ngs.\n\n\n\n\x03\x04\x0f\x01\x12\x03f\x08\x17\n\x0b\n\x04\x04\x0f\x02\0\
+    \x12\x03g\x04\x1d\n\x0c\n\x05\x04\x0f\x02\0\x04\x12\x03g\x04\x0c\n\x0c\n\
+    \x05\x04\x0f\x02\0\x06\x12\x03g\r\x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\
+    \x03g\x12\x18\n\x0c\n\x05\x04\x0f\x02\0\x03\x12\x03g\x1b\x1c\n\x0b\n\x04\
+    \x04\x0f\x02\x01\x12\x03h\x04\x1e\n\x0c\n\x05\x04\x0f\x02\x01\x04\x12\
+    \x03h\x04\x0c\n\x0c\n\x05\x04\x0f\x02\x01\x05\x12\x03h\r\x12\n\x0c\n\x05\
+    \x04\x0f\x02\x01\x01\x12\x03h\x13\x19\n\x0c\n\x05\x04\x0f\x02\x01\x03\
+    \x12\x03h\x1c\x1d\n\x0b\n\x04\x04\x0f\x02\x02\x12\x03i\x04\x1b\n\x0c\n\
+    \x05\x04\x0f\x02\x02\x04\x12\x03i\x04\x0c\n\x0c\n\x05\x04\x0f\x02\x02\
+    \x06\x12\x03i\r\x11\n\x0c\n\x05\x04\x0f\x02\x02\x01\x12\x03i\x12\x16\n\
+    \x0c\n\x05\x04\x0f\x02\x02\x03\x12\x03i\x19\x1a\n\n\n\x02\x04\x10\x12\
+    \x04l\0o\x01\n\n\n\x03\x04\x10\x01\x12\x03l\x08\x1a\n\x0b\n\x04\x04\x10\
+    \x02\0\x12\x03m\x04\x1d\n\x0c\n\x05\x04\x10\x02\0\x04\x12\x03m\x04\x0c\n\
+    \x0c\n\x05\x04\x10\x02\0\x06\x12\x03m\r\x11\n\x0c\n\x05\x04\x10\x02\0\
+    \x01\x12\x03m\x12\x18\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03m\x1b\x1c\nB\
+    \n\x04\x04\x10\x02\x01\x12\x03n\x04\x1b\"5\x20Absent\x20if\x20the\x20res\
+    ponding\x20node\x20doesn't\x20know\x20`target`\n\n\x0c\n\x05\x04\x10\x02\
+    \x01\x04\x12\x03n\x04\x0c\n\x0c\n\x05\x04\x10\x02\x01\x06\x12\x03n\r\x11\
+    \n\x0c\n\x05\x04\x10\x02\x01\x01\x12\x03n\x12\x16\n\x0c\n\x05\x04\x10\
+    \x02\x01\x03\x12\x03n\x19\x1a\n\xc2\x01\n\x02\x04\x11\x12\x04s\0u\x01\
+    \x1a\xb5\x01\x20Asks\x20a\x20node\x20to\x20relay\x20messages\x20to\x20us\
+    .\x20Nodes\x20that\x20can't\x20be\x20reached\x20directly\x20send\x20it\
+    \x20to\x20a\x20node\n\x20that\x20volunteers\x20as\x20a\x20relay,\x20and\
+    \x20keep\x20sending\x20it\x20to\x20keep\x20the\x20mapping\x20to\x20the\
+    \x20relay\x20open.\n\n\n\n\x03\x04\x11\x01\x12\x03s\x08\x1a\n\x0b\n\x04\
+    \x04\x11\x02\0\x12\x03t\x04\x1d\n\x0c\n\x05\x04\x11\x02\0\x04\x12\x03t\
+    \x04\x0c\n\x0c\n\x05\x04\x11\x02\0\x06\x12\x03t\r\x11\n\x0c\n\x05\x04\
+    \x11\x02\0\x01\x12\x03t\x12\x18\n\x0c\n\x05\x04\x11\x02\0\x03\x12\x03t\
+    \x1b\x1c\n\n\n\x02\x04\x12\x12\x04w\0z\x01\n\n\n\x03\x04\x12\x01\x12\x03\
+    w\x08\x1d\n\x0b\n\x04\x04\x12\x02\0\x12\x03x\x04\x1d\n\x0c\n\x05\x04\x12\
+    \x02\0\x04\x12\x03x\x04\x0c\n\x0c\n\x05\x04\x12\x02\0\x06\x12\x03x\r\x11\
+    \n\x0c\n\x05\x04\x12\x02\0\x01\x12\x03x\x12\x18\n\x0c\n\x05\x04\x12\x02\
+    \0\x03\x12\x03x\x1b\x1c\nD\n\x04\x04\x12\x02\x01\x12\x03y\x04\x1f\"7\x20\
+    Whether\x20the\x20responding\x20node\x20will\x20relay\x20messages\x20to\
+    \x20us\n\n\x0c\n\x05\x04\x12\x02\x01\x04\x12\x03y\x04\x0c\n\x0c\n\x05\
+    \x04\x12\x02\x01\x05\x12\x03y\r\x11\n\x0c\n\x05\x04\x12\x02\x01\x01\x12\
+    \x03y\x12\x1a\n\x0c\n\x05\x04\x12\x02\x01\x03\x12\x03y\x1d\x1e\n\xa8\x01\
+    \n\x02\x04\x13\x12\x05~\0\x81\x01\x01\x1a\x9a\x01\x20A\x20message\x20for\
+    \x20`target`\x20to\x20be\x20forwarded\x20by\x20a\x20relay\x20it's\x20reg\
+    istered\x20with.\x20`payload`\x20is\x20an\n\x20`Envelope`\x20passed\x20o\
+    n\x20as\x20is,\x20so\x20the\x20relay\x20needn't\x20understand\x20it.\n\n\
+    \n\n\x03\x04\x13\x01\x12\x03~\x08\r\n\x0b\n\x04\x04\x13\x02\0\x12\x03\
+    \x7f\x04\x1e\n\x0c\n\x05\x04\x13\x02\0\x04\x12\x03\x7f\x04\x0c\n\x0c\n\
+    \x05\x04\x13\x02\0\x05\x12\x03\x7f\r\x12\n\x0c\n\x05\x04\x13\x02\0\x01\
+    \x12\x03\x7f\x13\x19\n\x0c\n\x05\x04\x13\x02\0\x03\x12\x03\x7f\x1c\x1d\n\
+    \x0c\n\x04\x04\x13\x02\x01\x12\x04\x80\x01\x04\x1f\n\r\n\x05\x04\x13\x02\
+    \x01\x04\x12\x04\x80\x01\x04\x0c\n\r\n\x05\x04\x13\x02\x01\x05\x12\x04\
+    \x80\x01\r\x12\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\x80\x01\x13\x1a\n\r\
+    \n\x05\x04\x13\x02\x01\x03\x12\x04\x80\x01\x1d\x1e\n\x17\n\x02\x04\x14\
+    \x12\x06\x85\x01\0\xaa\x01\x012\t\x20Wrapper\n\n\x0b\n\x03\x04\x14\x01\
+    \x12\x04\x85\x01\x08\x10\n\x0e\n\x04\x04\x14\x04\0\x12\x06\x86\x01\x04\
+    \x92\x01\x05\n\r\n\x05\x04\x14\x04\0\x01\x12\x04\x86\x01\t\r\n\x0e\n\x06\
+    \x04\x14\x04\0\x02\0\x12\x04\x87\x01\x08\x1c\n\x0f\n\x07\x04\x14\x04\0\
+    \x02\0\x01\x12\x04\x87\x01\x08\x17\n\x0f\n\x07\x04\x14\x04\0\x02\0\x02\
+    \x12\x04\x87\x01\x1a\x1b\n\x0e\n\x06\x04\x14\x04\0\x02\x01\x12\x04\x88\
+    \x01\x08\x1f\n\x0f\n\x07\x04\x14\x04\0\x02\x01\x01\x12\x04\x88\x01\x08\
+    \x1a\n\x0f\n\x07\x04\x14\x04\0\x02\x01\x02\x12\x04\x88\x01\x1d\x1e\n\x0e\
+    \n\x06\x04\x14\x04\0\x02\x02\x12\x04\x89\x01\x08\x17\n\x0f\n\x07\x04\x14\
+    \x04\0\x02\x02\x01\x12\x04\x89\x01\x08\x12\n\x0f\n\x07\x04\x14\x04\0\x02\
+    \x02\x02\x12\x04\x89\x01\x15\x16\n\x0e\n\x06\x04\x14\x04\0\x02\x03\x12\
+    \x04\x8a\x01\x08\x1a\n\x0f\n\x07\x04\x14\x04\0\x02\x03\x01\x12\x04\x8a\
+    \x01\x08\x15\n\x0f\n\x07\x04\x14\x04\0\x02\x03\x02\x12\x04\x8a\x01\x18\
+    \x19\n\x0e\n\x06\x04\x14\x04\0\x02\x04\x12\x04\x8b\x01\x08\x19\n\x0f\n\
+    \x07\x04\x14\x04\0\x02\x04\x01\x12\x04\x8b\x01\x08\x14\n\x0f\n\x07\x04\
+    \x14\x04\0\x02\x04\x02\x12\x04\x8b\x01\x17\x18\n\x0e\n\x06\x04\x14\x04\0\
+    \x02\x05\x12\x04\x8c\x01\x08\x1c\n\x0f\n\x07\x04\x14\x04\0\x02\x05\x01\
+    \x12\x04\x8c\x01\x08\x17\n\x0f\n\x07\x04\x14\x04\0\x02\x05\x02\x12\x04\
+    \x8c\x01\x1a\x1b\n\x0e\n\x06\x04\x14\x04\0\x02\x06\x12\x04\x8d\x01\x08\
+    \x1d\n\x0f\n\x07\x04\x14\x04\0\x02\x06\x01\x12\x04\x8d\x01\x08\x18\n\x0f\
+    \n\x07\x04\x14\x04\0\x02\x06\x02\x12\x04\x8d\x01\x1b\x1c\n\x0e\n\x06\x04\
+    \x14\x04\0\x02\x07\x12\x04\x8e\x01\x08\x20\n\x0f\n\x07\x04\x14\x04\0\x02\
+    \x07\x01\x12\x04\x8e\x01\x08\x1b\n\x0f\n\x07\x04\x14\x04\0\x02\x07\x02\
+    \x12\x04\x8e\x01\x1e\x1f\n\x0e\n\x06\x04\x14\x04\0\x02\x08\x12\x04\x8f\
+    \x01\x08!\n\x0f\n\x07\x04\x14\x04\0\x02\x08\x01\x12\x04\x8f\x01\x08\x1c\
+    \n\x0f\n\x07\x04\x14\x04\0\x02\x08\x02\x12\x04\x8f\x01\x1f\x20\n\x0e\n\
+    \x06\x04\x14\x04\0\x02\t\x12\x04\x90\x01\x08%\n\x0f\n\x07\x04\x14\x04\0\
+    \x02\t\x01\x12\x04\x90\x01\x08\x1f\n\x0f\n\x07\x04\x14\x04\0\x02\t\x02\
+    \x12\x04\x90\x01\"$\n.\n\x06\x04\x14\x04\0\x02\n\x12\x04\x91\x01\x08\x13\
+    \"\x1e\x20Its\x20transaction\x20ID\x20is\x20unused\n\n\x0f\n\x07\x04\x14\
+    \x04\0\x02\n\x01\x12\x04\x91\x01\x08\r\n\x0f\n\x07\x04\x14\x04\0\x02\n\
+    \x02\x12\x04\x91\x01\x10\x12\nk\n\x04\x04\x14\x02\0\x12\x04\x94\x01\x04#\
+    \x1a]\x20Optional\x20so\x20that\x20envelopes\x20of\x20types\x20added\x20\
+    in\x20later\x20versions\x20still\x20parse,\x20and\x20can\x20be\x20skippe\
+    d\n\n\r\n\x05\x04\x14\x02\0\x04\x12\x04\x94\x01\x04\x0c\n\r\n\x05\x04\
+    \x14\x02\0\x06\x12\x04\x94\x01\r\x11\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\
+    \x94\x01\x12\x1e\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\x94\x01!\"\n\x0c\n\
+    \x04\x04\x14\x02\x01\x12\x04\x95\x01\x04'\n\r\n\x05\x04\x14\x02\x01\x04\
+    \x12\x04\x95\x01\x04\x0c\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\x95\x01\r\
+    \x13\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\x95\x01\x14\"\n\r\n\x05\x04\
+    \x14\x02\x01\x03\x12\x04\x95\x01%&\n+\n\x04\x04\x14\x02\x02\x12\x04\x98\
+    \x01\x04/\x1a\x1d\x20Replace\x20these\x20with\x20Any\x20type\n\n\r\n\x05\
+    \x04\x14\x02\x02\x04\x12\x04\x98\x01\x04\x0c\n\r\n\x05\x04\x14\x02\x02\
+    \x06\x12\x04\x98\x01\r\x1a\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\x98\x01\
+    \x1b*\n\r\n\x05\x04\x14\x02\x02\x03\x12\x04\x98\x01-.\n\x0c\n\x04\x04\
+    \x14\x02\x03\x12\x04\x99\x01\x045\n\r\n\x05\x04\x14\x02\x03\x04\x12\x04\
+    \x99\x01\x04\x0c\n\r\n\x05\x04\x14\x02\x03\x06\x12\x04\x99\x01\r\x1d\n\r\
+    \n\x05\x04\x14\x02\x03\x01\x12\x04\x99\x01\x1e0\n\r\n\x05\x04\x14\x02\
+    \x03\x03\x12\x04\x99\x0134\n\x0c\n\x04\x04\x14\x02\x04\x12\x04\x9a\x01\
+    \x04&\n\r\n\x05\x04\x14\x02\x04\x04\x12\x04\x9a\x01\x04\x0c\n\r\n\x05\
+    \x04\x14\x02\x04\x06\x12\x04\x9a\x01\r\x16\n\r\n\x05\x04\x14\x02\x04\x01\
+    \x12\x04\x9a\x01\x17!\n\r\n\x05\x04\x14\x02\x04\x03\x12\x04\x9a\x01$%\n\
+    \x0c\n\x04\x04\x14\x02\x05\x12\x04\x9b\x01\x04,\n\r\n\x05\x04\x14\x02\
+    \x05\x04\x12\x04\x9b\x01\x04\x0c\n\r\n\x05\x04\x14\x02\x05\x06\x12\x04\
+    \x9b\x01\r\x19\n\r\n\x05\x04\x14\x02\x05\x01\x12\x04\x9b\x01\x1a'\n\r\n\
+    \x05\x04\x14\x02\x05\x03\x12\x04\x9b\x01*+\n\x0c\n\x04\x04\x14\x02\x06\
+    \x12\x04\x9c\x01\x04*\n\r\n\x05\x04\x14\x02\x06\x04\x12\x04\x9c\x01\x04\
+    \x0c\n\r\n\x05\x04\x14\x02\x06\x06\x12\x04\x9c\x01\r\x18\n\r\n\x05\x04\
+    \x14\x02\x06\x01\x12\x04\x9c\x01\x19%\n\r\n\x05\x04\x14\x02\x06\x03\x12\
+    \x04\x9c\x01()\n\x0c\n\x04\x04\x14\x02\x07\x12\x04\x9d\x01\x040\n\r\n\
+    \x05\x04\x14\x02\x07\x04\x12\x04\x9d\x01\x04\x0c\n\r\n\x05\x04\x14\x02\
+    \x07\x06\x12\x04\x9d\x01\r\x1b\n\r\n\x05\x04\x14\x02\x07\x01\x12\x04\x9d\
+    \x01\x1c+\n\r\n\x05\x04\x14\x02\x07\x03\x12\x04\x9d\x01./\n\x0c\n\x04\
+    \x04\x14\x02\x08\x12\x04\x9e\x01\x042\n\r\n\x05\x04\x14\x02\x08\x04\x12\
+    \x04\x9e\x01\x04\x0c\n\r\n\x05\x04\x14\x02\x08\x06\x12\x04\x9e\x01\r\x1c\
+    \n\r\n\x05\x04\x14\x02\x08\x01\x12\x04\x9e\x01\x1d-\n\r\n\x05\x04\x14\
+    \x02\x08\x03\x12\x04\x9e\x0101\n\x0c\n\x04\x04\x14\x02\t\x12\x04\x9f\x01\
+    \x049\n\r\n\x05\x04\x14\x02\t\x04\x12\x04\x9f\x01\x04\x0c\n\r\n\x05\x04\
+    \x14\x02\t\x06\x12\x04\x9f\x01\r\x1f\n\r\n\x05\x04\x14\x02\t\x01\x12\x04\
+    \x9f\x01\x203\n\r\n\x05\x04\x14\x02\t\x03\x12\x04\x9f\x0168\n\x0c\n\x04\
+    \x04\x14\x02\n\x12\x04\xa0\x01\x04:\n\r\n\x05\x04\x14\x02\n\x04\x12\x04\
+    \xa0\x01\x04\x0c\n\r\n\x05\x04\x14\x02\n\x06\x12\x04\xa0\x01\r\x1f\n\r\n\
+    \x05\x04\x14\x02\n\x01\x12\x04\xa0\x01\x204\n\r\n\x05\x04\x14\x02\n\x03\
+    \x12\x04\xa0\x0179\n\x0c\n\x04\x04\x14\x02\x0b\x12\x04\xa1\x01\x04@\n\r\
+    \n\x05\x04\x14\x02\x0b\x04\x12\x04\xa1\x01\x04\x0c\n\r\n\x05\x04\x14\x02\
+    \x0b\x06\x12\x04\xa1\x01\r\"\n\r\n\x05\x04\x14\x02\x0b\x01\x12\x04\xa1\
+    \x01#:\n\r\n\x05\x04\x14\x02\x0b\x03\x12\x04\xa1\x01=?\n\x0c\n\x04\x04\
+    \x14\x02\x0c\x12\x04\xa2\x01\x04\x1e\n\r\n\x05\x04\x14\x02\x0c\x04\x12\
+    \x04\xa2\x01\x04\x0c\n\r\n\x05\x04\x14\x02\x0c\x06\x12\x04\xa2\x01\r\x12\
+    \n\r\n\x05\x04\x14\x02\x0c\x01\x12\x04\xa2\x01\x13\x18\n\r\n\x05\x04\x14\
+    \x02\x0c\x03\x12\x04\xa2\x01\x1b\x1d\n\xc5\x01\n\x04\x04\x14\x02\r\x12\
+    \x04\xa6\x01\x04\"\x1a\xb6\x01\x20The\x20origin's\x20Ed25519\x20signatur\
+    e\x20over\x20the\x20envelope\x20as\x20serialized\x20without\x20it,\x20wh\
+    ich\x20is\x20checked\n\x20against\x20the\x20bytes\x20received\x20with\
+    \x20this\x20field\x20cut\x20out.\x20Every\x20query\x20and\x20response\
+    \x20is\x20signed.\n\n\r\n\x05\x04\x14\x02\r\x04\x12\x04\xa6\x01\x04\x0c\
+    \n\r\n\x05\x04\x14\x02\r\x05\x12\x04\xa6\x01\r\x12\n\r\n\x05\x04\x14\x02\
+    \r\x01\x12\x04\xa6\x01\x13\x1c\n\r\n\x05\x04\x14\x02\r\x03\x12\x04\xa6\
+    \x01\x1f!\nV\n\x04\x04\x14\x02\x0e\x12\x04\xa9\x01\x04*\x1aH\x20The\x20v\
+    ersion\x20of\x20the\x20protocol\x20the\x20envelope\x20follows.\x20Absent\
+    \x20in\x20version\x201.\n\n\r\n\x05\x04\x14\x02\x0e\x04\x12\x04\xa9\x01\
+    \x04\x0c\n\r\n\x05\x04\x14\x02\x0e\x05\x12\x04\xa9\x01\r\x13\n\r\n\x05\
+    \x04\x14\x02\x0e\x01\x12\x04\xa9\x01\x14$\n\r\n\x05\x04\x14\x02\x0e\x03\
+    \x12\x04\xa9\x01')\n\x17\n\x02\x04\x15\x12\x06\xae\x01\0\xb2\x01\x012\t\
+    \x20Storage\n\n\x0b\n\x03\x04\x15\x01\x12\x04\xae\x01\x08\x11\n\x0c\n\
+    \x04\x04\x15\x02\0\x12\x04\xaf\x01\x04\x1b\n\r\n\x05\x04\x15\x02\0\x04\
+    \x12\x04\xaf\x01\x04\x0c\n\r\n\x05\x04\x15\x02\0\x06\x12\x04\xaf\x01\r\
+    \x11\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\xaf\x01\x12\x16\n\r\n\x05\x04\
+    \x15\x02\0\x03\x12\x04\xaf\x01\x19\x1a\n,\n\x04\x04\x15\x02\x01\x12\x04\
+    \xb0\x01\x04!\"\x1e\x20Seconds\x20since\x20the\x20Unix\x20epoch\n\n\r\n\
+    \x05\x04\x15\x02\x01\x04\x12\x04\xb0\x01\x04\x0c\n\r\n\x05\x04\x15\x02\
+    \x01\x05\x12\x04\xb0\x01\r\x12\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\xb0\
+    \x01\x13\x1c\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\xb0\x01\x1f\x20\n\x0c\
+    \n\x04\x04\x15\x02\x02\x12\x04\xb1\x01\x04$\n\r\n\x05\x04\x15\x02\x02\
+    \x04\x12\x04\xb1\x01\x04\x0c\n\r\n\x05\x04\x15\x02\x02\x05\x12\x04\xb1\
+    \x01\r\x11\n\r\n\x05\x04\x15\x02\x02\x01\x12\x04\xb1\x01\x12\x1f\n\r\n\
+    \x05\x04\x15\x02\x02\x03\x12\x04\xb1\x01\"#\n\x0c\n\x02\x04\x16\x12\x06\
+    \xb4\x01\0\xb8\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\xb4\x01\x08\x13\n\
+    8\n\x04\x04\x16\x02\0\x12\x04\xb5\x01\x04\x1e\"*\x20The\x20address\x20th\
+    e\x20bucket's\x20space\x20starts\x20at\n\n\r\n\x05\x04\x16\x02\0\x04\x12\
+    \x04\xb5\x01\x04\x0c\n\r\n\x05\x04\x16\x02\0\x05\x12\x04\xb5\x01\r\x12\n\
+    \r\n\x05\x04\x16\x02\0\x01\x12\x04\xb5\x01\x13\x19\n\r\n\x05\x04\x16\x02\
+    \0\x03\x12\x04\xb5\x01\x1c\x1d\nB\n\x04\x04\x16\x02\x01\x12\x04\xb6\x01\
+    \x04\x1e\"4\x20How\x20many\x20leading\x20bits\x20the\x20bucket's\x20addr\
+    esses\x20share\n\n\r\n\x05\x04\x16\x02\x01\x04\x12\x04\xb6\x01\x04\x0c\n\
+    \r\n\x05\x04\x16\x02\x01\x05\x12\x04\xb6\x01\r\x13\n\r\n\x05\x04\x16\x02\
+    \x01\x01\x12\x04\xb6\x01\x14\x19\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\
+    \xb6\x01\x1c\x1d\n\x0c\n\x04\x04\x16\x02\x02\x12\x04\xb7\x01\x04!\n\r\n\
+    \x05\x04\x16\x02\x02\x04\x12\x04\xb7\x01\x04\x0c\n\r\n\x05\x04\x16\x02\
+    \x02\x06\x12\x04\xb7\x01\r\x16\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\xb7\
+    \x01\x17\x1c\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\xb7\x01\x1f\x20\n\x0c\
+    \n\x02\x04\x17\x12\x06\xba\x01\0\xbd\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\
+    \x04\xba\x01\x08\x19\n\x0c\n\x04\x04\x17\x02\0\x12\x04\xbb\x01\x04\x1a\n\
+    \r\n\x05\x04\x17\x02\0\x04\x12\x04\xbb\x01\x04\x0c\n\r\n\x05\x04\x17\x02\
+    \0\x05\x12\x04\xbb\x01\r\x13\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xbb\x01\
+    \x14\x15\n\r\n\x05\x04\x17\x02\0\x03\x12\x04\xbb\x01\x18\x19\n\x0c\n\x04\
+    \x04\x17\x02\x01\x12\x04\xbc\x01\x04%\n\r\n\x05\x04\x17\x02\x01\x04\x12\
+    \x04\xbc\x01\x04\x0c\n\r\n\x05\x04\x17\x02\x01\x06\x12\x04\xbc\x01\r\x18\
+    \n\r\n\x05\x04\x17\x02\x01\x01\x12\x04\xbc\x01\x19\x20\n\r\n\x05\x04\x17\
+    \x02\x01\x03\x12\x04\xbc\x01#$\
+";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
     lock: ::protobuf::lazy::ONCE_INIT,
@@ -2,16 +2,15 @@ extern crate comm;
 extern crate env_logger;
 #[macro_use]
 extern crate log;
-extern crate num;
 
-use num::bigint::ToBigUint;
 use std::collections::HashSet;
 use std::env;
 use std::net::ToSocketAddrs;
 use std::thread;
 
-use comm::address::{Address, LENGTH};
+use comm::address::Address;
 use comm::client;
+use comm::identity::Identity;
 use comm::network;
 use comm::node;
 use comm::servers;
@@ -26,9 +25,6 @@ use comm::servers;
 ///
 ///     multi 0.0.0.0 8000 8100 500 73.207.94.182:6667
 pub fn start_multiple(host: &str, port_start: u16, port_end: u16, router_host: Option<&str>, rampup: u64) {
-    let min = 0.to_biguint().unwrap();
-    let max = num::pow(2.to_biguint().unwrap(), LENGTH);
-
     info!("Starting nodes {}:{}..{}", host, port_start, port_end);
 
     for port in port_start..port_end {
@@ -45,11 +41,12 @@ pub fn start_multiple(host: &str, port_start: u16, port_end: u16, router_host: O
             None => vec![]
         };
 
-        let address = Address::random(&min, &max);
+        let identity = Identity::generate();
+        let address = identity.address();
         let socket_address = (host, port);
         let socket_address = socket_address.to_socket_addrs().unwrap().next().unwrap();
         let servers = vec![servers::Server::Udp(servers::UdpServer::new(socket_address))];
-        let network = network::Network::new(address, servers, routers);
+        let network = network::Network::new(identity, servers, routers, None);
         let client = client::Client::new(address);
         client.run(network);
         thread::sleep(std::time::Duration::from_millis(rampup));
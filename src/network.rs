@@ -1,78 +1,217 @@
 use address::{Addressable, Address};
+use fragment::{self, Fragment, Reassembler, Reassembly, FRAGMENT_SIZE};
+use identity::Identity;
+use lookup::Lookup;
 use messages::outgoing;
 use mio;
-use node::Node;
-use routing_table::{InsertOutcome, InsertionResult, RoutingTable};
-use servers::Server;
+use node::{Capabilities, Node, RelayedTransport, Transport};
+use operation::{Operation, OperationId, Operations};
+use routing_table::{DiversityLimits, InsertionResult, RoutingTable, SavedRoutingTable};
+use servers::{Connections, Server};
+use snapshot::RoutingTableSnapshot;
+use std::cmp;
 use std::collections::{HashMap, HashSet};
 use std::io::Cursor;
+use std::path::PathBuf;
 use std::sync::mpsc;
 use std::thread;
+use stun::NatType;
+use time;
 use transaction::{TransactionId, TransactionIdGenerator};
 
+/// How many rounds of pings to send while punching a hole before giving up.
+pub const HOLE_PUNCH_ATTEMPTS: usize = 5;
+
+/// How long to wait between rounds of hole punching pings.
+pub const HOLE_PUNCH_INTERVAL_MS: u64 = 200;
+
+/// The most transports of a node we punch holes to at once.
+pub const HOLE_PUNCH_MAX_TRANSPORTS: usize = 4;
+
+/// How long a node that had us punch a hole has to wait before it can have us punch another.
+/// Otherwise anyone could keep us pinging the nodes we know.
+pub const HOLE_PUNCH_COOLDOWN_SECONDS: i64 = 10;
+
+/// How many queries a lookup keeps in flight at once.
+pub const LOOKUP_ALPHA: usize = 3;
+
+/// How long a lookup waits on a query before giving up on the node.
+pub const LOOKUP_QUERY_TIMEOUT_MS: u64 = 1000;
+
+/// How long to wait between one health check finishing and the next starting.
+pub const HEALTH_CHECK_INTERVAL_MS: u64 = 1000;
+
+/// How long a health check waits on each ping before it's done without an answer.
+pub const HEALTH_CHECK_PING_TIMEOUT_MS: u64 = 1000;
+
+/// How long to wait between one bucket refresh finishing and looking for another bucket to
+/// refresh.
+pub const REFRESH_INTERVAL_MS: u64 = 1000;
+
+/// How long to wait before bootstrapping again when no node answered. The wait doubles with
+/// every round of trying each router.
+pub const BOOTSTRAP_RETRY_MS: u64 = 1000;
+
+/// How many times each router is tried before bootstrapping is given up on.
+pub const BOOTSTRAP_ATTEMPTS_PER_ROUTER: usize = 3;
+
+/// How often a node behind a symmetric NAT registers with its relay again, which also keeps its
+/// NAT's mapping to the relay open.
+pub const RELAY_KEEPALIVE_MS: u64 = 15000;
+
+/// How long a relay keeps forwarding messages to a node after it last registered.
+pub const RELAY_REGISTRATION_SECONDS: i64 = 60;
+
+/// How many queries our relay may leave unanswered before we look for another.
+pub const RELAY_UNANSWERED_THRESHOLD: usize = 3;
+
+/// How often the routing table is saved, when it's being saved to a file.
+pub const ROUTING_TABLE_SAVE_INTERVAL_MS: u64 = 60000;
+
+/// How long after the first of a payload's fragments arrives we give up on the rest.
+pub const REASSEMBLY_TIMEOUT_MS: u64 = 10000;
+
+/// How often payloads still missing fragments are checked for having timed out, while there are
+/// any.
+pub const REASSEMBLY_EXPIRY_INTERVAL_MS: u64 = 1000;
+
 #[derive(Clone, Debug)]
 pub enum Event {
+    /// Bootstrapping found nodes near us, and we've started keeping the routing table healthy.
+    Bootstrapped,
+    /// No node answered however many times we tried to bootstrap. We try again as soon as any
+    /// node contacts us, or on `OneshotTask::StartBootstrap`.
+    BootstrapFailed,
+    HolePunched(Address),
+    /// A lookup for an address finished, having found these nodes nearest to it.
+    LookupFinished(Address, Vec<Address>),
     ReceivedPacket(Address, Vec<u8>),
+    RegisteredWithRelay(Address),
     Shutdown,
     Started
 }
 
 pub enum OneshotTask {
-    Incoming(Vec<u8>),
+    /// Data received by a server, and the transport it came from if the server can tell.
+    Incoming(Vec<u8>, Option<Transport>),
+    FindNode(Address),
+    PunchHole(Address),
     StartBootstrap,
     SendPacket(Address, Vec<u8>),
+    /// Replies with a snapshot of the routing table.
+    SnapshotRoutingTable(mpsc::Sender<RoutingTableSnapshot>),
     Shutdown
 }
 
 pub enum ScheduledTask {
     ContinueBootstrap,
     ContinueHealthCheck,
-    ContinueRefresh
+    ContinueHolePunch(Address, Vec<Transport>, usize),
+    ContinueRefresh,
+    /// Gives up on an operation's transaction if it still hasn't been answered.
+    ExpireTransaction(TransactionId),
+    /// Gives up on the payloads whose fragments haven't all arrived in time.
+    ExpireReassemblies,
+    ContinueRelayKeepAlive,
+    SaveRoutingTable
 }
 
+/// What's done with the nodes a lookup finds.
 enum TableAction {
-    Bootstrap(mio::Timeout),
-    HealthCheck(mio::Timeout),
-    RefreshBucket(mio::Timeout)
+    Bootstrap,
+    /// Delivers a packet to the nearest node each path of the lookup found.
+    DeliverPacket(Vec<u8>),
+    RefreshBucket
+}
+
+/// What an operation is for.
+enum OperationState {
+    Lookup(Lookup, Option<TableAction>),
+    /// Pings the nearest node and every questionable one, and is over once they've all answered
+    /// or timed out.
+    HealthCheck
 }
 
 pub type TaskSender = mio::Sender<OneshotTask>;
 
 enum Status {
-    Bootstrapping,
-    Idle
+    /// Bootstrapping hasn't started yet.
+    Idle,
+    /// Looking up our own address, on the attempt numbered from 0.
+    Bootstrapping(usize),
+    Bootstrapped,
+    BootstrapFailed
 }
 
 pub struct Network {
     servers: HashMap<mio::Token, Server>,
+    connections: Connections,
     routing_table: RoutingTable,
     self_node: Node,
     transaction_ids: TransactionIdGenerator,
     status: Status,
-    pending_actions: HashMap<TransactionId, TableAction>,
+    operations: Operations<OperationState>,
+    disjoint_paths: usize,
+    fragments: Reassembler,
+    is_expiring_reassemblies: bool,
+    hole_punches: HashMap<TransactionId, (Address, Transport)>,
+    hole_punch_requesters: HashMap<Address, time::Tm>,
+    is_relay: bool,
+    relay: Option<RelayedTransport>,
+    relayed_nodes: HashMap<Address, (Vec<Transport>, time::Tm)>,
+    routing_table_file: Option<PathBuf>,
+    is_maintaining_table: bool,
+    dropped_messages: usize,
     event_listeners: Vec<mpsc::Sender<Event>>
 }
 
 impl Network {
-    pub fn new(self_address: Address, servers: Vec<Server>, routers: Vec<Node>) -> Network {
+    /// Creates a network for our node with `identity`, listening on `servers`. Its routing table
+    /// is restored from `saved` if there is one, and otherwise starts out knowing only `routers`.
+    pub fn new(identity: Identity, servers: Vec<Server>, routers: Vec<Node>,
+               saved: Option<SavedRoutingTable>) -> Network {
         let mut transports = HashSet::new();
+        let mut nat_type = None;
         let mut server_hash = HashMap::new();
-        for (i, server) in servers.into_iter().enumerate() {
+        for (i, mut server) in servers.into_iter().enumerate() {
             let token = mio::Token(i);
+            server.bind();
             transports.insert(server.transport());
+            nat_type = nat_type.or_else(|| server.nat_type());
             server_hash.insert(token, server);
         }
 
-        let self_node = Node::new(self_address, transports);
-        let routing_table = RoutingTable::new(8, self_address, routers);
+        let self_address = identity.address();
+        let mut self_node = Node::for_identity(identity, transports);
+        let mut capabilities = Capabilities::RENDEZVOUS;
+        capabilities.insert(Capabilities::FRAGMENTS);
+        self_node.set_capabilities(capabilities);
+        self_node.set_nat_type(nat_type.unwrap_or(NatType::Unknown));
+        info!("Behind NAT type {:?}", self_node.nat_type());
+        let routing_table = match saved {
+            Some(saved) => RoutingTable::restore(saved, self_address, routers),
+            None => RoutingTable::new(8, self_address, routers)
+        };
 
         Network {
             servers: server_hash,
+            connections: Connections::new(),
             routing_table: routing_table,
             self_node: self_node,
             transaction_ids: TransactionIdGenerator::new(),
             status: Status::Idle,
-            pending_actions: HashMap::new(),
+            operations: Operations::new(),
+            disjoint_paths: 1,
+            fragments: Reassembler::new(fragment::DEFAULT_MAX_PAYLOAD_SIZE),
+            is_expiring_reassemblies: false,
+            hole_punches: HashMap::new(),
+            hole_punch_requesters: HashMap::new(),
+            is_relay: false,
+            relay: None,
+            relayed_nodes: HashMap::new(),
+            routing_table_file: None,
+            is_maintaining_table: false,
+            dropped_messages: 0,
             event_listeners: vec![]
         }
     }
@@ -83,13 +222,21 @@ impl Network {
         let mut event_loop = mio::EventLoop::configured(event_loop_config).unwrap();
 
         for (token, server) in &mut self.servers.iter_mut() {
-            let evented = server.run();
-            event_loop
-                .register(evented, *token, mio::EventSet::readable(), mio::PollOpt::edge())
-                .expect("Couldn't register server to EventLoop");
+            {
+                let evented = server.run();
+                event_loop
+                    .register(evented, *token, mio::EventSet::readable(), mio::PollOpt::edge())
+                    .expect("Couldn't register server to EventLoop");
+            }
+            if let Some(socket) = server.udp_socket() {
+                self.connections.add_udp_socket(socket);
+            }
         }
 
         event_loop.channel().send(OneshotTask::StartBootstrap).unwrap();
+        if self.routing_table_file.is_some() {
+            event_loop.timeout_ms(ScheduledTask::SaveRoutingTable, ROUTING_TABLE_SAVE_INTERVAL_MS).unwrap();
+        }
         info!("Running server at {:?}", self.self_node);
         let mut handler = Handler::new(self);
         let task_sender = event_loop.channel();
@@ -101,14 +248,55 @@ impl Network {
         self.event_listeners.push(event_listener);
     }
 
+    /// Offers to relay messages to nodes that can't be reached directly. Only worth doing on a
+    /// node that's publicly reachable itself.
+    pub fn volunteer_as_relay(&mut self) {
+        self.is_relay = true;
+        let mut capabilities = self.self_node.capabilities();
+        capabilities.insert(Capabilities::RELAY);
+        self.self_node.set_capabilities(capabilities);
+    }
+
+    /// Runs lookups over `paths` node-disjoint paths, so that nodes in one part of the address
+    /// space can't capture them by answering with only each other. Packets are then delivered by
+    /// looking up their recipients and sending them along every path.
+    pub fn use_disjoint_paths(&mut self, paths: usize) {
+        self.disjoint_paths = cmp::max(paths, 1);
+    }
+
+    /// The largest packet payload we send, or put back together from the fragments it arrives in.
+    /// Payloads larger than `FRAGMENT_SIZE` are split into fragments, each sent in its own query.
+    pub fn set_max_packet_size(&mut self, size: usize) {
+        self.fragments.set_max_payload_size(size);
+    }
+
+    /// Limits how many nodes sharing an IP address or subnet the routing table takes in. See
+    /// `DiversityLimits`.
+    pub fn set_diversity_limits(&mut self, limits: DiversityLimits) {
+        self.routing_table.set_diversity_limits(limits);
+    }
+
+    /// Saves the routing table to `path` every so often and on shutdown, to be restored from with
+    /// `SavedRoutingTable::read_from_file` the next time we start.
+    pub fn save_routing_table_to<P: Into<PathBuf>>(&mut self, path: P) {
+        self.routing_table_file = Some(path.into());
+    }
+
     fn read_server(&self, token: mio::Token, event_loop: &mut mio::EventLoop<Handler>) {
         self.servers[&token].read(event_loop.channel());
     }
 
-    fn handle_incoming(&mut self, data: Vec<u8>, event_loop: &mut mio::EventLoop<Handler>) {
+    fn handle_incoming(&mut self, data: Vec<u8>, source: Option<Transport>, event_loop: &mut mio::EventLoop<Handler>) {
         use messages::incoming::{Message, Query, Response, self};
         let mut data = Cursor::new(data);
-        let message = incoming::parse_from_reader(&mut data).unwrap();
+        let message = match incoming::parse_from_reader(&mut data) {
+            Ok(message) => message,
+            Err(error) => {
+                self.dropped_messages += 1;
+                debug!("Dropping incoming message ({} dropped so far): {}", self.dropped_messages, error);
+                return
+            }
+        };
 
         match message {
             Message::Query(transaction_id, origin, query) => {
@@ -121,21 +309,71 @@ impl Network {
                                 transaction_id,
                                 &self.self_node,
                                 self.routing_table.nearest_live_nodes_to(&target, false));
-                        origin.send(response);
+                        origin.send(response, &mut self.connections);
                     },
-                    Query::Packet(payload) => {
+                    Query::Packet(payload, fragment) => {
                         // Logging packets would be too chatty
-                        self.broadcast_event(Event::ReceivedPacket(origin_address, payload));
+                        let payload = match fragment {
+                            Some(fragment) => self.reassemble(origin_address, fragment, payload, event_loop),
+                            None => Some(payload)
+                        };
+                        if let Some(payload) = payload {
+                            self.broadcast_event(Event::ReceivedPacket(origin_address, payload));
+                        }
                         let response = outgoing::create_packet_response(
                             transaction_id, &self.self_node);
-                        origin.send(response);
+                        origin.send(response, &mut self.connections);
                     },
                     Query::Ping => {
                         debug!("Received Ping from {:?}", &origin);
                         let response = outgoing::create_ping_response(
                             transaction_id,
                             &self.self_node);
-                        origin.send(response);
+                        origin.send(response, &mut self.connections);
+                    }
+                    Query::Rendezvous(target, peer) => {
+                        debug!("Received Rendezvous query for {} from {:?}", &target, &origin);
+                        let response = if target == self.self_node.address() {
+                            // Either a mutual peer is introducing us to `peer`, or `origin` asked
+                            // us directly
+                            let address = match peer {
+                                Some(ref peer) => peer.address(),
+                                None => origin_address
+                            };
+                            self.punch_hole(origin_address, address, event_loop);
+                            outgoing::create_rendezvous_response(
+                                transaction_id, &self.self_node, Some(&self.self_node))
+                        } else if let Some(target_node) = self.routing_table
+                            .find_node(&target)
+                            .filter(|node| node.capabilities().contains(Capabilities::RENDEZVOUS)) {
+                            let forward_transaction_id = self.transaction_ids.generate();
+                            let forward = outgoing::create_rendezvous_query(
+                                forward_transaction_id, &self.self_node, &target, Some(&origin));
+                            target_node.send_query(forward_transaction_id, forward, &mut self.connections);
+                            outgoing::create_rendezvous_response(
+                                transaction_id, &self.self_node, Some(target_node))
+                        } else {
+                            outgoing::create_rendezvous_response(
+                                transaction_id, &self.self_node, None)
+                        };
+                        origin.send(response, &mut self.connections);
+                    }
+                    Query::RegisterRelay => {
+                        debug!("Received RegisterRelay query from {:?}", &origin);
+                        // A node asking for a relay can't be reached at the transports it
+                        // advertises, so answer the way the query came in if we can tell
+                        let transports: Vec<Transport> = match source {
+                            Some(source) => vec![source],
+                            None => origin.transports().filter(|t| !t.is_relayed()).cloned().collect()
+                        };
+                        let response = outgoing::create_register_relay_response(
+                            transaction_id, &self.self_node, self.is_relay);
+                        for transport in &transports {
+                            transport.send(response.clone(), &mut self.connections);
+                        }
+                        if self.is_relay {
+                            self.relayed_nodes.insert(origin_address, (transports, time::now_utc()));
+                        }
                     }
                 }
 
@@ -144,57 +382,46 @@ impl Network {
                 if let Some(origin) = self.routing_table.find_node(&origin_address) {
                     origin.received_query(transaction_id);
                 }
+
+                // Whoever contacted us can get us into the network where our routers couldn't
+                if let Status::BootstrapFailed = self.status {
+                    self.start_bootstrap(event_loop);
+                }
             }
 
             Message::Response(transaction_id, origin, response) => {
                 let origin_address = origin.address();
+                // Operations need their own copy of whoever answered their transactions
+                let operation = self.operations
+                    .answered(&transaction_id)
+                    .map(|operation_id| (operation_id, origin.clone()));
+                let mut lookup_nodes = None;
                 // Always insert the origin node
-                let mut encounted_new_node = self.insert_node(origin) == Ok(InsertOutcome::Inserted);
+                self.insert_node(origin).unwrap();
 
                 match response {
-                    Response::FindNode(mut nodes) => {
-                        for node in nodes.drain(..) {
-                            match self.insert_node(node) {
-                                Ok(InsertOutcome::Inserted) => {
-                                    encounted_new_node = true;
-                                }
-                                Err(error) => { panic!(error) }
-                                _ => { }
-                            }
-                        }
-
-                        {
-                            if let Some(mut origin) = self.routing_table.find_node(&origin_address) {
-                                origin.received_response(transaction_id);
+                    Response::FindNode(nodes) => {
+                        let self_address = self.self_node.address();
+                        // Nodes whose addresses aren't derived from their keys could be anywhere
+                        // they like in the address space, so we don't trust them with a place
+                        let nodes: Vec<Node> = nodes
+                            .into_iter()
+                            .filter(|node| node.has_verifiable_address())
+                            .collect();
+                        lookup_nodes = Some(nodes
+                            .iter()
+                            .filter(|node| node.address() != self_address)
+                            .cloned()
+                            .collect());
+                        for node in nodes {
+                            if let Err(error) = self.insert_node(node) {
+                                panic!(error)
                             }
                         }
 
-                        match self.pending_actions.remove(&transaction_id) {
-                            Some(TableAction::Bootstrap(timeout)) => {
-                                event_loop.clear_timeout(timeout);
-                                if encounted_new_node {
-                                    self.continue_bootstrap(event_loop);
-                                } else {
-                                    // TODO: Extract finish_bootstrap()
-                                    self.continue_health_check(event_loop);
-                                    self.continue_refresh(event_loop);
-                                    match self.status {
-                                        Status::Bootstrapping => {
-                                            self.status = Status::Idle;
-                                        }
-                                        _ => { }
-                                    }
-                                }
-                            }
-                            Some(TableAction::HealthCheck(_)) => {
-                                // Does not clear timeout. Lets Healthcheck proceed at regular
-                                // interval.
-                            },
-                            Some(TableAction::RefreshBucket(_)) => {
-                            }
-                            None => { }
+                        if let Some(mut origin) = self.routing_table.find_node(&origin_address) {
+                            origin.received_response(transaction_id);
                         }
-
                     }
                     Response::Packet => {
                         if let Some(mut origin) = self.routing_table.find_node(&origin_address) {
@@ -205,87 +432,304 @@ impl Network {
                         if let Some(mut origin) = self.routing_table.find_node(&origin_address) {
                             origin.received_response(transaction_id);
                         }
+                        // Only the node we punched a hole to can finish it, otherwise anyone who saw
+                        // the ping could graft a transport onto it
+                        let punched = match self.hole_punches.get(&transaction_id) {
+                            Some(&(address, _)) => address == origin_address,
+                            None => false
+                        };
+                        if punched {
+                            let (address, transport) = self.hole_punches.remove(&transaction_id).unwrap();
+                            self.finish_hole_punch(address, transport);
+                        }
+                    }
+                    Response::Rendezvous(peer) => {
+                        if let Some(origin) = self.routing_table.find_node(&origin_address) {
+                            origin.received_response(transaction_id);
+                        }
+                        if let Some(peer) = peer {
+                            if peer.address() != self.self_node.address() {
+                                self.punch_hole(origin_address, peer.address(), event_loop);
+                            }
+                        }
                     }
+                    Response::RegisterRelay(accepted) => {
+                        let relayed_transport = match self.routing_table.find_node(&origin_address) {
+                            Some(origin) => {
+                                origin.received_response(transaction_id);
+                                let transports = origin.transports()
+                                    .filter(|t| !t.is_relayed())
+                                    .cloned()
+                                    .collect();
+                                Some(RelayedTransport::new(origin_address, transports))
+                            }
+                            None => None
+                        };
+                        if accepted && self.relay.is_none() {
+                            if let Some(relayed_transport) = relayed_transport {
+                                self.use_relay(relayed_transport);
+                            }
+                        }
+                    }
+                }
+
+                if let Some((operation_id, origin)) = operation {
+                    self.received_operation_response(operation_id, transaction_id, origin, lookup_nodes, event_loop);
                 }
             }
+
+            Message::Relay(target, message) => self.relay_message(target, message)
         }
     }
 
+    /// Joins the network by looking up our own address, which fills the routing table with the
+    /// nodes nearest to us. Does nothing if we're already at it.
     fn start_bootstrap(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
-        self.status = Status::Bootstrapping;
+        let is_first = match self.status {
+            Status::Bootstrapping(_) => return,
+            Status::Idle => true,
+            _ => false
+        };
+        self.status = Status::Bootstrapping(0);
         self.continue_bootstrap(event_loop);
-        self.broadcast_event(Event::Started);
+        if is_first {
+            self.broadcast_event(Event::Started);
+        }
     }
 
+    /// Makes the current bootstrap attempt. Each one is seeded with the nodes we already know of,
+    /// if any, and the next of our routers in turn.
     fn continue_bootstrap(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
-        let address = &self.self_node.address();
-        let transaction_id = self.find_node(address);
-        let timeout = event_loop.timeout_ms(ScheduledTask::ContinueBootstrap, 1000).unwrap();
-        self.pending_actions.insert(transaction_id, TableAction::Bootstrap(timeout));
-    }
-
-    fn continue_health_check(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
-        let transaction_id = self.health_check();
-        let timeout = event_loop.timeout_ms(ScheduledTask::ContinueHealthCheck, 1000).unwrap();
-        self.pending_actions.insert(transaction_id, TableAction::HealthCheck(timeout));
+        let attempt = match self.status {
+            Status::Bootstrapping(attempt) => attempt,
+            _ => return
+        };
+        let address = self.self_node.address();
+        let mut seeds: Vec<Node> = self.routing_table
+            .nearest_live_nodes_to(&address, false)
+            .into_iter()
+            .map(|node| node.clone())
+            .collect();
+        let routers = self.routing_table.routers();
+        if !routers.is_empty() {
+            let router = &routers[attempt % routers.len()];
+            debug!("Bootstrapping through {:?}, attempt {}", router, attempt + 1);
+            seeds.push(router.clone());
+        }
+        self.start_lookup(&address, seeds, Some(TableAction::Bootstrap), event_loop);
     }
 
-    fn continue_refresh(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
-        let timeout = event_loop.timeout_ms(ScheduledTask::ContinueRefresh, 1000).unwrap();
-
-        match self.refresh_bucket() {
-            Some(transaction_id) => {
-                self.pending_actions.insert(transaction_id, TableAction::RefreshBucket(timeout));
+    /// Once a lookup for our own address has filled the routing table with the nodes nearest to
+    /// us, starts keeping it healthy. If no node answered, we try again after a while, giving up
+    /// once every router has been tried `BOOTSTRAP_ATTEMPTS_PER_ROUTER` times.
+    fn finish_bootstrap(&mut self, nearest: &[Address], event_loop: &mut mio::EventLoop<Handler>) {
+        let attempt = match self.status {
+            Status::Bootstrapping(attempt) => attempt,
+            _ => return
+        };
+        if nearest.is_empty() {
+            let routers = cmp::max(self.routing_table.routers().len(), 1);
+            if attempt + 1 >= routers * BOOTSTRAP_ATTEMPTS_PER_ROUTER {
+                info!("Failed to bootstrap after {} attempts", attempt + 1);
+                self.status = Status::BootstrapFailed;
+                self.broadcast_event(Event::BootstrapFailed);
+            } else {
+                self.status = Status::Bootstrapping(attempt + 1);
+                let delay = BOOTSTRAP_RETRY_MS << ((attempt + 1) / routers);
+                event_loop.timeout_ms(ScheduledTask::ContinueBootstrap, delay).unwrap();
             }
-            None => { }
+            return
         }
-    }
-
-    fn find_node(&mut self, address: &Address) -> TransactionId {
-        let transaction_id = self.transaction_ids.generate();
-        let query = outgoing::create_find_node_query(
-            transaction_id,
-            &self.self_node,
-            address);
-        for node in self.routing_table.nearest() {
-            node.sent_query(transaction_id);
-            node.send(query.clone());
+        info!("Bootstrapped, nearest nodes are {:?}", nearest);
+        self.status = Status::Bootstrapped;
+        if !self.is_maintaining_table {
+            self.is_maintaining_table = true;
+            self.continue_health_check(event_loop);
+            self.continue_refresh(event_loop);
+            self.continue_relay_keep_alive(event_loop);
         }
-        transaction_id
+        self.broadcast_event(Event::Bootstrapped);
     }
 
-    // TODO: I should de-couple operations and transactions. Some operations, e.g. health_check
-    // can be comprised of multiple transactions that must be completed before the operation is
-    // complete. In this case, I should receive a ping from each questionable node before
-    // health_check is done.
-    fn health_check(&mut self) -> TransactionId {
-        let transaction_id = self.transaction_ids.generate();
+    /// Starts a health check, which pings the nearest node and every questionable one. The next
+    /// one starts a while after it's over.
+    fn continue_health_check(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
+        let operation_id = self.transaction_ids.generate();
+        self.operations.start(operation_id, OperationState::HealthCheck);
+        let mut pings = vec![];
 
         // TODO: this should be a separate keep-alive task, but it will be
         // dependant on the type of connection we're keeping alive.
         //
         // Ping nearest node every time.
         if let Some(nearest_node) = self.routing_table.nearest().first_mut() {
+            let transaction_id = self.transaction_ids.generate();
             let query = outgoing::create_ping_query(
                 transaction_id, &self.self_node);
-            nearest_node.sent_query(transaction_id);
-            nearest_node.send(query);
+            nearest_node.send_query(transaction_id, query, &mut self.connections);
+            pings.push(transaction_id);
         }
 
         for node in self.routing_table.questionable_nodes().iter_mut() {
+            let transaction_id = self.transaction_ids.generate();
             let query = outgoing::create_ping_query(
                 transaction_id, &self.self_node);
-            node.sent_query(transaction_id);
-            node.send(query);
+            node.send_query(transaction_id, query, &mut self.connections);
+            pings.push(transaction_id);
+        }
+
+        for transaction_id in pings {
+            self.add_transaction(operation_id, transaction_id, HEALTH_CHECK_PING_TIMEOUT_MS, event_loop);
         }
-        transaction_id
+        self.routing_table.expire_eviction_pings();
+        self.continue_operation(operation_id, event_loop);
     }
 
-    fn refresh_bucket(&mut self) -> Option<TransactionId> {
-        if let Some(address) = self.address_to_find_for_refresh() {
-            Some(self.find_node(&address))
-        } else {
-            None
+    fn finish_health_check(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
+        // Every node we knew of has gone bad, so we'd better rejoin
+        if let Status::Bootstrapped = self.status {
+            let address = self.self_node.address();
+            if self.routing_table.nearest_live_nodes_to(&address, false).is_empty() {
+                info!("Routing table emptied out, bootstrapping again");
+                self.start_bootstrap(event_loop);
+            }
+        }
+        event_loop.timeout_ms(ScheduledTask::ContinueHealthCheck, HEALTH_CHECK_INTERVAL_MS).unwrap();
+    }
+
+    /// Refreshes a bucket that hasn't changed in a while, if there is one, by looking up a random
+    /// address in it. The next refresh starts a while after the lookup is over.
+    fn continue_refresh(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
+        match self.address_to_find_for_refresh() {
+            Some(address) => self.find_node(&address, Some(TableAction::RefreshBucket), event_loop),
+            None => {
+                event_loop.timeout_ms(ScheduledTask::ContinueRefresh, REFRESH_INTERVAL_MS).unwrap();
+            }
+        }
+    }
+
+    /// Starts an iterative lookup for the nodes nearest to `target`, seeded with the nearest ones
+    /// we know of. `action` is carried out once the lookup finishes.
+    fn find_node(&mut self, target: &Address, action: Option<TableAction>, event_loop: &mut mio::EventLoop<Handler>) {
+        let seeds = self.routing_table
+            .nearest_live_nodes_to(target, true)
+            .into_iter()
+            .map(|node| node.clone())
+            .collect();
+        self.start_lookup(target, seeds, action, event_loop);
+    }
+
+    fn start_lookup(&mut self, target: &Address, seeds: Vec<Node>, action: Option<TableAction>, event_loop: &mut mio::EventLoop<Handler>) {
+        let operation_id = self.transaction_ids.generate();
+        let k = self.routing_table.k();
+        let lookup = Lookup::new(*target, k, LOOKUP_ALPHA, self.disjoint_paths, seeds);
+        self.operations.start(operation_id, OperationState::Lookup(lookup, action));
+        self.continue_lookup(operation_id, event_loop);
+    }
+
+    /// Sends out the queries the lookup `operation_id` is ready for, or finishes it if it's done.
+    fn continue_lookup(&mut self, operation_id: OperationId, event_loop: &mut mio::EventLoop<Handler>) {
+        let (target, queries, finished) = match self.operations.get_mut(&operation_id) {
+            Some(Operation { state: OperationState::Lookup(lookup, _), .. }) => {
+                let queries = lookup.next_queries(&mut self.transaction_ids);
+                (lookup.target(), queries, lookup.is_finished())
+            }
+            _ => return
+        };
+        for (transaction_id, mut node) in queries {
+            let query = outgoing::create_find_node_query(transaction_id, &self.self_node, &target);
+            // Nodes we know of keep track of how well they answer
+            match self.routing_table.find_node(&node.address()) {
+                Some(known) => known.send_query(transaction_id, query, &mut self.connections),
+                None => node.send_query(transaction_id, query, &mut self.connections)
+            }
+            self.add_transaction(operation_id, transaction_id, LOOKUP_QUERY_TIMEOUT_MS, event_loop);
+        }
+        if finished {
+            self.finish_operation(operation_id, event_loop);
+        }
+    }
+
+    fn finish_lookup(&mut self, lookup: Lookup, action: Option<TableAction>, event_loop: &mut mio::EventLoop<Handler>) {
+        let nearest = lookup.nearest();
+        debug!("Finished lookup for {}, found {:?}", lookup.target(), &nearest);
+        self.broadcast_event(Event::LookupFinished(lookup.target(), nearest.clone()));
+        match action {
+            Some(TableAction::Bootstrap) => self.finish_bootstrap(&nearest, event_loop),
+            Some(TableAction::DeliverPacket(payload)) => {
+                for node in lookup.nearest_on_each_path() {
+                    self.send_packet_to(node, payload.clone());
+                }
+            }
+            Some(TableAction::RefreshBucket) => {
+                event_loop.timeout_ms(ScheduledTask::ContinueRefresh, REFRESH_INTERVAL_MS).unwrap();
+            }
+            None => { }
+        }
+    }
+
+    /// Adds `transaction_id` to the operation `operation_id`, and gives up on it if it goes
+    /// unanswered for `timeout_ms`.
+    fn add_transaction(&mut self, operation_id: OperationId, transaction_id: TransactionId, timeout_ms: u64, event_loop: &mut mio::EventLoop<Handler>) {
+        self.operations.add_transaction(operation_id, transaction_id);
+        event_loop.timeout_ms(ScheduledTask::ExpireTransaction(transaction_id), timeout_ms).unwrap();
+    }
+
+    /// Moves the operation `operation_id` on now that `origin` has answered its transaction
+    /// `transaction_id`, with `nodes` if it was a find node query.
+    fn received_operation_response(&mut self, operation_id: OperationId, transaction_id: TransactionId, origin: Node, nodes: Option<Vec<Node>>, event_loop: &mut mio::EventLoop<Handler>) {
+        if let Some(Operation { state: OperationState::Lookup(lookup, _), .. }) = self.operations.get_mut(&operation_id) {
+            // A response from someone other than the node we queried settles the transaction all
+            // the same, so the query is as good as unanswered
+            let accepted = match nodes {
+                Some(nodes) => lookup.received_response(transaction_id, origin, nodes),
+                None => false
+            };
+            if !accepted {
+                lookup.failed(transaction_id);
+            }
+        }
+        self.continue_operation(operation_id, event_loop);
+    }
+
+    fn expire_transaction(&mut self, transaction_id: TransactionId, event_loop: &mut mio::EventLoop<Handler>) {
+        if let Some(operation_id) = self.operations.expired(&transaction_id) {
+            if let Some(Operation { state: OperationState::Lookup(lookup, _), .. }) = self.operations.get_mut(&operation_id) {
+                lookup.failed(transaction_id);
+            }
+            self.continue_operation(operation_id, event_loop);
+        }
+    }
+
+    /// Carries on with the operation `operation_id` after one of its transactions was settled.
+    /// Lookups decide for themselves when they're done, and other operations are done once all of
+    /// their transactions are settled.
+    fn continue_operation(&mut self, operation_id: OperationId, event_loop: &mut mio::EventLoop<Handler>) {
+        let is_settled = match self.operations.get(&operation_id) {
+            Some(Operation { state: OperationState::Lookup(..), .. }) => {
+                self.continue_lookup(operation_id, event_loop);
+                return
+            }
+            Some(operation) => operation.is_settled(),
+            None => return
+        };
+        if is_settled {
+            self.finish_operation(operation_id, event_loop);
+        }
+    }
+
+    fn finish_operation(&mut self, operation_id: OperationId, event_loop: &mut mio::EventLoop<Handler>) {
+        let operation = match self.operations.finish(&operation_id) {
+            Some(operation) => operation,
+            None => return
+        };
+        match operation.state {
+            OperationState::Lookup(lookup, action) => self.finish_lookup(lookup, action, event_loop),
+            OperationState::HealthCheck => {
+                debug!("Finished health check, {} pings answered and {} not",
+                       operation.answered(), operation.expired());
+                self.finish_health_check(event_loop);
+            }
         }
     }
 
@@ -298,21 +742,268 @@ impl Network {
         }
     }
 
+    /// Asks the nodes nearest to `target` to introduce us, so that we can reach it even if we're
+    /// both behind NATs. Whoever knows `target` tells it to punch a hole towards us, and tells us
+    /// to punch one towards it.
+    fn rendezvous(&mut self, target: Address) {
+        let transaction_id = self.transaction_ids.generate();
+        let query = outgoing::create_rendezvous_query(
+            transaction_id, &self.self_node, &target, None);
+        for node in self.routing_table.nearest_live_nodes_to(&target, true) {
+            if node.capabilities().contains(Capabilities::RENDEZVOUS) {
+                node.send_query(transaction_id, query.clone(), &mut self.connections);
+            }
+        }
+    }
+
+    /// Pings `address` on its datagram transports, opening mappings in our NAT so that its pings
+    /// can get through to us. It does the same at the same time, and whichever transport we first
+    /// hear back on is added to the node. `requester` is whoever asked us to.
+    ///
+    /// Nobody vouches for the transports a rendezvous names, so we only punch holes to nodes we
+    /// already know, over the transports we know them by, and each requester can only have us do
+    /// so every `HOLE_PUNCH_COOLDOWN_SECONDS`. Otherwise we could be made to ping anyone.
+    fn punch_hole(&mut self, requester: Address, address: Address, event_loop: &mut mio::EventLoop<Handler>) {
+        if self.is_punching_hole(&address) {
+            return
+        }
+        let transports: Vec<Transport> = match self.routing_table.find_node(&address) {
+            Some(ref node) if node.has_verifiable_address() => node.transports()
+                .filter(|t| t.is_datagram())
+                .take(HOLE_PUNCH_MAX_TRANSPORTS)
+                .cloned()
+                .collect(),
+            _ => {
+                debug!("Not punching a hole to {}, which we don't know", &address);
+                return
+            }
+        };
+        if transports.is_empty() {
+            return
+        }
+        let now = time::now_utc();
+        let cooldown = time::Duration::seconds(HOLE_PUNCH_COOLDOWN_SECONDS);
+        self.hole_punch_requesters.retain(|_, &mut requested| now - requested < cooldown);
+        if self.hole_punch_requesters.contains_key(&requester) {
+            debug!("Not punching a hole to {} for {} again so soon", &address, &requester);
+            return
+        }
+        self.hole_punch_requesters.insert(requester, now);
+        self.send_hole_punch_pings(address, &transports);
+        let task = ScheduledTask::ContinueHolePunch(address, transports, HOLE_PUNCH_ATTEMPTS - 1);
+        event_loop.timeout_ms(task, HOLE_PUNCH_INTERVAL_MS).unwrap();
+    }
+
+    fn continue_hole_punch(&mut self, address: Address, transports: Vec<Transport>, attempts: usize, event_loop: &mut mio::EventLoop<Handler>) {
+        if !self.is_punching_hole(&address) {
+            return
+        }
+        if attempts == 0 {
+            debug!("Gave up punching a hole to {}", &address);
+            self.hole_punches.retain(|_, &mut (a, _)| a != address);
+            return
+        }
+        self.send_hole_punch_pings(address, &transports);
+        let task = ScheduledTask::ContinueHolePunch(address, transports, attempts - 1);
+        event_loop.timeout_ms(task, HOLE_PUNCH_INTERVAL_MS).unwrap();
+    }
+
+    fn is_punching_hole(&self, address: &Address) -> bool {
+        self.hole_punches.values().any(|&(a, _)| a == *address)
+    }
+
+    fn send_hole_punch_pings(&mut self, address: Address, transports: &[Transport]) {
+        for transport in transports {
+            let transaction_id = self.transaction_ids.generate();
+            let query = outgoing::create_ping_query(transaction_id, &self.self_node);
+            transport.send(query, &mut self.connections);
+            self.hole_punches.insert(transaction_id, (address, transport.clone()));
+        }
+    }
+
+    fn finish_hole_punch(&mut self, address: Address, transport: Transport) {
+        debug!("Punched a hole to {} over {:?}", &address, &transport);
+        self.hole_punches.retain(|_, &mut (a, _)| a != address);
+        if let Some(node) = self.routing_table.find_node(&address) {
+            node.add_transport(transport);
+        }
+        self.broadcast_event(Event::HolePunched(address));
+    }
+
+    /// Keeps us registered with a relay for as long as we're behind a symmetric NAT, which no
+    /// hole can be punched through. If our relay stops answering, we ask the nodes nearest to us
+    /// for another.
+    fn continue_relay_keep_alive(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
+        if self.self_node.nat_type() != NatType::Symmetric {
+            return
+        }
+        self.register_with_relay();
+        event_loop.timeout_ms(ScheduledTask::ContinueRelayKeepAlive, RELAY_KEEPALIVE_MS).unwrap();
+    }
+
+    fn register_with_relay(&mut self) {
+        let transaction_id = self.transaction_ids.generate();
+        let query = outgoing::create_register_relay_query(transaction_id, &self.self_node);
+        if let Some(relay) = self.relay.as_ref().map(|relayed_transport| relayed_transport.relay()) {
+            match self.routing_table.find_node(&relay) {
+                Some(ref mut node) if node.pending_query_count() < RELAY_UNANSWERED_THRESHOLD => {
+                    node.send_query(transaction_id, query, &mut self.connections);
+                    return
+                }
+                _ => {
+                    info!("Lost relay {}", &relay);
+                    let relayed_transport = Transport::Relayed(self.relay.take().unwrap());
+                    self.self_node.remove_transport(&relayed_transport);
+                }
+            }
+        }
+        for node in self.routing_table.nearest() {
+            if node.capabilities().contains(Capabilities::RELAY) {
+                node.send_query(transaction_id, query.clone(), &mut self.connections);
+            }
+        }
+    }
+
+    /// Advertises `relayed_transport` as the way to reach us from now on.
+    fn use_relay(&mut self, relayed_transport: RelayedTransport) {
+        let relay = relayed_transport.relay();
+        info!("Relaying through {}", &relay);
+        self.self_node.add_transport(Transport::Relayed(relayed_transport.clone()));
+        self.relay = Some(relayed_transport);
+        self.broadcast_event(Event::RegisteredWithRelay(relay));
+    }
+
+    /// Forwards `message` to `target` if it's registered with us as its relay.
+    fn relay_message(&mut self, target: Address, message: Vec<u8>) {
+        let now = time::now_utc();
+        let expiry = time::Duration::seconds(RELAY_REGISTRATION_SECONDS);
+        self.relayed_nodes.retain(|_, &mut (_, registered_at)| now - registered_at < expiry);
+        match self.relayed_nodes.get(&target) {
+            Some((transports, _)) => {
+                for transport in transports {
+                    transport.send(message.clone(), &mut self.connections);
+                }
+            }
+            None => debug!("Dropping message for {}, which isn't registered with us", &target)
+        }
+    }
+
     fn insert_node(&mut self, node: Node) -> InsertionResult {
-        self.routing_table.insert(node, &self.self_node, &mut self.transaction_ids)
+        self.routing_table.insert(node, &self.self_node, &mut self.transaction_ids,
+                                  &mut self.connections)
     }
 
-    fn send_packet(&mut self, recipient: Address, payload: Vec<u8>, _event_loop: &mut mio::EventLoop<Handler>) {
+    fn send_packet(&mut self, recipient: Address, payload: Vec<u8>, event_loop: &mut mio::EventLoop<Handler>) {
+        if payload.len() > self.fragments.max_payload_size() {
+            debug!("Not sending {} byte packet to {}, which is over the maximum of {}",
+                   payload.len(), &recipient, self.fragments.max_payload_size());
+            return
+        }
+        if self.disjoint_paths > 1 {
+            self.find_node(&recipient, Some(TableAction::DeliverPacket(payload)), event_loop);
+            return
+        }
         for node in self.routing_table.nearest_live_nodes_to(&recipient, false) {
-            let transaction_id = self.transaction_ids.generate();
-            let query = outgoing::create_packet_query(
-                transaction_id, &self.self_node, payload.clone());
-            node.sent_query(transaction_id);
-            node.send(query.clone());
+            let queries = Self::create_packet_queries(&mut self.transaction_ids, &self.self_node, node, &payload);
+            for (transaction_id, query) in queries {
+                node.send_query(transaction_id, query, &mut self.connections);
+            }
+        }
+    }
+
+    fn send_packet_to(&mut self, node: &Node, payload: Vec<u8>) {
+        let queries = Self::create_packet_queries(&mut self.transaction_ids, &self.self_node, node, &payload);
+        for (transaction_id, query) in queries {
+            // Nodes we know of keep track of how well they answer
+            match self.routing_table.find_node(&node.address()) {
+                Some(known) => known.send_query(transaction_id, query, &mut self.connections),
+                None => node.clone().send_query(transaction_id, query, &mut self.connections)
+            }
+        }
+    }
+
+    /// The packet queries to send `payload` to `recipient` in, with their transaction IDs: just
+    /// the one, unless it's larger than `FRAGMENT_SIZE`, in which case one for each fragment of it.
+    /// Nodes that don't put fragments back together would take each for a whole packet, so they're
+    /// sent none.
+    fn create_packet_queries(transaction_ids: &mut TransactionIdGenerator, self_node: &Node, recipient: &Node, payload: &[u8]) -> Vec<(TransactionId, Vec<u8>)> {
+        if payload.len() <= FRAGMENT_SIZE {
+            let transaction_id = transaction_ids.generate();
+            return vec![(transaction_id, outgoing::create_packet_query(transaction_id, self_node, payload.to_vec()))];
+        }
+        if !recipient.capabilities().contains(Capabilities::FRAGMENTS) {
+            debug!("Not sending {} byte packet to {}, which doesn't support fragments",
+                   payload.len(), recipient.address());
+            return vec![];
+        }
+        let id = transaction_ids.generate();
+        let count = fragment::fragment_count(payload.len());
+        payload.chunks(FRAGMENT_SIZE)
+            .enumerate()
+            .map(|(index, data)| {
+                let transaction_id = transaction_ids.generate();
+                let fragment = Fragment {
+                    id: id,
+                    index: index,
+                    count: count
+                };
+                (transaction_id, outgoing::create_packet_fragment_query(transaction_id, self_node, &fragment, data.to_vec()))
+            })
+            .collect()
+    }
+
+    /// Adds `data`, the `fragment` of a payload from `sender`, and returns the whole payload if
+    /// it was the last fragment missing.
+    fn reassemble(&mut self, sender: Address, fragment: Fragment, data: Vec<u8>, event_loop: &mut mio::EventLoop<Handler>) -> Option<Vec<u8>> {
+        match self.fragments.insert(sender, fragment, data) {
+            Reassembly::Started => {
+                // A single timeout checks on every payload, however many are started
+                if !self.is_expiring_reassemblies {
+                    self.is_expiring_reassemblies = true;
+                    event_loop.timeout_ms(ScheduledTask::ExpireReassemblies, REASSEMBLY_EXPIRY_INTERVAL_MS).unwrap();
+                }
+                None
+            }
+            Reassembly::Buffered => None,
+            Reassembly::Complete(payload) => Some(payload),
+            Reassembly::Dropped(reason) => {
+                debug!("Dropping fragment {:?} from {}: {}", fragment, sender, reason);
+                None
+            }
+        }
+    }
+
+    fn expire_reassemblies(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
+        self.fragments.expire(time::Duration::milliseconds(REASSEMBLY_TIMEOUT_MS as i64));
+        if self.fragments.is_reassembling() {
+            event_loop.timeout_ms(ScheduledTask::ExpireReassemblies, REASSEMBLY_EXPIRY_INTERVAL_MS).unwrap();
+        } else {
+            self.is_expiring_reassemblies = false;
+        }
+    }
+
+    fn snapshot_routing_table(&self, reply: mpsc::Sender<RoutingTableSnapshot>) {
+        if reply.send(self.routing_table.snapshot()).is_err() {
+            debug!("Nobody was waiting for the routing table snapshot");
+        }
+    }
+
+    fn continue_saving_routing_table(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
+        self.save_routing_table();
+        event_loop.timeout_ms(ScheduledTask::SaveRoutingTable, ROUTING_TABLE_SAVE_INTERVAL_MS).unwrap();
+    }
+
+    fn save_routing_table(&self) {
+        if let Some(path) = &self.routing_table_file {
+            match self.routing_table.save().write_to_file(path) {
+                Ok(()) => debug!("Saved routing table to {:?}", path),
+                Err(error) => info!("Couldn't save routing table to {:?}: {}", path, error)
+            }
         }
     }
 
     fn shutdown(&mut self, event_loop: &mut mio::EventLoop<Handler>) {
+        self.save_routing_table();
         event_loop.shutdown();
         self.broadcast_event(Event::Shutdown);
     }
@@ -346,10 +1037,13 @@ impl mio::Handler for Handler {
 
     fn notify(&mut self, event_loop: &mut mio::EventLoop<Handler>, task: OneshotTask) {
         match task {
-            OneshotTask::Incoming(data) => self.network.handle_incoming(data, event_loop),
+            OneshotTask::Incoming(data, source) => self.network.handle_incoming(data, source, event_loop),
+            OneshotTask::FindNode(target) => self.network.find_node(&target, None, event_loop),
+            OneshotTask::PunchHole(target) => self.network.rendezvous(target),
             OneshotTask::StartBootstrap => self.network.start_bootstrap(event_loop),
             OneshotTask::SendPacket(recipient, payload) =>
                 self.network.send_packet(recipient, payload, event_loop),
+            OneshotTask::SnapshotRoutingTable(reply) => self.network.snapshot_routing_table(reply),
             OneshotTask::Shutdown => self.network.shutdown(event_loop)
         }
     }
@@ -358,7 +1052,556 @@ impl mio::Handler for Handler {
         match timeout {
             ScheduledTask::ContinueBootstrap => self.network.continue_bootstrap(event_loop),
             ScheduledTask::ContinueHealthCheck => self.network.continue_health_check(event_loop),
-            ScheduledTask::ContinueRefresh => self.network.continue_refresh(event_loop)
+            ScheduledTask::ContinueHolePunch(address, transports, attempts) =>
+                self.network.continue_hole_punch(address, transports, attempts, event_loop),
+            ScheduledTask::ContinueRefresh => self.network.continue_refresh(event_loop),
+            ScheduledTask::ExpireTransaction(transaction_id) =>
+                self.network.expire_transaction(transaction_id, event_loop),
+            ScheduledTask::ExpireReassemblies => self.network.expire_reassemblies(event_loop),
+            ScheduledTask::ContinueRelayKeepAlive => self.network.continue_relay_keep_alive(event_loop),
+            ScheduledTask::SaveRoutingTable => self.network.continue_saving_routing_table(event_loop)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use address::Address;
+    use identity::Identity;
+    use node::{Node, RelayedTransport, Transport};
+    use servers::{Connections, Server};
+    use std::collections::HashSet;
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use super::{Event, Network, OneshotTask};
+
+    #[test]
+    fn test_send_packet_in_memory() {
+        let alpha_identity = Identity::for_secret("alpha");
+        let alpha_address = alpha_identity.address();
+        let beta_identity = Identity::for_secret("beta");
+        let beta_address = beta_identity.address();
+
+        let alpha_servers = vec![Server::create("memory://test_send_packet_in_memory_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_send_packet_in_memory_alpha").unwrap());
+        let router = Node::new(Address::null(), transports);
+        let beta_servers = vec![Server::create("memory://test_send_packet_in_memory_beta").unwrap()];
+        let beta = Network::new(beta_identity, beta_servers, vec![router], None);
+        let beta_tasks = beta.run();
+
+        // Keep sending until beta has bootstrapped and knows where to find alpha
+        let mut received = None;
+        for _ in 0..50 {
+            beta_tasks.send(OneshotTask::SendPacket(alpha_address, b"hello".to_vec())).unwrap();
+            while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(100)) {
+                if let Event::ReceivedPacket(sender, payload) = event {
+                    received = Some((sender, payload));
+                    break
+                }
+            }
+            if received.is_some() {
+                break
+            }
+        }
+
+        assert_eq!(received, Some((beta_address, b"hello".to_vec())));
+        beta_tasks.send(OneshotTask::Shutdown).unwrap();
+        alpha_tasks.send(OneshotTask::Shutdown).unwrap();
+    }
+
+    #[test]
+    fn test_send_packet_in_fragments() {
+        use fragment::FRAGMENT_SIZE;
+
+        let alpha_identity = Identity::for_secret("alpha");
+        let alpha_address = alpha_identity.address();
+
+        let alpha_servers = vec![Server::create("memory://test_send_packet_in_fragments_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_send_packet_in_fragments_alpha").unwrap());
+        let router = Node::new(Address::null(), transports);
+        let beta_servers = vec![Server::create("memory://test_send_packet_in_fragments_beta").unwrap()];
+        let mut beta = Network::new(Identity::for_secret("beta"), beta_servers, vec![router], None);
+        beta.set_max_packet_size(4 * FRAGMENT_SIZE);
+        let beta_tasks = beta.run();
+
+        let payload: Vec<u8> = (0..3 * FRAGMENT_SIZE + 7).map(|i| i as u8).collect();
+        let mut received = None;
+        for _ in 0..50 {
+            beta_tasks.send(OneshotTask::SendPacket(alpha_address, vec![0; 4 * FRAGMENT_SIZE + 1])).unwrap();
+            beta_tasks.send(OneshotTask::SendPacket(alpha_address, payload.clone())).unwrap();
+            while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(100)) {
+                if let Event::ReceivedPacket(_, payload) = event {
+                    received = Some(payload);
+                    break
+                }
+            }
+            if received.is_some() {
+                break
+            }
+        }
+
+        // The payload over beta's maximum is never sent
+        assert_eq!(received, Some(payload));
+        beta_tasks.send(OneshotTask::Shutdown).unwrap();
+        alpha_tasks.send(OneshotTask::Shutdown).unwrap();
+    }
+
+    #[test]
+    fn test_only_fragments_for_nodes_that_support_it() {
+        use fragment::FRAGMENT_SIZE;
+        use node::Capabilities;
+        use transaction::TransactionIdGenerator;
+
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let self_node = Node::for_identity(Identity::for_secret("alpha"), HashSet::new());
+        let mut recipient = Node::new(Address::for_content("beta"), HashSet::new());
+        let payload = vec![0; 2 * FRAGMENT_SIZE + 1];
+
+        // An older node would take each fragment for a whole packet
+        assert_eq!(Network::create_packet_queries(&mut transaction_ids, &self_node, &recipient, &payload).len(), 0);
+        assert_eq!(Network::create_packet_queries(&mut transaction_ids, &self_node, &recipient, b"small").len(), 1);
+
+        recipient.set_capabilities(Capabilities::FRAGMENTS);
+        assert_eq!(Network::create_packet_queries(&mut transaction_ids, &self_node, &recipient, &payload).len(), 3);
+    }
+
+    #[test]
+    fn test_send_packet_along_disjoint_paths() {
+        let alpha_identity = Identity::for_secret("alpha");
+        let alpha_address = alpha_identity.address();
+        let beta_identity = Identity::for_secret("beta");
+        let beta_address = beta_identity.address();
+
+        let alpha_servers = vec![Server::create("memory://test_send_packet_disjoint_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_send_packet_disjoint_alpha").unwrap());
+        let router = Node::new(Address::null(), transports);
+        let beta_servers = vec![Server::create("memory://test_send_packet_disjoint_beta").unwrap()];
+        let mut beta = Network::new(beta_identity, beta_servers, vec![router], None);
+        beta.use_disjoint_paths(2);
+        let beta_tasks = beta.run();
+
+        // Each packet waits on a lookup for alpha, which finds it once beta has bootstrapped
+        let mut received = None;
+        for _ in 0..20 {
+            beta_tasks.send(OneshotTask::SendPacket(alpha_address, b"hello".to_vec())).unwrap();
+            while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(250)) {
+                if let Event::ReceivedPacket(sender, payload) = event {
+                    received = Some((sender, payload));
+                    break
+                }
+            }
+            if received.is_some() {
+                break
+            }
+        }
+
+        assert_eq!(received, Some((beta_address, b"hello".to_vec())));
+        beta_tasks.send(OneshotTask::Shutdown).unwrap();
+        alpha_tasks.send(OneshotTask::Shutdown).unwrap();
+    }
+
+    #[test]
+    fn test_drops_messages_not_signed_by_their_origin() {
+        use messages::outgoing;
+
+        let alpha_servers = vec![Server::create("memory://test_drops_unsigned_alpha").unwrap()];
+        let mut alpha = Network::new(Identity::for_secret("alpha"), alpha_servers, vec![], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_drops_unsigned_alpha").unwrap());
+        let alpha_node = Node::new(Address::null(), transports);
+
+        // Claims beta's address without beta's key, so it can't sign for it
+        let beta = Identity::for_secret("beta");
+        let impostor = Node::new(beta.address(), HashSet::new());
+        let query = outgoing::create_packet_query(1, &impostor, b"forged".to_vec());
+        alpha_node.send(query, &mut Connections::new());
+
+        let beta_node = Node::for_identity(beta, HashSet::new());
+        let query = outgoing::create_packet_query(2, &beta_node, b"genuine".to_vec());
+        alpha_node.send(query, &mut Connections::new());
+
+        let mut received = vec![];
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(500)) {
+            if let Event::ReceivedPacket(_, payload) = event {
+                received.push(payload);
+            }
+        }
+        assert_eq!(received, vec![b"genuine".to_vec()]);
+        alpha_tasks.send(OneshotTask::Shutdown).unwrap();
+    }
+
+    #[test]
+    fn test_survives_malformed_messages() {
+        use messages::outgoing;
+
+        let alpha_servers = vec![Server::create("memory://test_survives_malformed_alpha").unwrap()];
+        let mut alpha = Network::new(Identity::for_secret("alpha"), alpha_servers, vec![], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_survives_malformed_alpha").unwrap());
+        let alpha_node = Node::new(Address::null(), transports);
+        alpha_node.send(vec![], &mut Connections::new());
+        alpha_node.send(b"\x0a\xff\xff\xff\xff".to_vec(), &mut Connections::new());
+        alpha_node.send(outgoing::create_relay(&Address::null(), vec![])[..10].to_vec(), &mut Connections::new());
+
+        let beta_node = Node::for_identity(Identity::for_secret("beta"), HashSet::new());
+        let query = outgoing::create_packet_query(1, &beta_node, b"still up".to_vec());
+        alpha_node.send(query, &mut Connections::new());
+
+        let mut received = vec![];
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(500)) {
+            if let Event::ReceivedPacket(_, payload) = event {
+                received.push(payload);
+            }
+        }
+        assert_eq!(received, vec![b"still up".to_vec()]);
+        alpha_tasks.send(OneshotTask::Shutdown).unwrap();
+    }
+
+    #[test]
+    fn test_self_node_nat_type() {
+        use stun::{self, NatType};
+
+        let first = stun::tests::stun_server(10, |_| "203.0.113.7:41000".parse().unwrap());
+        let second = stun::tests::stun_server(10, |_| "203.0.113.7:41001".parse().unwrap());
+        let mut server = Server::create("udp://127.0.0.1:0").unwrap();
+        server.set_stun_config(stun::Config {
+            servers: vec![first.to_string(), second.to_string()],
+            timeout: Duration::from_millis(200),
+            attempts: 1
+        });
+        let network = Network::new(Identity::for_secret("alpha"), vec![server], vec![], None);
+        assert_eq!(network.self_node.nat_type(), NatType::Symmetric);
+
+        let server = Server::create("memory://test_self_node_nat_type").unwrap();
+        let network = Network::new(Identity::for_secret("alpha"), vec![server], vec![], None);
+        assert_eq!(network.self_node.nat_type(), NatType::Unknown);
+    }
+
+    #[test]
+    fn test_punch_hole_through_mutual_peer() {
+        let alpha_identity = Identity::for_secret("alpha");
+        let beta_identity = Identity::for_secret("beta");
+        let beta_address = beta_identity.address();
+        let gamma_identity = Identity::for_secret("gamma");
+
+        let gamma_servers = vec![Server::create("memory://test_punch_hole_gamma").unwrap()];
+        let gamma_tasks = Network::new(gamma_identity, gamma_servers, vec![], None).run();
+
+        let router = || {
+            let mut transports = HashSet::new();
+            transports.insert(Transport::create("memory://test_punch_hole_gamma").unwrap());
+            Node::new(Address::null(), transports)
+        };
+        let alpha_servers = vec![Server::create("memory://test_punch_hole_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![router()], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+        let beta_servers = vec![Server::create("memory://test_punch_hole_beta").unwrap()];
+        let beta_tasks = Network::new(beta_identity, beta_servers, vec![router()], None).run();
+
+        // Keep asking until both have bootstrapped through gamma
+        let mut punched = false;
+        for _ in 0..50 {
+            alpha_tasks.send(OneshotTask::PunchHole(beta_address)).unwrap();
+            while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(100)) {
+                if let Event::HolePunched(address) = event {
+                    assert_eq!(address, beta_address);
+                    punched = true;
+                    break
+                }
+            }
+            if punched {
+                break
+            }
+        }
+        assert!(punched);
+
+        for tasks in vec![alpha_tasks, beta_tasks, gamma_tasks] {
+            tasks.send(OneshotTask::Shutdown).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_find_node() {
+        let alpha_identity = Identity::for_secret("alpha");
+        let alpha_address = alpha_identity.address();
+        let beta_identity = Identity::for_secret("beta");
+        let beta_address = beta_identity.address();
+        let gamma_identity = Identity::for_secret("gamma");
+        let gamma_address = gamma_identity.address();
+
+        let gamma_servers = vec![Server::create("memory://test_find_node_gamma").unwrap()];
+        let gamma_tasks = Network::new(gamma_identity, gamma_servers, vec![], None).run();
+
+        let router = || {
+            let mut transports = HashSet::new();
+            transports.insert(Transport::create("memory://test_find_node_gamma").unwrap());
+            Node::new(Address::null(), transports)
+        };
+        let beta_servers = vec![Server::create("memory://test_find_node_beta").unwrap()];
+        let beta_tasks = Network::new(beta_identity, beta_servers, vec![router()], None).run();
+        let alpha_servers = vec![Server::create("memory://test_find_node_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![router()], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        // Bootstrapping is a lookup for our own address, by way of gamma
+        let mut bootstrapped = None;
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(5000)) {
+            if let Event::LookupFinished(target, nearest) = event {
+                assert_eq!(target, alpha_address);
+                bootstrapped = Some(nearest);
+                break
+            }
+        }
+        assert!(bootstrapped.unwrap().contains(&gamma_address));
+
+        // Keep looking until beta has bootstrapped too and gamma knows of it
+        let mut found = false;
+        for _ in 0..50 {
+            alpha_tasks.send(OneshotTask::FindNode(beta_address)).unwrap();
+            while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(100)) {
+                if let Event::LookupFinished(target, nearest) = event {
+                    if target == beta_address {
+                        found = nearest.first() == Some(&beta_address);
+                        break
+                    }
+                }
+            }
+            if found {
+                break
+            }
+        }
+        assert!(found);
+
+        for tasks in vec![alpha_tasks, beta_tasks, gamma_tasks] {
+            tasks.send(OneshotTask::Shutdown).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_relay_for_node_behind_symmetric_nat() {
+        use messages::outgoing;
+        use stun::NatType;
+
+        let alpha_identity = Identity::for_secret("alpha");
+        let alpha_address = alpha_identity.address();
+        let beta_identity = Identity::for_secret("beta");
+        let beta_address = beta_identity.address();
+        let gamma_identity = Identity::for_secret("gamma");
+        let gamma_address = gamma_identity.address();
+
+        let gamma_servers = vec![Server::create("memory://test_relay_gamma").unwrap()];
+        let mut gamma = Network::new(gamma_identity, gamma_servers, vec![], None);
+        gamma.volunteer_as_relay();
+        let gamma_tasks = gamma.run();
+
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_relay_gamma").unwrap());
+        let router = Node::new(Address::null(), transports);
+        let alpha_servers = vec![Server::create("memory://test_relay_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![router], None);
+        alpha.self_node.set_nat_type(NatType::Symmetric);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        let mut relay = None;
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(5000)) {
+            if let Event::RegisteredWithRelay(address) = event {
+                relay = Some(address);
+                break
+            }
+        }
+        assert_eq!(relay, Some(gamma_address));
+
+        // Reach alpha only by way of gamma
+        let relay_transports = vec![Transport::create("memory://test_relay_gamma").unwrap()];
+        let mut transports = HashSet::new();
+        transports.insert(Transport::Relayed(RelayedTransport::new(gamma_address, relay_transports)));
+        let alpha_node = Node::new(alpha_address, transports);
+        let beta_node = Node::for_identity(beta_identity, HashSet::new());
+        let query = outgoing::create_packet_query(1, &beta_node, b"hello".to_vec());
+        alpha_node.send(query, &mut Connections::new());
+
+        let mut received = None;
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(1000)) {
+            if let Event::ReceivedPacket(sender, payload) = event {
+                received = Some((sender, payload));
+                break
+            }
+        }
+        assert_eq!(received, Some((beta_address, b"hello".to_vec())));
+
+        for tasks in vec![alpha_tasks, gamma_tasks] {
+            tasks.send(OneshotTask::Shutdown).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_rotates_through_routers() {
+        let alpha_identity = Identity::for_secret("alpha");
+        let alpha_address = alpha_identity.address();
+        let gamma_identity = Identity::for_secret("gamma");
+
+        let gamma_servers = vec![Server::create("memory://test_bootstrap_rotates_gamma").unwrap()];
+        let gamma_tasks = Network::new(gamma_identity, gamma_servers, vec![], None).run();
+
+        let router = |name| {
+            let mut transports = HashSet::new();
+            transports.insert(Transport::create(name).unwrap());
+            Node::new(Address::null(), transports)
+        };
+        let routers = vec![router("memory://test_bootstrap_rotates_nobody"),
+                           router("memory://test_bootstrap_rotates_gamma")];
+        let alpha_servers = vec![Server::create("memory://test_bootstrap_rotates_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, routers, None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        // The first router never answers, so it takes a second attempt through the next
+        let mut attempts = 0;
+        let mut bootstrapped = false;
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(5000)) {
+            match event {
+                Event::LookupFinished(target, _) => {
+                    assert_eq!(target, alpha_address);
+                    attempts += 1;
+                }
+                Event::Bootstrapped => {
+                    bootstrapped = true;
+                    break
+                }
+                Event::BootstrapFailed => break,
+                _ => {}
+            }
+        }
+        assert!(bootstrapped);
+        assert_eq!(attempts, 2);
+
+        for tasks in vec![alpha_tasks, gamma_tasks] {
+            tasks.send(OneshotTask::Shutdown).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_gives_up_and_resumes_when_contacted() {
+        use super::BOOTSTRAP_ATTEMPTS_PER_ROUTER;
+
+        let alpha_identity = Identity::for_secret("alpha");
+        let beta_identity = Identity::for_secret("beta");
+
+        let alpha_servers = vec![Server::create("memory://test_bootstrap_gives_up_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        // Nobody to bootstrap through
+        let mut attempts = 0;
+        let mut failed = false;
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(10000)) {
+            match event {
+                Event::LookupFinished(..) => attempts += 1,
+                Event::BootstrapFailed => {
+                    failed = true;
+                    break
+                }
+                _ => {}
+            }
+        }
+        assert!(failed);
+        assert_eq!(attempts, BOOTSTRAP_ATTEMPTS_PER_ROUTER);
+
+        // Until beta comes along
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_bootstrap_gives_up_alpha").unwrap());
+        let router = Node::new(Address::null(), transports);
+        let beta_servers = vec![Server::create("memory://test_bootstrap_gives_up_beta").unwrap()];
+        let beta_tasks = Network::new(beta_identity, beta_servers, vec![router], None).run();
+
+        let mut bootstrapped = false;
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(5000)) {
+            if let Event::Bootstrapped = event {
+                bootstrapped = true;
+                break
+            }
+        }
+        assert!(bootstrapped);
+
+        for tasks in vec![alpha_tasks, beta_tasks] {
+            tasks.send(OneshotTask::Shutdown).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_snapshot_routing_table() {
+        use node::Status;
+
+        let alpha_identity = Identity::for_secret("alpha");
+        let alpha_address = alpha_identity.address();
+        let gamma_identity = Identity::for_secret("gamma");
+        let gamma_address = gamma_identity.address();
+
+        let gamma_servers = vec![Server::create("memory://test_snapshot_gamma").unwrap()];
+        let gamma_tasks = Network::new(gamma_identity, gamma_servers, vec![], None).run();
+
+        let mut transports = HashSet::new();
+        transports.insert(Transport::create("memory://test_snapshot_gamma").unwrap());
+        let router = Node::new(Address::null(), transports);
+        let alpha_servers = vec![Server::create("memory://test_snapshot_alpha").unwrap()];
+        let mut alpha = Network::new(alpha_identity, alpha_servers, vec![router], None);
+        let (alpha_events, alpha_received) = mpsc::channel();
+        alpha.register_event_listener(alpha_events);
+        let alpha_tasks = alpha.run();
+
+        while let Ok(event) = alpha_received.recv_timeout(Duration::from_millis(5000)) {
+            if let Event::Bootstrapped = event {
+                break
+            }
+        }
+
+        let (reply, snapshots) = mpsc::channel();
+        alpha_tasks.send(OneshotTask::SnapshotRoutingTable(reply)).unwrap();
+        let snapshot = snapshots.recv_timeout(Duration::from_millis(1000)).unwrap();
+        assert_eq!(snapshot.self_address, alpha_address);
+        assert_eq!(snapshot.node_count(), 1);
+        let gamma = snapshot.buckets.iter().flat_map(|b| b.nodes.iter()).next().unwrap();
+        assert_eq!(gamma.address, gamma_address);
+        // Queried as a router, so it hasn't answered us under its own address yet
+        assert!(gamma.status != Status::Bad);
+
+        for tasks in vec![alpha_tasks, gamma_tasks] {
+            tasks.send(OneshotTask::Shutdown).unwrap();
         }
     }
 }
@@ -1,11 +1,18 @@
 use address::{Address, Addressable};
+use identity::Identity;
 use messages;
+use servers::{self, Connections};
 use std::cmp;
 use std::collections::{HashMap, HashSet};
+use std::collections::hash_map;
 use std::fmt;
-use std::net::{SocketAddr, UdpSocket, IpAddr, Ipv4Addr};
+use std::hash::{Hash, Hasher};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket, IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::{Path, PathBuf};
+use stun::NatType;
 use time;
 use transaction::TransactionId;
+use websocket;
 
 /// The maximum number of queries sent without a response before a node is considered bad.
 pub const FAILED_TO_RESPOND_THRESHOLD: usize = 5;
@@ -13,6 +20,13 @@ pub const FAILED_TO_RESPOND_THRESHOLD: usize = 5;
 /// A node becomes questionable if it hasn't been heard from in this many minutes.
 pub const MINUTES_UNTIL_QUESTIONABLE: i64 = 15;
 
+/// A query sent over a transport that goes unanswered for this many seconds counts as a failure
+/// of the transport.
+pub const SECONDS_UNTIL_TRANSPORT_FAILURE: i64 = 5;
+
+/// A transport that fails this many times in a row is dropped, unless it's a node's last.
+pub const TRANSPORT_FAILURE_THRESHOLD: usize = 3;
+
 /// Anything that needs to be serialized for transfer or storage.
 ///
 /// TODO: this and `deserialize` should probably both be a part of a `Serializable` trait so that
@@ -21,46 +35,209 @@ pub trait Serialize {
     fn serialize(&self) -> messages::protobufs::Node;
 }
 
-#[derive(Debug, PartialEq)]
+/// The optional parts of the protocol a node supports, as the bitmap carried in its protobuf. A
+/// node that doesn't say supports none of them, only finding nodes, pings and packets. Bits we
+/// don't know of are kept, so that they're passed on as they were.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Capabilities(u64);
+
+impl Capabilities {
+    /// Answers rendezvous queries, introducing the nodes it knows to those looking for them.
+    pub const RENDEZVOUS: Capabilities = Capabilities(1);
+
+    /// Relays messages to nodes that can't be reached directly.
+    pub const RELAY: Capabilities = Capabilities(1 << 1);
+
+    /// Puts packets sent in fragments back together. Packets too large for one fragment are only
+    /// sent to nodes that do.
+    pub const FRAGMENTS: Capabilities = Capabilities(1 << 2);
+
+    pub fn from_bits(bits: u64) -> Capabilities {
+        Capabilities(bits)
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn contains(&self, other: Capabilities) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn insert(&mut self, other: Capabilities) {
+        self.0 |= other.0;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Status {
     Good,
     Questionable,
     Bad
 }
 
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Transport {
-    Udp(UdpTransport)
+    Udp(UdpTransport),
+    Tcp(TcpTransport),
+    Memory(MemoryTransport),
+    Unix(UnixTransport),
+    WebSocket(WebSocketTransport),
+    Relayed(RelayedTransport)
 }
 
 impl Transport {
-    /// Creates a Transport trait object (thus it must be boxed)
-    fn deserialize(message: &messages::protobufs::Transport) -> Transport {
+    /// Creates a `Transport` from a URL such as `udp://1.2.3.4:6667`, `tcp://1.2.3.4:6667`,
+    /// `unix:///run/comm.sock` or `ws://comm.example.com:80/comm`. A bare `host:port` is taken to
+    /// mean UDP.
+    pub fn create(url: &str) -> Option<Transport> {
+        let parts: Vec<&str> = url.splitn(2, "://").collect();
+        let (protocol, host) = match parts.len() {
+            2 => (parts[0], parts[1]),
+            _ => ("udp", parts[0])
+        };
+        match protocol {
+            "memory" => return Some(Transport::Memory(MemoryTransport::new(host))),
+            "unix" => return Some(Transport::Unix(UnixTransport::new(host))),
+            "ws" => {
+                let (host, path) = websocket::split_path(host);
+                return Some(Transport::WebSocket(WebSocketTransport::new(host, path)))
+            }
+            _ => { }
+        }
+        let socket_addr = match host.to_socket_addrs().ok().and_then(|mut s| s.next()) {
+            Some(socket_addr) => socket_addr,
+            None => return None
+        };
+        match protocol {
+            "udp" => Some(Transport::Udp(UdpTransport::new(socket_addr))),
+            "tcp" => Some(Transport::Tcp(TcpTransport::new(socket_addr))),
+            _ => None
+        }
+    }
+
+    /// Deserializes a `Transport` from a protobuf. Returns `None` if it's of a type we don't know
+    /// of, or its IP address is neither IPv4 nor IPv6.
+    fn deserialize(message: &messages::protobufs::Transport) -> Option<Transport> {
+        if !message.has_transport_type() {
+            return None
+        }
         match message.get_transport_type() {
             messages::protobufs::Transport_Type::UDP => {
                 let message = message.get_udp_transport();
-                let ip = message.get_ip_address();
-                let ip = Ipv4Addr::new(ip[0], ip[1], ip[2], ip[3]);
-                let port = message.get_port() as u16;
-                Transport::Udp(UdpTransport::new(SocketAddr::new(IpAddr::V4(ip), port)))
+                deserialize_socket_address(message.get_ip_address(), message.get_port())
+                    .map(|socket_address| Transport::Udp(UdpTransport::new(socket_address)))
+            }
+            messages::protobufs::Transport_Type::TCP => {
+                let message = message.get_tcp_transport();
+                deserialize_socket_address(message.get_ip_address(), message.get_port())
+                    .map(|socket_address| Transport::Tcp(TcpTransport::new(socket_address)))
+            }
+            messages::protobufs::Transport_Type::MEMORY => {
+                let message = message.get_memory_transport();
+                Some(Transport::Memory(MemoryTransport::new(message.get_name())))
+            }
+            messages::protobufs::Transport_Type::UNIX => {
+                let message = message.get_unix_transport();
+                Some(Transport::Unix(UnixTransport::new(message.get_path())))
+            }
+            messages::protobufs::Transport_Type::WEBSOCKET => {
+                let message = message.get_websocket_transport();
+                Some(Transport::WebSocket(WebSocketTransport::new(message.get_host(), message.get_path())))
+            }
+            messages::protobufs::Transport_Type::RELAYED => {
+                let relay = message.get_relayed_transport().get_relay();
+                let address = Address::from_wire(relay.get_id())?;
+                // Relays are only ever reached directly, so one relay can't point through another
+                let transports = relay.get_transports()
+                    .iter()
+                    .filter_map(|t| Transport::deserialize(t))
+                    .filter(|t| !t.is_relayed())
+                    .collect();
+                Some(Transport::Relayed(RelayedTransport::new(address, transports)))
             }
         }
     }
 
-    fn send(&self, message: Vec<u8>) {
+    /// Whether this transport goes through a relay rather than directly to the node.
+    pub fn is_relayed(&self) -> bool {
         match self {
-            Transport::Udp(transport) => transport.send(message)
+            Transport::Relayed(_) => true,
+            _ => false
+        }
+    }
+
+    /// Whether the transport sends datagrams, which is what holes are punched through NATs for.
+    /// In-memory transports stand in for UDP in tests, and can't reach outside the process.
+    pub fn is_datagram(&self) -> bool {
+        match self {
+            Transport::Udp(_) | Transport::Memory(_) => true,
+            _ => false
+        }
+    }
+
+    /// The IP address the transport reaches the node at, if it goes over IP directly.
+    pub fn ip_address(&self) -> Option<IpAddr> {
+        match self {
+            Transport::Udp(transport) => Some(transport.socket_address.ip()),
+            Transport::Tcp(transport) => Some(transport.socket_address.ip()),
+            _ => None
+        }
+    }
+
+    /// Sends `message` over the transport. A relayed transport also needs to know which node it
+    /// leads to, so messages for it have to go through `Node::send` instead.
+    pub fn send(&self, message: Vec<u8>, connections: &mut Connections) {
+        match self {
+            Transport::Udp(transport) => transport.send(message, connections),
+            Transport::Tcp(transport) => transport.send(message, connections),
+            Transport::Memory(transport) => transport.send(message),
+            Transport::Unix(transport) => transport.send(message, connections),
+            Transport::WebSocket(transport) => transport.send(message, connections),
+            Transport::Relayed(transport) => debug!("Can't send over {:?} without a target", transport)
         }
     }
 
     fn serialize(&self) -> messages::protobufs::Transport {
         match self {
-            Transport::Udp(transport) => transport.serialize()
+            Transport::Udp(transport) => transport.serialize(),
+            Transport::Tcp(transport) => transport.serialize(),
+            Transport::Memory(transport) => transport.serialize(),
+            Transport::Unix(transport) => transport.serialize(),
+            Transport::WebSocket(transport) => transport.serialize(),
+            Transport::Relayed(transport) => transport.serialize()
         }
     }
 }
 
-#[derive(Eq, Hash, PartialEq)]
+/// The octets of an IP address as they're carried in a transport protobuf: 4 bytes for IPv4, 16
+/// for IPv6.
+fn serialize_ip_address(ip_address: &IpAddr) -> Vec<u8> {
+    match *ip_address {
+        IpAddr::V4(ipv4_addr) => ipv4_addr.octets().to_vec(),
+        IpAddr::V6(ipv6_addr) => ipv6_addr.octets().to_vec()
+    }
+}
+
+/// The inverse of `serialize_ip_address`, with a port tacked on.
+fn deserialize_socket_address(ip_address: &[u8], port: u32) -> Option<SocketAddr> {
+    let ip_address = match ip_address.len() {
+        4 => {
+            let mut octets = [0; 4];
+            octets.copy_from_slice(ip_address);
+            IpAddr::V4(Ipv4Addr::from(octets))
+        }
+        16 => {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(ip_address);
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return None
+    };
+    Some(SocketAddr::new(ip_address, port as u16))
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
 pub struct UdpTransport {
     socket_address: SocketAddr
 }
@@ -72,21 +249,35 @@ impl UdpTransport {
         }
     }
 
-    fn send(&self, message: Vec<u8>) {
-        let socket = UdpSocket::bind("0.0.0.0:0").unwrap();
-        socket.send_to(&message[..], self.socket_address).unwrap();
+    /// Sends `message` from one of our bound server sockets, so that it originates from the port
+    /// we advertise. Only if we have no server of the right address family do we fall back to an
+    /// ephemeral socket.
+    fn send(&self, message: Vec<u8>, connections: &mut Connections) {
+        if let Some(socket) = connections.udp_socket_for(&self.socket_address) {
+            if let Err(error) = socket.send_to(&message[..], &self.socket_address) {
+                debug!("Couldn't send to {:?}: {}", self, error);
+            }
+            return
+        }
+
+        debug!("No bound socket to reach {:?}, sending from an ephemeral one", self);
+        let local_address = match self.socket_address {
+            SocketAddr::V4(_) => "0.0.0.0:0",
+            SocketAddr::V6(_) => "[::]:0"
+        };
+        match UdpSocket::bind(local_address) {
+            Ok(socket) => {
+                if let Err(error) = socket.send_to(&message[..], self.socket_address) {
+                    debug!("Couldn't send to {:?}: {}", self, error);
+                }
+            }
+            Err(error) => debug!("Couldn't bind socket to send to {:?}: {}", self, error)
+        }
     }
 
     fn serialize(&self) -> messages::protobufs::Transport {
         let mut transport = messages::protobufs::UdpTransport::new();
-        match self.socket_address.ip() {
-            IpAddr::V4(ipv4_addr) => {
-                transport.set_ip_address(ipv4_addr.octets().iter().cloned().collect());
-            }
-            IpAddr::V6(_) => {
-                // TODO ipv6 node support
-            }
-        }
+        transport.set_ip_address(serialize_ip_address(&self.socket_address.ip()));
         transport.set_port(self.socket_address.port() as u32);
         let mut message = messages::protobufs::Transport::new();
         message.set_transport_type(messages::protobufs::Transport_Type::UDP);
@@ -101,6 +292,249 @@ impl fmt::Debug for UdpTransport {
     }
 }
 
+/// A TCP connection to a node. Messages are written as length-prefixed frames over a connection
+/// that is kept open in `Connections` and reused for subsequent messages to the same node.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct TcpTransport {
+    socket_address: SocketAddr
+}
+
+impl TcpTransport {
+    pub fn new(socket_address: SocketAddr) -> TcpTransport {
+        TcpTransport {
+            socket_address: socket_address
+        }
+    }
+
+    fn send(&self, message: Vec<u8>, connections: &mut Connections) {
+        connections.send_tcp(&self.socket_address, message);
+    }
+
+    fn serialize(&self) -> messages::protobufs::Transport {
+        let mut transport = messages::protobufs::TcpTransport::new();
+        transport.set_ip_address(serialize_ip_address(&self.socket_address.ip()));
+        transport.set_port(self.socket_address.port() as u32);
+        let mut message = messages::protobufs::Transport::new();
+        message.set_transport_type(messages::protobufs::Transport_Type::TCP);
+        message.set_tcp_transport(transport);
+        message
+    }
+}
+
+impl fmt::Debug for TcpTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TcpTransport {{ {} }}", self.socket_address)
+    }
+}
+
+/// A `MemoryServer` in the same process, addressed by name. Useful for building networks of
+/// nodes in tests without touching real sockets.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct MemoryTransport {
+    name: String
+}
+
+impl MemoryTransport {
+    pub fn new(name: &str) -> MemoryTransport {
+        MemoryTransport {
+            name: name.to_string()
+        }
+    }
+
+    fn send(&self, message: Vec<u8>) {
+        if !servers::deliver_in_memory(&self.name, message) {
+            debug!("Couldn't send to {:?}: no such server", self);
+        }
+    }
+
+    fn serialize(&self) -> messages::protobufs::Transport {
+        let mut transport = messages::protobufs::MemoryTransport::new();
+        transport.set_name(self.name.clone());
+        let mut message = messages::protobufs::Transport::new();
+        message.set_transport_type(messages::protobufs::Transport_Type::MEMORY);
+        message.set_memory_transport(transport);
+        message
+    }
+}
+
+impl fmt::Debug for MemoryTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MemoryTransport {{ {} }}", self.name)
+    }
+}
+
+/// A `UnixServer` on the same host, addressed by the path of its datagram socket. Only processes
+/// allowed to write to the socket file can reach it.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct UnixTransport {
+    path: PathBuf
+}
+
+impl UnixTransport {
+    pub fn new<P: AsRef<Path>>(path: P) -> UnixTransport {
+        UnixTransport {
+            path: path.as_ref().to_path_buf()
+        }
+    }
+
+    fn send(&self, message: Vec<u8>, connections: &mut Connections) {
+        let result = connections.unix_socket()
+            .and_then(|socket| socket.send_to(&message, &self.path));
+        if let Err(error) = result {
+            debug!("Couldn't send to {:?}: {}", self, error);
+        }
+    }
+
+    fn serialize(&self) -> messages::protobufs::Transport {
+        let mut transport = messages::protobufs::UnixTransport::new();
+        transport.set_path(self.path.to_string_lossy().into_owned());
+        let mut message = messages::protobufs::Transport::new();
+        message.set_transport_type(messages::protobufs::Transport_Type::UNIX);
+        message.set_unix_transport(transport);
+        message
+    }
+}
+
+impl fmt::Debug for UnixTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UnixTransport {{ {} }}", self.path.display())
+    }
+}
+
+/// A WebSocket connection to a node's `WebSocketServer`, carrying each message as a binary frame.
+/// Like TCP connections, it's kept open in `Connections` for reuse. It gets through HTTP proxies
+/// and firewalls that only let web traffic out.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct WebSocketTransport {
+    host: String,
+    path: String
+}
+
+impl WebSocketTransport {
+    pub fn new(host: &str, path: &str) -> WebSocketTransport {
+        WebSocketTransport {
+            host: host.to_string(),
+            path: path.to_string()
+        }
+    }
+
+    fn send(&self, message: Vec<u8>, connections: &mut Connections) {
+        connections.send_websocket(&self.host, &self.path, message);
+    }
+
+    fn serialize(&self) -> messages::protobufs::Transport {
+        let mut transport = messages::protobufs::WebSocketTransport::new();
+        transport.set_host(self.host.clone());
+        transport.set_path(self.path.clone());
+        let mut message = messages::protobufs::Transport::new();
+        message.set_transport_type(messages::protobufs::Transport_Type::WEBSOCKET);
+        message.set_websocket_transport(transport);
+        message
+    }
+}
+
+impl fmt::Debug for WebSocketTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WebSocketTransport {{ ws://{}{} }}", self.host, self.path)
+    }
+}
+
+/// Reaches a node that can't be reached directly, such as one behind a symmetric NAT, by way of a
+/// relay it keeps registered with. Messages are wrapped in a `Relay` naming the node and sent to
+/// the relay, which forwards them on over the mapping the node keeps open to it.
+///
+/// A node has at most one relay, so relayed transports are told apart by relay alone.
+#[derive(Clone)]
+pub struct RelayedTransport {
+    relay: Address,
+    relay_transports: Vec<Transport>
+}
+
+impl RelayedTransport {
+    pub fn new(relay: Address, relay_transports: Vec<Transport>) -> RelayedTransport {
+        RelayedTransport {
+            relay: relay,
+            relay_transports: relay_transports
+        }
+    }
+
+    /// The address of the relay.
+    pub fn relay(&self) -> Address {
+        self.relay
+    }
+
+    fn send(&self, target: &Address, message: Vec<u8>, connections: &mut Connections) {
+        let message = messages::outgoing::create_relay(target, message);
+        for transport in &self.relay_transports {
+            transport.send(message.clone(), connections);
+        }
+    }
+
+    fn serialize(&self) -> messages::protobufs::Transport {
+        let mut relay = messages::protobufs::Node::new();
+        relay.set_id(self.relay.to_bytes().to_vec());
+        let transports = self.relay_transports.iter().map(|t| t.serialize()).collect();
+        relay.set_transports(protobuf::RepeatedField::from_vec(transports));
+        let mut transport = messages::protobufs::RelayedTransport::new();
+        transport.set_relay(relay);
+        let mut message = messages::protobufs::Transport::new();
+        message.set_transport_type(messages::protobufs::Transport_Type::RELAYED);
+        message.set_relayed_transport(transport);
+        message
+    }
+}
+
+impl PartialEq for RelayedTransport {
+    fn eq(&self, other: &Self) -> bool {
+        self.relay == other.relay
+    }
+}
+
+impl Eq for RelayedTransport { }
+
+impl Hash for RelayedTransport {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.relay.hash(state);
+    }
+}
+
+impl fmt::Debug for RelayedTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RelayedTransport {{ {}, {:?} }}", self.relay, self.relay_transports)
+    }
+}
+
+/// What we've seen of how well a transport reaches a node.
+#[derive(Clone, Debug, Default)]
+pub struct TransportHealth {
+    /// Round-trip time of the last query answered over the transport.
+    pub rtt: Option<time::Duration>,
+
+    /// When a query over the transport was last answered.
+    pub last_success: Option<time::Tm>,
+
+    /// How many queries over the transport have gone unanswered since the last one that was.
+    pub failures: usize
+}
+
+impl TransportHealth {
+    fn succeeded(&mut self, rtt: time::Duration) {
+        self.rtt = Some(rtt);
+        self.last_success = Some(time::now_utc());
+        self.failures = 0;
+    }
+
+    fn failed(&mut self) {
+        self.failures += 1;
+    }
+
+    /// Orders transports from healthiest to least healthy: fewest recent failures, then fastest,
+    /// with untried transports after those known to work.
+    fn rank(&self) -> (usize, bool, Option<time::Duration>) {
+        (self.failures, self.rtt.is_none(), self.rtt)
+    }
+}
+
 /// A `Node` is a peer in the network. It represents another network participant such as ourself.
 /// It has an address, and a means to be sent messages. When we receive messages, they come from
 /// other nodes.
@@ -117,38 +551,104 @@ impl fmt::Debug for UdpTransport {
 /// partitioned networks. If a subnetwork is connected via Bluetooth, and at least one participant
 /// has a connection to the larger network, all participants are thereby connected to the larger
 /// network.
+#[derive(Clone)]
 pub struct Node {
     address: Address,
-    transports: HashSet<Transport>,
+    public_key: Option<Vec<u8>>,
+    identity: Option<Identity>,
+    capabilities: Capabilities,
+    transports: HashMap<Transport, TransportHealth>,
     pending_queries: HashMap<TransactionId, time::Tm>,
+    query_transports: HashMap<TransactionId, Vec<Transport>>,
     has_ever_responded: bool,
     last_received_query: time::Tm,
     last_received_response: time::Tm,
+    nat_type: NatType
 }
 
 impl Node {
-    /// Deserialize a `Node` from a protobuf.
-    pub fn deserialize(message: &messages::protobufs::Node) -> Node {
-        let address = Address::from_str(message.get_id()).unwrap();
+    /// Deserialize a `Node` from a protobuf. Transports we can't make sense of are left out.
+    /// Returns `None` if the node's id isn't an address.
+    pub fn deserialize(message: &messages::protobufs::Node) -> Option<Node> {
+        let address = Address::from_wire(message.get_id())?;
         let transports = message.get_transports().
             iter().
-            map(|t| Transport::deserialize(t)).
+            filter_map(|t| Transport::deserialize(t)).
             collect();
-        Node::new(address, transports)
+        let mut node = Node::new(address, transports);
+        if message.has_public_key() {
+            node.public_key = Some(message.get_public_key().to_vec());
+        }
+        node.capabilities = Capabilities::from_bits(message.get_capabilities());
+        Some(node)
     }
 
     pub fn new(address: Address, transports: HashSet<Transport>) -> Node {
         Node {
             address: address,
-            transports: transports,
+            public_key: None,
+            identity: None,
+            capabilities: Capabilities::default(),
+            transports: transports.into_iter().map(|t| (t, TransportHealth::default())).collect(),
             pending_queries: HashMap::new(),
+            query_transports: HashMap::new(),
             has_ever_responded: false,
             last_received_query: time::now_utc(),
-            last_received_response: time::now_utc()
+            last_received_response: time::now_utc(),
+            nat_type: NatType::Unknown
         }
     }
 
-    fn status(&self) -> Status {
+    /// Our own node, whose address is derived from `identity` and which signs what it sends with
+    /// it.
+    pub fn for_identity(identity: Identity, transports: HashSet<Transport>) -> Node {
+        let mut node = Node::new(identity.address(), transports);
+        node.public_key = Some(identity.public_key().to_vec());
+        node.identity = Some(identity);
+        node
+    }
+
+    /// The node's Ed25519 public key, if it told us.
+    pub fn public_key(&self) -> Option<&[u8]> {
+        self.public_key.as_deref()
+    }
+
+    /// The key pair to sign messages with. Only our own node has one.
+    pub fn identity(&self) -> Option<&Identity> {
+        self.identity.as_ref()
+    }
+
+    /// The optional parts of the protocol the node supports. Queries that are one of them should
+    /// only be sent to nodes that support it.
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    /// Whether the node's address is the one derived from its public key. Only then can its
+    /// signatures vouch for its address.
+    pub fn has_verifiable_address(&self) -> bool {
+        match self.public_key {
+            Some(ref public_key) => Address::for_public_key(public_key) == self.address,
+            None => false
+        }
+    }
+
+    /// The kind of NAT the node is behind. Only known for our own node, which probes for it when
+    /// the network starts.
+    pub fn nat_type(&self) -> NatType {
+        self.nat_type
+    }
+
+    pub fn set_nat_type(&mut self, nat_type: NatType) {
+        self.nat_type = nat_type;
+    }
+
+    /// Whether the node is good, questionable or bad. See `is_bad` and `is_questionable`.
+    pub fn status(&self) -> Status {
         let time_since_last_seen = time::now_utc() - self.last_seen();
 
         if self.has_ever_responded &&
@@ -197,6 +697,24 @@ impl Node {
         self.pending_queries.len()
     }
 
+    /// Whether the node has ever answered one of our queries.
+    pub fn has_ever_responded(&self) -> bool {
+        self.has_ever_responded
+    }
+
+    /// Restores what we knew of the node before it was saved: when we last heard from it, and
+    /// whether it had ever answered us.
+    pub fn restore_history(&mut self, last_seen: time::Tm, has_ever_responded: bool) {
+        self.last_received_query = last_seen;
+        self.last_received_response = last_seen;
+        self.has_ever_responded = has_ever_responded;
+    }
+
+    /// Whether the query we sent with TID `transaction_id` is still unanswered.
+    pub fn is_awaiting_response(&self, transaction_id: TransactionId) -> bool {
+        self.pending_queries.contains_key(&transaction_id)
+    }
+
     /// Update the `last_received_query` timestamp for a node. Any time the network receives a
     /// query from a node, this method should be called, passing in the query's `TransactionId`.
     ///
@@ -215,6 +733,14 @@ impl Node {
         self.last_received_response = time::now_utc();
         if let Some(queried_at) = self.pending_queries.remove(&transaction_id) {
             self.has_ever_responded = true;
+            // When we didn't know which transport would work and sent over several, they all get
+            // the credit. Those that don't actually work soon fail on their own.
+            let rtt = time::now_utc() - queried_at;
+            for transport in self.query_transports.remove(&transaction_id).unwrap_or_default() {
+                if let Some(health) = self.transports.get_mut(&transport) {
+                    health.succeeded(rtt);
+                }
+            }
             debug!("Received response from {:?} for transaction {} in {}ms",
                    &self, &transaction_id, time::now_utc() - queried_at);
         } else {
@@ -225,36 +751,124 @@ impl Node {
     }
 
     /// Send an encoded message to a node via its transports. A node may have numerous transports
-    /// (aka connections).
+    /// (aka connections). Connection-oriented transports draw on `connections` for an open
+    /// connection to the node.
     ///
-    /// TODO: There should be some logic to determine which transports are still good.
-    pub fn send(&self, message: Vec<u8>) {
-        for transport in &self.transports {
-            transport.send(message.clone());
+    /// Once any transport is known to work, only the healthiest is used. Until then, the message
+    /// goes out over all of them.
+    pub fn send(&self, message: Vec<u8>, connections: &mut Connections) {
+        for transport in self.transports_to_send_over() {
+            self.send_over(transport, message.clone(), connections);
+        }
+    }
+
+    /// Sends a query to the node like `send`, and records that we're expecting a response for the
+    /// TID `transaction_id` over the transports it went out on.
+    pub fn send_query(&mut self, transaction_id: TransactionId, message: Vec<u8>, connections: &mut Connections) {
+        self.expire_queries();
+        self.sent_query(transaction_id);
+        let transports: Vec<Transport> = self.transports_to_send_over().into_iter().cloned().collect();
+        for transport in &transports {
+            self.send_over(transport, message.clone(), connections);
+        }
+        self.query_transports.insert(transaction_id, transports);
+    }
+
+    /// Sends `message` over `transport`, wrapping it for the relay to forward to us if it's
+    /// relayed.
+    fn send_over(&self, transport: &Transport, message: Vec<u8>, connections: &mut Connections) {
+        match transport {
+            Transport::Relayed(transport) => transport.send(&self.address, message, connections),
+            transport => transport.send(message, connections)
         }
     }
 
     /// Records that we're expecting a response from this node for the TID `transaction_id`.
-    ///
-    /// TODO: Perhaps this method should be rolled together with a method like `send_query` that
-    /// records the TID and calls `send`.
     pub fn sent_query(&mut self, transaction_id: TransactionId) {
         self.pending_queries.insert(transaction_id, time::now_utc());
     }
 
-    /// Update the socket address and port of a node. This is useful for when a node disconnects
-    /// and reconnects to the internet, or changes IP addresses, etc.
-    ///
-    /// In the future, a node will have n connections instead of one socket address, and we'd just
-    /// add another to the set, and keep track of which are good, eventually ejecting dead
-    /// connections.
-    ///
-    /// TODO: this is a temporary measure until a node can support multiple connections. Rather
-    /// than updating its only connection, it should just add another to the set and keep track of
-    /// the most reliable connection.
+    fn transports_to_send_over(&self) -> Vec<&Transport> {
+        let best = self.transports
+            .iter()
+            .filter(|&(_, health)| health.last_success.is_some())
+            .min_by_key(|&(_, health)| health.rank());
+        match best {
+            Some((transport, _)) => vec![transport],
+            None => self.transports.keys().collect()
+        }
+    }
+
+    /// Counts queries that have gone unanswered for too long as failures of the transports they
+    /// were sent over, and drops transports that keep failing.
+    fn expire_queries(&mut self) {
+        let now = time::now_utc();
+        let timeout = time::Duration::seconds(SECONDS_UNTIL_TRANSPORT_FAILURE);
+        let expired: Vec<TransactionId> = self.query_transports
+            .keys()
+            .filter(|t| self.pending_queries.get(t).map_or(true, |&sent| now - sent >= timeout))
+            .cloned()
+            .collect();
+        for transaction_id in expired {
+            for transport in self.query_transports.remove(&transaction_id).unwrap_or_default() {
+                if let Some(health) = self.transports.get_mut(&transport) {
+                    health.failed();
+                }
+            }
+        }
+
+        let failing: Vec<Transport> = self.transports
+            .iter()
+            .filter(|&(_, health)| health.failures >= TRANSPORT_FAILURE_THRESHOLD)
+            .map(|(transport, _)| transport.clone())
+            .collect();
+        for transport in failing {
+            if self.transports.len() > 1 {
+                debug!("Dropping failing transport {:?} of {}", &transport, &self.address);
+                self.transports.remove(&transport);
+            }
+        }
+    }
+
+    /// The transports we know of to reach the node.
+    pub fn transports(&self) -> hash_map::Keys<Transport, TransportHealth> {
+        self.transports.keys()
+    }
+
+    /// The IP addresses the node can be reached at directly.
+    pub fn ip_addresses(&self) -> Vec<IpAddr> {
+        let mut ip_addresses: Vec<IpAddr> = self.transports.keys().filter_map(|t| t.ip_address()).collect();
+        ip_addresses.sort();
+        ip_addresses.dedup();
+        ip_addresses
+    }
+
+    /// What we've seen of how well `transport` reaches the node.
+    pub fn transport_health(&self, transport: &Transport) -> Option<&TransportHealth> {
+        self.transports.get(transport)
+    }
+
+    /// Adds a transport we've found works for reaching the node, e.g. through a punched hole.
+    pub fn add_transport(&mut self, transport: Transport) {
+        self.transports.entry(transport).or_default();
+    }
+
+    /// Stops using `transport` to reach the node, e.g. because its relay has gone away.
+    pub fn remove_transport(&mut self, transport: &Transport) {
+        self.transports.remove(transport);
+    }
+
+    /// Adds any transports of `other_node` we didn't know of to this one. This is useful for when
+    /// a node disconnects and reconnects to the internet, or changes IP addresses, etc. Transports
+    /// that stop working are dropped as queries over them go unanswered. The capabilities it
+    /// advertises now replace the ones it did.
     pub fn update_connection(&mut self, other_node: Self) {
-        for transport in other_node.transports {
-            self.transports.insert(transport);
+        if self.public_key.is_none() && other_node.has_verifiable_address() {
+            self.public_key = other_node.public_key;
+        }
+        self.capabilities = other_node.capabilities;
+        for (transport, _) in other_node.transports {
+            self.add_transport(transport);
         }
     }
 }
@@ -268,8 +882,14 @@ impl PartialEq for Node {
 impl Serialize for Node {
     fn serialize(&self) -> messages::protobufs::Node {
         let mut message = messages::protobufs::Node::new();
-        message.set_id(self.address.to_str());
-        let transports = self.transports.iter().map(|t| t.serialize()).collect();
+        message.set_id(self.address.to_bytes().to_vec());
+        if let Some(ref public_key) = self.public_key {
+            message.set_public_key(public_key.clone());
+        }
+        if self.capabilities != Capabilities::default() {
+            message.set_capabilities(self.capabilities.bits());
+        }
+        let transports = self.transports.keys().map(|t| t.serialize()).collect();
         let transports = protobuf::RepeatedField::from_vec(transports);
         message.set_transports(transports);
         message
@@ -284,7 +904,8 @@ impl Addressable for Node {
 
 impl fmt::Debug for Node {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Node {{ {}, {:?} }}", self.address, self.transports)
+        let transports: Vec<&Transport> = self.transports.keys().collect();
+        write!(f, "Node {{ {}, {:?} }}", self.address, transports)
     }
 }
 
@@ -293,22 +914,29 @@ pub mod tests {
     use address::Address;
     use std::collections::{HashMap, HashSet};
     use std::net::ToSocketAddrs;
-    use super::{FAILED_TO_RESPOND_THRESHOLD, MINUTES_UNTIL_QUESTIONABLE, Node, Serialize, Transport, UdpTransport};
+    use servers::Connections;
+    use stun::NatType;
+    use super::{FAILED_TO_RESPOND_THRESHOLD, MINUTES_UNTIL_QUESTIONABLE, SECONDS_UNTIL_TRANSPORT_FAILURE, TRANSPORT_FAILURE_THRESHOLD, Capabilities, MemoryTransport, Node, RelayedTransport, Serialize, TcpTransport, Transport, TransportHealth, UdpTransport, UnixTransport, WebSocketTransport};
     use time;
     use transaction::TransactionId;
 
     pub fn new(address: Address, last_received_response: time::Tm, pending_queries: HashMap<TransactionId, time::Tm>) -> Node {
         use rand::{thread_rng, Rng};
         let port = thread_rng().gen_range(1000, 10000);
-        let mut transports = HashSet::new();
-        transports.insert(Transport::Udp(UdpTransport::new(("0.0.0.0", port).to_socket_addrs().unwrap().next().unwrap())));
+        let mut transports = HashMap::new();
+        transports.insert(Transport::Udp(UdpTransport::new(("0.0.0.0", port).to_socket_addrs().unwrap().next().unwrap())), TransportHealth::default());
         Node {
             address: address,
+            public_key: None,
+            identity: None,
+            capabilities: Capabilities::default(),
             transports: transports,
             pending_queries: pending_queries,
+            query_transports: HashMap::new(),
             has_ever_responded: false,
             last_received_query: time::empty_tm(),
-            last_received_response: last_received_response
+            last_received_response: last_received_response,
+            nat_type: NatType::Unknown
         }
     }
 
@@ -365,7 +993,8 @@ pub mod tests {
         udp_transport_message.set_port(9000);
         transport_message.set_transport_type(messages::protobufs::Transport_Type::UDP);
         transport_message.set_udp_transport(udp_transport_message);
-        node_message.set_id("8b45e4bd1c6acb88bebf6407d16205f567e62a3e".to_string());
+        node_message.set_id(vec![0x8b, 0x45, 0xe4, 0xbd, 0x1c, 0x6a, 0xcb, 0x88, 0xbe, 0xbf,
+                                 0x64, 0x07, 0xd1, 0x62, 0x05, 0xf5, 0x67, 0xe6, 0x2a, 0x3e]);
         node_message.set_transports(protobuf::RepeatedField::from_vec(vec![transport_message]));
 
         let address = Address::for_content("some string");
@@ -375,4 +1004,267 @@ pub mod tests {
         let node = Node::new(address, transports);
         assert_eq!(node.serialize(), node_message);
     }
+
+    #[test]
+    fn test_serialize_capabilities() {
+        let mut node = Node::new(Address::for_content("some string"), HashSet::new());
+        assert!(!node.capabilities().contains(Capabilities::RENDEZVOUS));
+
+        // Bits from later versions are passed on as they are
+        let mut capabilities = Capabilities::from_bits(1 << 40);
+        capabilities.insert(Capabilities::RELAY);
+        node.set_capabilities(capabilities);
+        let node = Node::deserialize(&node.serialize()).unwrap();
+        assert!(node.capabilities().contains(Capabilities::RELAY));
+        assert!(!node.capabilities().contains(Capabilities::RENDEZVOUS));
+        assert_eq!(node.capabilities().bits(), (1 << 40) | Capabilities::RELAY.bits());
+    }
+
+    #[test]
+    fn test_serialize_tcp_transport() {
+        use messages;
+        let mut transport_message = messages::protobufs::Transport::new();
+        let mut tcp_transport_message = messages::protobufs::TcpTransport::new();
+        tcp_transport_message.set_ip_address(vec![192, 168, 1, 2]);
+        tcp_transport_message.set_port(9000);
+        transport_message.set_transport_type(messages::protobufs::Transport_Type::TCP);
+        transport_message.set_tcp_transport(tcp_transport_message);
+
+        let socket_address = ("192.168.1.2", 9000).to_socket_addrs().unwrap().next().unwrap();
+        let transport = Transport::Tcp(TcpTransport::new(socket_address));
+        assert_eq!(transport.serialize(), transport_message);
+        assert_eq!(Transport::deserialize(&transport_message), Some(transport));
+    }
+
+    #[test]
+    fn test_create_transport() {
+        let socket_address = ("192.168.1.2", 9000).to_socket_addrs().unwrap().next().unwrap();
+        assert_eq!(Transport::create("tcp://192.168.1.2:9000"),
+                   Some(Transport::Tcp(TcpTransport::new(socket_address))));
+        assert_eq!(Transport::create("udp://192.168.1.2:9000"),
+                   Some(Transport::Udp(UdpTransport::new(socket_address))));
+        assert_eq!(Transport::create("192.168.1.2:9000"),
+                   Some(Transport::Udp(UdpTransport::new(socket_address))));
+        assert_eq!(Transport::create("memory://alpha"),
+                   Some(Transport::Memory(MemoryTransport::new("alpha"))));
+        assert_eq!(Transport::create("carrier-pigeon://192.168.1.2:9000"), None);
+    }
+
+    #[test]
+    fn test_udp_send_from_bound_socket() {
+        use mio;
+        use servers::Connections;
+        use std::net::UdpSocket;
+
+        let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let bound = mio::udp::UdpSocket::bound(&"127.0.0.1:0".parse().unwrap()).unwrap();
+        let bound_address = bound.local_addr().unwrap();
+        let mut connections = Connections::new();
+        connections.add_udp_socket(bound);
+
+        let transport = Transport::Udp(UdpTransport::new(receiver.local_addr().unwrap()));
+        transport.send(b"hello".to_vec(), &mut connections);
+
+        let mut buf = [0; 16];
+        let (size, source) = receiver.recv_from(&mut buf).unwrap();
+        assert_eq!(&buf[..size], b"hello");
+        assert_eq!(source, bound_address);
+    }
+
+    #[test]
+    fn test_serialize_ipv6_transport() {
+        use messages;
+        let mut transport_message = messages::protobufs::Transport::new();
+        let mut udp_transport_message = messages::protobufs::UdpTransport::new();
+        udp_transport_message.set_ip_address(vec![0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        udp_transport_message.set_port(9000);
+        transport_message.set_transport_type(messages::protobufs::Transport_Type::UDP);
+        transport_message.set_udp_transport(udp_transport_message);
+
+        let socket_address = ("2001:db8::1", 9000).to_socket_addrs().unwrap().next().unwrap();
+        let transport = Transport::Udp(UdpTransport::new(socket_address));
+        assert_eq!(transport.serialize(), transport_message);
+        assert_eq!(Transport::deserialize(&transport_message), Some(transport));
+    }
+
+    #[test]
+    fn test_deserialize_dual_stack_node() {
+        let v4_address = ("192.168.1.2", 9000).to_socket_addrs().unwrap().next().unwrap();
+        let v6_address = ("2001:db8::1", 9000).to_socket_addrs().unwrap().next().unwrap();
+        let mut transports = HashSet::new();
+        transports.insert(Transport::Udp(UdpTransport::new(v4_address)));
+        transports.insert(Transport::Udp(UdpTransport::new(v6_address)));
+        let node = Node::new(Address::for_content("some string"), transports);
+
+        let deserialized = Node::deserialize(&node.serialize()).unwrap();
+        assert_eq!(deserialized.transports.len(), 2);
+        assert!(deserialized.transports.contains_key(&Transport::Udp(UdpTransport::new(v4_address))));
+        assert!(deserialized.transports.contains_key(&Transport::Udp(UdpTransport::new(v6_address))));
+    }
+
+    #[test]
+    fn test_deserialize_invalid_ip_address() {
+        use messages;
+        let mut transport_message = messages::protobufs::Transport::new();
+        let mut udp_transport_message = messages::protobufs::UdpTransport::new();
+        udp_transport_message.set_ip_address(vec![192, 168]);
+        udp_transport_message.set_port(9000);
+        transport_message.set_transport_type(messages::protobufs::Transport_Type::UDP);
+        transport_message.set_udp_transport(udp_transport_message);
+        assert_eq!(Transport::deserialize(&transport_message), None);
+    }
+
+    #[test]
+    fn test_deserialize_unknown_transport_type() {
+        use messages;
+        use protobuf;
+        use protobuf::Message;
+        let socket_address = ("192.168.1.2", 9000).to_socket_addrs().unwrap().next().unwrap();
+        let mut transports = HashSet::new();
+        transports.insert(Transport::Udp(UdpTransport::new(socket_address)));
+        let node = Node::new(Address::for_content("some string"), transports);
+        let mut bytes = node.serialize().write_to_bytes().unwrap();
+        // A transport of type 99, as a later version might send
+        bytes.extend_from_slice(&[0x12, 0x02, 0x08, 0x63]);
+
+        let message = protobuf::parse_from_bytes::<messages::protobufs::Node>(&bytes).unwrap();
+        assert_eq!(message.get_transports().len(), 2);
+        let deserialized = Node::deserialize(&message).unwrap();
+        assert_eq!(deserialized.transports.len(), 1);
+        assert!(deserialized.transports.contains_key(&Transport::Udp(UdpTransport::new(socket_address))));
+    }
+
+    #[test]
+    fn test_deserialize_invalid_address() {
+        let mut message = Node::new(Address::for_content("some string"), HashSet::new()).serialize();
+        message.set_id(b"not hexadecimal".to_vec());
+        assert!(Node::deserialize(&message).is_none());
+    }
+
+    #[test]
+    fn test_deserialize_hexadecimal_address() {
+        let address = Address::for_content("some string");
+        let mut message = Node::new(address, HashSet::new()).serialize();
+        // As nodes that haven't switched to binary addresses send them
+        message.set_id(address.to_str().into_bytes());
+        assert_eq!(Node::deserialize(&message).unwrap().address, address);
+    }
+
+    #[test]
+    fn test_serialize_memory_transport() {
+        let transport = Transport::Memory(MemoryTransport::new("alpha"));
+        assert_eq!(Transport::deserialize(&transport.serialize()), Some(transport));
+    }
+
+    #[test]
+    fn test_serialize_unix_transport() {
+        let transport = Transport::create("unix:///run/comm.sock").unwrap();
+        assert_eq!(transport, Transport::Unix(UnixTransport::new("/run/comm.sock")));
+        assert_eq!(Transport::deserialize(&transport.serialize()), Some(transport));
+    }
+
+    #[test]
+    fn test_serialize_websocket_transport() {
+        let transport = Transport::create("ws://comm.example.com:80/comm").unwrap();
+        assert_eq!(transport, Transport::WebSocket(WebSocketTransport::new("comm.example.com:80", "/comm")));
+        assert_eq!(Transport::deserialize(&transport.serialize()), Some(transport));
+        assert_eq!(Transport::create("ws://comm.example.com:80"),
+                   Some(Transport::WebSocket(WebSocketTransport::new("comm.example.com:80", "/"))));
+    }
+
+    #[test]
+    fn test_serialize_relayed_transport() {
+        let relay_transports = vec![Transport::create("tcp://203.0.113.5:6667").unwrap()];
+        let transport = Transport::Relayed(RelayedTransport::new(Address::for_content("relay"), relay_transports));
+        match Transport::deserialize(&transport.serialize()) {
+            Some(Transport::Relayed(deserialized)) => {
+                assert_eq!(deserialized.relay(), Address::for_content("relay"));
+                assert_eq!(deserialized.relay_transports, vec![Transport::create("tcp://203.0.113.5:6667").unwrap()]);
+            }
+            _ => assert!(false)
+        }
+
+        // A relay can't itself be reached through a relay
+        let transport = Transport::Relayed(RelayedTransport::new(Address::for_content("relay"), vec![transport]));
+        match Transport::deserialize(&transport.serialize()) {
+            Some(Transport::Relayed(deserialized)) => assert!(deserialized.relay_transports.is_empty()),
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn test_send_over_relayed_transport() {
+        use messages::incoming::{self, Message};
+        use std::io::Cursor;
+        use std::net::UdpSocket;
+
+        let relay = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let relay_transports = vec![Transport::Udp(UdpTransport::new(relay.local_addr().unwrap()))];
+        let mut transports = HashSet::new();
+        transports.insert(Transport::Relayed(RelayedTransport::new(Address::for_content("relay"), relay_transports)));
+        let node = Node::new(Address::for_content("target"), transports);
+        node.send(b"hello".to_vec(), &mut Connections::new());
+
+        let mut buf = [0; 256];
+        let size = relay.recv(&mut buf).unwrap();
+        match incoming::parse_from_reader(&mut Cursor::new(&buf[..size])) {
+            Ok(Message::Relay(target, message)) => {
+                assert_eq!(target, Address::for_content("target"));
+                assert_eq!(message, b"hello".to_vec());
+            }
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn test_send_over_healthiest_transport() {
+        let alpha = Transport::create("memory://test_send_over_healthiest_transport_alpha").unwrap();
+        let beta = Transport::create("memory://test_send_over_healthiest_transport_beta").unwrap();
+        let mut transports = HashSet::new();
+        transports.insert(alpha.clone());
+        transports.insert(beta.clone());
+        let mut node = Node::new(Address::for_content("node"), transports);
+
+        // Nothing is known to work yet, so everything is tried, and credited with the response
+        let mut connections = Connections::new();
+        node.send_query(1, b"query".to_vec(), &mut connections);
+        assert_eq!(node.transports_to_send_over().len(), 2);
+        node.received_response(1);
+        assert!(node.transport_health(&alpha).unwrap().last_success.is_some());
+        assert!(node.transport_health(&beta).unwrap().last_success.is_some());
+
+        node.transports.get_mut(&alpha).unwrap().rtt = Some(time::Duration::milliseconds(50));
+        node.transports.get_mut(&beta).unwrap().rtt = Some(time::Duration::milliseconds(10));
+        assert_eq!(node.transports_to_send_over(), vec![&beta]);
+
+        node.transports.get_mut(&beta).unwrap().failures = 1;
+        assert_eq!(node.transports_to_send_over(), vec![&alpha]);
+    }
+
+    #[test]
+    fn test_failing_transports_expire() {
+        let alpha = Transport::create("memory://test_failing_transports_expire_alpha").unwrap();
+        let beta = Transport::create("memory://test_failing_transports_expire_beta").unwrap();
+        let mut transports = HashSet::new();
+        transports.insert(alpha.clone());
+        transports.insert(beta.clone());
+        let mut node = Node::new(Address::for_content("node"), transports);
+
+        let long_ago = time::now_utc() - time::Duration::seconds(SECONDS_UNTIL_TRANSPORT_FAILURE);
+        for transaction_id in 0..TRANSPORT_FAILURE_THRESHOLD as TransactionId {
+            node.pending_queries.insert(transaction_id, long_ago);
+            node.query_transports.insert(transaction_id, vec![alpha.clone()]);
+        }
+        node.expire_queries();
+        assert!(node.transport_health(&alpha).is_none());
+        assert_eq!(node.transport_health(&beta).unwrap().failures, 0);
+
+        // The last transport is kept no matter what
+        for transaction_id in 0..TRANSPORT_FAILURE_THRESHOLD as TransactionId {
+            node.pending_queries.insert(transaction_id, long_ago);
+            node.query_transports.insert(transaction_id, vec![beta.clone()]);
+        }
+        node.expire_queries();
+        assert_eq!(node.transport_health(&beta).unwrap().failures, TRANSPORT_FAILURE_THRESHOLD);
+    }
 }
@@ -1,19 +1,24 @@
-use address::{LENGTH, Addressable, Address};
+use address::{LENGTH, Addressable, Address, Distance};
 use node::Node;
 use num::bigint::{BigUint, ToBigUint};
 use std::cmp;
 use std::collections::HashMap;
 use std::fmt;
 use time;
+use transaction::TransactionId;
 use num;
 
 const MINUTES_UNTIL_NEEDS_REFRESH: i64 = 15;
 
+/// How long the least recently seen node in a full bucket has to answer a ping before it's
+/// replaced by a node from the replacement cache.
+const SECONDS_TO_ANSWER_EVICTION_PING: i64 = 5;
+
 #[derive(Debug, PartialEq)]
 pub enum InsertOutcome {
     Inserted,   // Inserted new node
     Updated,    // Updated existing node
-    Discarded   // Bucket is full (disregard might be a better name)
+    Cached      // Bucket is full, so kept as a replacement
 }
 
 pub type InsertionResult = Result<InsertOutcome, String>;
@@ -22,8 +27,12 @@ pub struct NodeBucket {
     k: usize,
     min: BigUint,
     max: BigUint,
+    prefix: Address,
+    depth: usize,
     addresses: Vec<Address>,
     nodes: HashMap<Address, Node>,
+    replacements: Vec<Node>,
+    eviction_ping: Option<(Address, TransactionId, time::Tm)>,
     last_inserted: time::Tm
 }
 
@@ -42,12 +51,45 @@ impl NodeBucket {
             k: k,
             min: min,
             max: max,
+            prefix: Address::null(),
+            depth: 0,
             addresses: Vec::with_capacity(k),
             nodes: HashMap::with_capacity(k),
+            replacements: Vec::with_capacity(k),
+            eviction_ping: None,
             last_inserted: time::empty_tm()
         }
     }
 
+    /// An empty bucket for the space of addresses sharing `prefix`'s leading `depth` bits, such
+    /// as one restored from a saved routing table.
+    pub fn with_space(k: usize, prefix: Address, depth: usize) -> NodeBucket {
+        let min = prefix.as_numeric();
+        let max = &min + num::pow(2.to_biguint().unwrap(), LENGTH - depth);
+        NodeBucket {
+            k: k,
+            min: min,
+            max: max,
+            prefix: prefix,
+            depth: depth,
+            addresses: Vec::with_capacity(k),
+            nodes: HashMap::with_capacity(k),
+            replacements: Vec::with_capacity(k),
+            eviction_ping: None,
+            last_inserted: time::empty_tm()
+        }
+    }
+
+    /// The lowest address in the bucket's space.
+    pub fn prefix(&self) -> Address {
+        self.prefix
+    }
+
+    /// How many leading bits the addresses in the bucket's space share.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
     pub fn any_bad_nodes(&self) -> bool {
         self.nodes.iter().any(|(_, node)| node.is_bad())
     }
@@ -56,15 +98,29 @@ impl NodeBucket {
         self.addresses.contains(address)
     }
 
+    /// Whether `address` falls within the bucket's space. Since buckets only ever come from
+    /// halving the whole address space, that's whether it shares the bucket's leading `depth`
+    /// bits.
     pub fn covers(&self, address: &Address) -> bool {
-        let numeric = address.as_numeric();
-        self.min <= numeric && numeric < self.max
+        self.prefix.xor(address).leading(self.depth).is_zero()
+    }
+
+    /// The lowest distance from `address` of any address within the bucket's space. The spaces of
+    /// different buckets lie at ranges of distance from `address` that don't overlap, so every
+    /// node in a bucket with a lower `min_distance_to` is nearer than any in one with a higher.
+    pub fn min_distance_to(&self, address: &Address) -> Distance {
+        self.prefix.xor(address).leading(self.depth)
     }
 
     pub fn find_node(&mut self, address: &Address) -> Option<&mut Node> {
         self.nodes.get_mut(address)
     }
 
+    /// The bucket's nodes, most recently inserted or updated first.
+    pub fn nodes(&self) -> Vec<&Node> {
+        self.addresses.iter().map(|a| &self.nodes[a]).collect()
+    }
+
     pub fn get_nodes(&mut self) -> Vec<&mut Node> {
         self.nodes.iter_mut().map(|(_, node)| node).collect()
     }
@@ -97,14 +153,77 @@ impl NodeBucket {
                 self.remove_worst_node();
                 self.insert(node)
             } else {
-                debug!("Discarded node {:?}", &node);
-                Ok(InsertOutcome::Discarded)
+                debug!("Cached node {:?}", &node);
+                self.cache(node);
+                Ok(InsertOutcome::Cached)
             }
         } else {
             Err(format!("Bucket {:?}  does not cover {:?}", self, address))
         }
     }
 
+    /// Keeps `node` as a candidate to replace one that stops responding. The most recently seen
+    /// candidates are kept, up to `k` of them.
+    fn cache(&mut self, node: Node) {
+        let address = node.address();
+        self.replacements.retain(|n| n.address() != address);
+        self.replacements.insert(0, node);
+        self.replacements.truncate(self.k);
+    }
+
+    pub fn replacements(&self) -> &[Node] {
+        &self.replacements
+    }
+
+    /// Takes the most recently seen replacement out of the cache, for the routing table to fill
+    /// room in the bucket with.
+    pub fn take_replacement(&mut self) -> Option<Node> {
+        if self.replacements.is_empty() {
+            None
+        } else {
+            Some(self.replacements.remove(0))
+        }
+    }
+
+    /// The node to ping to find out whether it should make way for a replacement: the least
+    /// recently seen one, unless there are no replacements or we're already waiting on a ping.
+    pub fn eviction_candidate(&self) -> Option<Address> {
+        if self.eviction_ping.is_some() || self.replacements.is_empty() {
+            return None;
+        }
+        self.nodes
+            .values()
+            .min_by_key(|n| n.last_seen())
+            .map(|n| n.address())
+    }
+
+    /// Records that we pinged the node at `address` with TID `transaction_id` to find out whether
+    /// it should be evicted.
+    pub fn sent_eviction_ping(&mut self, address: Address, transaction_id: TransactionId) {
+        self.eviction_ping = Some((address, transaction_id, time::now_utc()));
+    }
+
+    /// Settles the eviction ping in flight, if any. A node that answered keeps its place, while
+    /// one that didn't in time is evicted to make room for a replacement. Returns whether one was.
+    pub fn expire_eviction_ping(&mut self) -> bool {
+        let (address, transaction_id, sent_at) = match self.eviction_ping {
+            Some(eviction_ping) => eviction_ping,
+            None => return false
+        };
+        let answered = self.nodes
+            .get(&address)
+            .map_or(true, |n| !n.is_awaiting_response(transaction_id));
+        if answered {
+            self.eviction_ping = None;
+        } else if time::now_utc() - sent_at >= time::Duration::seconds(SECONDS_TO_ANSWER_EVICTION_PING) {
+            self.eviction_ping = None;
+            self.remove(&address);
+            debug!("Evicted unresponsive node {:?}", &address);
+            return true
+        }
+        false
+    }
+
     pub fn is_full(&self) -> bool {
         self.nodes.len() >= self.k
     }
@@ -160,29 +279,47 @@ impl NodeBucket {
             }
         }
 
-        let a = NodeBucket {
+        let mut a = NodeBucket {
             k: self.k,
             min: self.min,
             max: partition.clone(),
+            prefix: self.prefix,
+            depth: self.depth + 1,
             addresses: a_addresses,
             nodes: a_nodes,
+            replacements: Vec::with_capacity(self.k),
+            eviction_ping: None,
             last_inserted: self.last_inserted
         };
-        let b = NodeBucket {
+        let mut b = NodeBucket {
             k: self.k,
+            prefix: Address::from_numeric(partition.clone()),
+            depth: self.depth + 1,
             min: partition,
             max: self.max,
             addresses: b_addresses,
             nodes: b_nodes,
+            replacements: Vec::with_capacity(self.k),
+            eviction_ping: None,
             last_inserted: self.last_inserted
         };
+
+        // Each half may now have room for the replacements that fall in it, which is up to the
+        // routing table to fill
+        for replacement in self.replacements.into_iter().rev() {
+            if a.covers(&replacement.address()) {
+                a.cache(replacement);
+            } else {
+                b.cache(replacement);
+            }
+        }
         (a, b)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use address::{Addressable, Address};
+    use address::{Addressable, Address, LENGTH};
     use node;
     use super::{InsertOutcome, NodeBucket};
     use time;
@@ -219,9 +356,53 @@ mod tests {
         bucket.insert(node::tests::good(Address::for_content("node 1"))).unwrap();
         bucket.insert(node::tests::good(Address::for_content("node 2"))).unwrap();
         let result = bucket.insert(node::tests::good(Address::for_content("node 3"))).unwrap();
-        assert_eq!(result, InsertOutcome::Discarded);
+        assert_eq!(result, InsertOutcome::Cached);
         assert_eq!(bucket.addresses.len(), 2);
         assert_eq!(bucket.nodes.get(&Address::for_content("node 3")), None);
+        assert_eq!(bucket.replacements()[0].address(), Address::for_content("node 3"));
+    }
+
+    #[test]
+    fn test_evicts_node_that_does_not_answer_ping() {
+        let mut bucket: NodeBucket = NodeBucket::new(2);
+        bucket.insert(node::tests::questionable(Address::for_content("node 1"))).unwrap();
+        bucket.insert(node::tests::good(Address::for_content("node 2"))).unwrap();
+        assert_eq!(bucket.eviction_candidate(), None);
+        bucket.insert(node::tests::good(Address::for_content("node 3"))).unwrap();
+
+        // Pings the least recently seen node, one at a time
+        let address = bucket.eviction_candidate().unwrap();
+        assert_eq!(address, Address::for_content("node 1"));
+        bucket.find_node(&address).unwrap().sent_query(7);
+        bucket.sent_eviction_ping(address, 7);
+        assert_eq!(bucket.eviction_candidate(), None);
+
+        // Gives it time to answer
+        assert!(!bucket.expire_eviction_ping());
+        assert!(bucket.contains(&address));
+
+        bucket.eviction_ping = Some((address, 7, time::now_utc() - time::Duration::seconds(60)));
+        assert!(bucket.expire_eviction_ping());
+        assert!(!bucket.contains(&address));
+        assert!(!bucket.is_full());
+        assert_eq!(bucket.take_replacement().unwrap().address(), Address::for_content("node 3"));
+        assert!(bucket.take_replacement().is_none());
+    }
+
+    #[test]
+    fn test_keeps_node_that_answers_ping() {
+        let mut bucket: NodeBucket = NodeBucket::new(1);
+        bucket.insert(node::tests::questionable(Address::for_content("node 1"))).unwrap();
+        bucket.insert(node::tests::good(Address::for_content("node 2"))).unwrap();
+        let address = bucket.eviction_candidate().unwrap();
+        bucket.find_node(&address).unwrap().sent_query(7);
+        bucket.eviction_ping = Some((address, 7, time::now_utc() - time::Duration::seconds(60)));
+        bucket.find_node(&address).unwrap().received_response(7);
+
+        assert!(!bucket.expire_eviction_ping());
+        assert!(bucket.contains(&address));
+        assert_eq!(bucket.replacements().len(), 1);
+        assert_eq!(bucket.eviction_candidate(), Some(address));
     }
 
     #[test]
@@ -246,8 +427,12 @@ mod tests {
             k: 8,
             min: 0.to_biguint().unwrap(),
             max: 1.to_biguint().unwrap(),
+            prefix: Address::null(),
+            depth: LENGTH,
             addresses: vec![],
             nodes: HashMap::new(),
+            replacements: vec![],
+            eviction_ping: None,
             last_inserted: time::empty_tm()
         };
         let result = bucket.insert(node::tests::good(Address::for_content("node 3")));
@@ -321,6 +506,33 @@ mod tests {
         assert!(b.covers(&Address::from_str("ffffffffffffffffffffffffffffffffffffffff").unwrap()));
     }
 
+    #[test]
+    fn test_with_space() {
+        let (_, b) = NodeBucket::new(4).split();
+        let (b_a, _) = b.split();
+        let bucket = NodeBucket::with_space(4, b_a.prefix(), b_a.depth());
+        assert_eq!(bucket.min, b_a.min);
+        assert_eq!(bucket.max, b_a.max);
+        assert!(bucket.covers(&Address::from_str("8000000000000000000000000000000000000000").unwrap()));
+        assert!(bucket.covers(&Address::from_str("bfffffffffffffffffffffffffffffffffffffff").unwrap()));
+        assert!(!bucket.covers(&Address::from_str("c000000000000000000000000000000000000000").unwrap()));
+    }
+
+    #[test]
+    fn test_min_distance_to() {
+        let bucket: NodeBucket = NodeBucket::new(4);
+        let (a, b) = bucket.split();
+        let (b_a, b_b) = b.split();
+        let target = Address::from_str("c000000000000000000000000000000000000001").unwrap();
+
+        // The bucket covering the target is nearest, and buckets get further as they diverge
+        assert!(b_b.min_distance_to(&target).is_zero());
+        assert!(b_b.min_distance_to(&target) < b_a.min_distance_to(&target));
+        assert!(b_a.min_distance_to(&target) < a.min_distance_to(&target));
+        let nearest_in_a = Address::from_str("4000000000000000000000000000000000000001").unwrap();
+        assert_eq!(a.min_distance_to(&target), nearest_in_a.xor(&target));
+    }
+
     #[test]
     fn test_last_changed() {
         let mut bucket: NodeBucket = NodeBucket::new(4);
@@ -0,0 +1,151 @@
+use std::collections::{HashMap, HashSet};
+use transaction::TransactionId;
+
+/// Identifies an `Operation`. They're drawn from the same generator as transaction IDs, so an
+/// operation's ID never clashes with a transaction's.
+pub type OperationId = TransactionId;
+
+/// Something we do that takes many transactions, such as a lookup sending find node queries, or a
+/// health check pinging every questionable node.
+///
+/// An operation owns its transactions, each of which its owner expires if it isn't answered in
+/// time. It's settled once every one of them has either been answered or expired, which is when
+/// most operations are over. `state` is what the operation is for and whatever it keeps track of along
+/// the way, which its owner goes by to decide what to do once it's over.
+pub struct Operation<T> {
+    pub state: T,
+    pending: HashSet<TransactionId>,
+    answered: usize,
+    expired: usize
+}
+
+impl<T> Operation<T> {
+    /// Whether none of the operation's transactions are still waiting on an answer.
+    pub fn is_settled(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// How many of the operation's transactions have been answered.
+    pub fn answered(&self) -> usize {
+        self.answered
+    }
+
+    /// How many of the operation's transactions went unanswered.
+    pub fn expired(&self) -> usize {
+        self.expired
+    }
+}
+
+/// Keeps track of the operations under way, and of which one each transaction belongs to.
+pub struct Operations<T> {
+    operations: HashMap<OperationId, Operation<T>>,
+    transactions: HashMap<TransactionId, OperationId>
+}
+
+impl<T> Operations<T> {
+    pub fn new() -> Operations<T> {
+        Operations {
+            operations: HashMap::new(),
+            transactions: HashMap::new()
+        }
+    }
+
+    /// Starts the operation `id`, with no transactions yet.
+    pub fn start(&mut self, id: OperationId, state: T) {
+        self.operations.insert(id, Operation {
+            state: state,
+            pending: HashSet::new(),
+            answered: 0,
+            expired: 0
+        });
+    }
+
+    /// Adds `transaction_id` to the operation `id`. Does nothing if there's no such operation.
+    pub fn add_transaction(&mut self, id: OperationId, transaction_id: TransactionId) {
+        if let Some(operation) = self.operations.get_mut(&id) {
+            operation.pending.insert(transaction_id);
+            self.transactions.insert(transaction_id, id);
+        }
+    }
+
+    pub fn get(&self, id: &OperationId) -> Option<&Operation<T>> {
+        self.operations.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: &OperationId) -> Option<&mut Operation<T>> {
+        self.operations.get_mut(id)
+    }
+
+    /// Records the answer to `transaction_id`, and returns the operation it belongs to. Returns
+    /// `None` if it isn't waiting on an answer, e.g. because it already expired.
+    pub fn answered(&mut self, transaction_id: &TransactionId) -> Option<OperationId> {
+        let id = self.transactions.remove(transaction_id)?;
+        let operation = self.operations.get_mut(&id)?;
+        operation.pending.remove(transaction_id);
+        operation.answered += 1;
+        Some(id)
+    }
+
+    /// Records that `transaction_id` went unanswered for too long, and returns the operation
+    /// it belongs to. Returns `None` if it isn't waiting on an answer, e.g. because it was
+    /// answered in time.
+    pub fn expired(&mut self, transaction_id: &TransactionId) -> Option<OperationId> {
+        let id = self.transactions.remove(transaction_id)?;
+        let operation = self.operations.get_mut(&id)?;
+        operation.pending.remove(transaction_id);
+        operation.expired += 1;
+        Some(id)
+    }
+
+    /// Ends the operation `id`, whether it's settled or not. Answers to any of its transactions
+    /// still pending are of no more use, and are ignored from now on.
+    pub fn finish(&mut self, id: &OperationId) -> Option<Operation<T>> {
+        let operation = self.operations.remove(id)?;
+        for transaction_id in &operation.pending {
+            self.transactions.remove(transaction_id);
+        }
+        Some(operation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Operations;
+
+    #[test]
+    fn test_settles_once_every_transaction_is_answered_or_expired() {
+        let mut operations = Operations::new();
+        operations.start(1, "health check");
+        operations.add_transaction(1, 2);
+        operations.add_transaction(1, 3);
+        assert!(!operations.get(&1).unwrap().is_settled());
+
+        assert_eq!(operations.answered(&2), Some(1));
+        assert!(!operations.get(&1).unwrap().is_settled());
+        assert_eq!(operations.expired(&3), Some(1));
+        let operation = operations.get(&1).unwrap();
+        assert!(operation.is_settled());
+        assert_eq!((operation.answered(), operation.expired()), (1, 1));
+
+        // Each transaction is only settled once
+        assert_eq!(operations.answered(&3), None);
+        assert_eq!(operations.expired(&2), None);
+    }
+
+    #[test]
+    fn test_finish_drops_pending_transactions() {
+        let mut operations = Operations::new();
+        operations.start(1, "lookup");
+        operations.add_transaction(1, 2);
+        operations.add_transaction(3, 4);
+
+        let operation = operations.finish(&1).unwrap();
+        assert_eq!(operation.state, "lookup");
+        assert!(!operation.is_settled());
+        assert_eq!(operations.answered(&2), None);
+        assert!(operations.get(&1).is_none());
+
+        // There was no operation 3 to add a transaction to
+        assert_eq!(operations.expired(&4), None);
+    }
+}
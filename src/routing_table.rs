@@ -1,7 +1,20 @@
 use node_bucket;
 use node_bucket::NodeBucket;
-use address::{Addressable, Address, LENGTH};
-use node::Node;
+use address::{Addressable, Address, Distance, LENGTH};
+use messages::protobufs;
+use node::{Node, Serialize};
+use num;
+use num::bigint::ToBigUint;
+use protobuf;
+use protobuf::Message;
+use servers::Connections;
+use snapshot::{BucketSnapshot, NodeSnapshot, RoutingTableSnapshot, TransportSnapshot};
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use std::str::FromStr;
+use time;
 use transaction::TransactionIdGenerator;
 
 #[derive(Debug, PartialEq)]
@@ -9,17 +22,185 @@ pub enum InsertOutcome {
     Ignored,    // Currently just for ignoring self-node
     Inserted,   // Inserted new node
     Updated,    // Updated existing node
-    Discarded   // Bucket is full
+    Cached,     // Bucket is full, so kept as a replacement
+    Rejected(Rejection)
 }
 
 pub type InsertionResult = Result<InsertOutcome, String>;
 
+/// Why `RoutingTable::insert` turned a node away: where it would have gone, there are already as
+/// many nodes sharing its IP address or subnet as `DiversityLimits` allow.
+#[derive(Debug, PartialEq)]
+pub enum Rejection {
+    Bucket(SharedNetwork),
+    Table(SharedNetwork)
+}
+
+/// What a rejected node shares with too many others.
+#[derive(Debug, PartialEq)]
+pub enum SharedNetwork {
+    IpAddress(IpAddr),
+    /// A /24 or /64, given as its first address.
+    Subnet(IpAddr)
+}
+
+/// How many nodes may share one IP address, and how many one subnet: a /24 for IPv4, or a /64
+/// for IPv6.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IpLimits {
+    pub per_ip_address: usize,
+    pub per_subnet: usize
+}
+
+impl IpLimits {
+    /// What `node` would share with more of `others` than allowed, if anything.
+    fn exceeded_by(&self, node: &Node, others: &[&Node]) -> Option<SharedNetwork> {
+        for ip_address in limited_ip_addresses(node) {
+            let sharing = others.iter()
+                .filter(|other| limited_ip_addresses(other).contains(&ip_address))
+                .count();
+            if sharing >= self.per_ip_address {
+                return Some(SharedNetwork::IpAddress(ip_address));
+            }
+            let subnet = subnet_of(&ip_address);
+            let sharing = others.iter()
+                .filter(|other| limited_ip_addresses(other).iter().any(|ip| subnet_of(ip) == subnet))
+                .count();
+            if sharing >= self.per_subnet {
+                return Some(SharedNetwork::Subnet(subnet));
+            }
+        }
+        None
+    }
+}
+
+impl FromStr for IpLimits {
+    type Err = String;
+
+    /// Parses limits given as `PER_IP_ADDRESS,PER_SUBNET`, e.g. `2,4`.
+    fn from_str(s: &str) -> Result<IpLimits, String> {
+        let limits: Vec<&str> = s.split(',').collect();
+        if limits.len() != 2 {
+            return Err(format!("Expected PER_IP_ADDRESS,PER_SUBNET, got {}", s));
+        }
+        let per_ip_address = limits[0].parse().map_err(|_| format!("Invalid limit {}", limits[0]))?;
+        let per_subnet = limits[1].parse().map_err(|_| format!("Invalid limit {}", limits[1]))?;
+        Ok(IpLimits {
+            per_ip_address: per_ip_address,
+            per_subnet: per_subnet
+        })
+    }
+}
+
+/// Limits on how many nodes sharing an IP address or subnet each bucket, and the table as a
+/// whole, may hold. Without them, one host could fill every bucket by minting addresses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiversityLimits {
+    pub bucket: IpLimits,
+    pub table: IpLimits
+}
+
+impl Default for DiversityLimits {
+    fn default() -> DiversityLimits {
+        DiversityLimits {
+            bucket: IpLimits {
+                per_ip_address: 2,
+                per_subnet: 4
+            },
+            table: IpLimits {
+                per_ip_address: 8,
+                per_subnet: 16
+            }
+        }
+    }
+}
+
+/// The IP addresses of `node` that count towards `DiversityLimits`. Loopback and unspecified
+/// addresses don't, since they say nothing about which host a node is on.
+fn limited_ip_addresses(node: &Node) -> Vec<IpAddr> {
+    node.ip_addresses()
+        .into_iter()
+        .filter(|ip| !ip.is_loopback() && !ip.is_unspecified())
+        .collect()
+}
+
+/// The first address of the /24 or /64 that `ip_address` is in.
+fn subnet_of(ip_address: &IpAddr) -> IpAddr {
+    match *ip_address {
+        IpAddr::V4(ip) => {
+            let octets = ip.octets();
+            IpAddr::V4(Ipv4Addr::new(octets[0], octets[1], octets[2], 0))
+        }
+        IpAddr::V6(ip) => {
+            let segments = ip.segments();
+            IpAddr::V6(Ipv6Addr::new(segments[0], segments[1], segments[2], segments[3], 0, 0, 0, 0))
+        }
+    }
+}
+
+/// A `RoutingTable` saved to be restored later, so that a restarted node can rejoin the network
+/// without bootstrapping from scratch, even if its routers are gone.
+pub struct SavedRoutingTable {
+    message: protobufs::SavedRoutingTable
+}
+
+impl SavedRoutingTable {
+    /// Reads a table saved with `write_to_file`. Fails if the file can't be read, or doesn't hold
+    /// buckets that together cover the whole address space exactly once.
+    pub fn read_from_file<P: AsRef<Path>>(path: P) -> io::Result<SavedRoutingTable> {
+        let mut file = fs::File::open(path)?;
+        let message = protobuf::parse_from_reader::<protobufs::SavedRoutingTable>(&mut file)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let saved = SavedRoutingTable {
+            message: message
+        };
+        saved.validate().map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(saved)
+    }
+
+    /// Writes the table to `path`, replacing whatever was there only once it's all written.
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let partial = path.with_extension("partial");
+        {
+            let mut file = fs::File::create(&partial)?;
+            self.message.write_to_writer(&mut file)
+                .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+            file.sync_all()?;
+        }
+        fs::rename(partial, path)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.message.get_k() == 0 {
+            return Err("k must be at least 1".to_string());
+        }
+        // Buckets are saved in the order of their spaces, which must follow on from one another
+        let mut next = 0.to_biguint().unwrap();
+        for bucket in self.message.get_buckets() {
+            let prefix = Address::from_wire(bucket.get_prefix())
+                .ok_or_else(|| format!("Invalid bucket prefix {:?}", bucket.get_prefix()))?;
+            let depth = bucket.get_depth() as usize;
+            if depth > LENGTH || prefix.as_numeric() != next {
+                return Err(format!("Bucket {}/{} doesn't follow on from the one before it",
+                                   prefix.to_str(), depth));
+            }
+            next += num::pow(2.to_biguint().unwrap(), LENGTH - depth);
+        }
+        if next != num::pow(2.to_biguint().unwrap(), LENGTH) {
+            return Err("Buckets don't cover the whole address space".to_string());
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct RoutingTable {
     k: usize,
     self_address: Address,
     routers: Vec<Node>,
-    buckets: Vec<NodeBucket>
+    buckets: Vec<NodeBucket>,
+    limits: DiversityLimits
 }
 
 impl RoutingTable {
@@ -29,13 +210,122 @@ impl RoutingTable {
             k: k,
             self_address: self_address,
             routers: routers,
-            buckets: vec![bucket]
+            buckets: vec![bucket],
+            limits: DiversityLimits::default()
+        }
+    }
+
+    /// Rebuilds a table from `saved`, with its buckets covering the same spaces and holding the
+    /// same nodes, which are as good or questionable as they were when it was saved.
+    pub fn restore(saved: SavedRoutingTable, self_address: Address, routers: Vec<Node>) -> RoutingTable {
+        let k = saved.message.get_k() as usize;
+        let buckets = saved.message.get_buckets()
+            .iter()
+            .map(|saved_bucket| {
+                let prefix = Address::from_wire(saved_bucket.get_prefix()).unwrap();
+                let mut bucket = NodeBucket::with_space(k, prefix, saved_bucket.get_depth() as usize);
+                // Oldest first, so they end up in the order they were saved in
+                for saved_node in saved_bucket.get_nodes().iter().rev() {
+                    let mut node = match Node::deserialize(saved_node.get_node()) {
+                        Some(node) => node,
+                        None => continue
+                    };
+                    let last_seen = time::at_utc(time::Timespec::new(saved_node.get_last_seen(), 0));
+                    node.restore_history(last_seen, saved_node.get_has_responded());
+                    if node.address() == self_address {
+                        continue
+                    }
+                    if let Err(error) = bucket.insert(node) {
+                        debug!("Couldn't restore node: {}", error);
+                    }
+                }
+                bucket
+            })
+            .collect();
+        RoutingTable {
+            k: k,
+            self_address: self_address,
+            routers: routers,
+            buckets: buckets,
+            limits: DiversityLimits::default()
+        }
+    }
+
+    /// Limits how many nodes sharing an IP address or subnet are let into the table from now on.
+    pub fn set_diversity_limits(&mut self, limits: DiversityLimits) {
+        self.limits = limits;
+    }
+
+    /// Saves the table's buckets and, for each of their nodes, its transports and when we last
+    /// heard from it.
+    pub fn save(&self) -> SavedRoutingTable {
+        let buckets: Vec<protobufs::SavedBucket> = self.buckets
+            .iter()
+            .map(|bucket| {
+                let nodes: Vec<protobufs::SavedNode> = bucket.nodes()
+                    .iter()
+                    .map(|node| {
+                        let mut saved_node = protobufs::SavedNode::new();
+                        saved_node.set_node(node.serialize());
+                        saved_node.set_last_seen(node.last_seen().to_timespec().sec);
+                        saved_node.set_has_responded(node.has_ever_responded());
+                        saved_node
+                    })
+                    .collect();
+                let mut saved_bucket = protobufs::SavedBucket::new();
+                saved_bucket.set_prefix(bucket.prefix().to_bytes().to_vec());
+                saved_bucket.set_depth(bucket.depth() as u32);
+                saved_bucket.set_nodes(protobuf::RepeatedField::from_vec(nodes));
+                saved_bucket
+            })
+            .collect();
+        let mut message = protobufs::SavedRoutingTable::new();
+        message.set_k(self.k as u32);
+        message.set_buckets(protobuf::RepeatedField::from_vec(buckets));
+        SavedRoutingTable {
+            message: message
+        }
+    }
+
+    /// A copy of what the table knows right now, for looking into.
+    pub fn snapshot(&self) -> RoutingTableSnapshot {
+        let buckets = self.buckets
+            .iter()
+            .map(|bucket| BucketSnapshot {
+                prefix: bucket.prefix(),
+                depth: bucket.depth(),
+                replacements: bucket.replacements().len(),
+                nodes: bucket.nodes()
+                    .iter()
+                    .map(|node| NodeSnapshot {
+                        address: node.address(),
+                        status: node.status(),
+                        pending_queries: node.pending_query_count(),
+                        last_seen: node.last_seen(),
+                        transports: node.transports()
+                            .map(|transport| {
+                                let health = node.transport_health(transport).unwrap();
+                                TransportSnapshot {
+                                    transport: format!("{:?}", transport),
+                                    rtt: health.rtt,
+                                    failures: health.failures
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+        RoutingTableSnapshot {
+            self_address: self.self_address,
+            k: self.k,
+            buckets: buckets
         }
     }
 
     // TODO: i don't like how much this function has to know about sending pings
     pub fn insert(&mut self, node: Node, self_node: &Node, transaction_ids:
-                  &mut TransactionIdGenerator) -> InsertionResult {
+                  &mut TransactionIdGenerator, connections: &mut Connections) -> InsertionResult {
         use messages::outgoing;
 
         if node.address() == self.self_address {
@@ -51,21 +341,29 @@ impl RoutingTable {
             let (a, b) = bucket.split();
             self.buckets.insert(index, a);
             self.buckets.insert(index + 1, b);
-            self.insert(node, self_node, transaction_ids)
+            self.promote_replacements(index);
+            self.promote_replacements(index + 1);
+            self.insert(node, self_node, transaction_ids, connections)
+        } else if let Some(rejection) = self.diversity_rejection(&node, &bucket) {
+            debug!("Rejected {:?}: {:?}", &node, &rejection);
+            self.buckets.insert(index, bucket);
+            Ok(InsertOutcome::Rejected(rejection))
         } else {
             let status = match bucket.insert(node) {
                 Ok(node_bucket::InsertOutcome::Inserted) => Ok(InsertOutcome::Inserted),
                 Ok(node_bucket::InsertOutcome::Updated) => Ok(InsertOutcome::Updated),
-                Ok(node_bucket::InsertOutcome::Discarded) => {
-                    for n in bucket.questionable_nodes() {
+                Ok(node_bucket::InsertOutcome::Cached) => {
+                    // Find out whether the least recently seen node should make way
+                    if let Some(address) = bucket.eviction_candidate() {
                         let transaction_id = transaction_ids.generate();
                         let query = outgoing::create_ping_query(
                             transaction_id, self_node);
-                        n.sent_query(transaction_id);
-                        n.send(query);
-                        debug!("Pinged questionable node {:?}", &n);
+                        let n = bucket.find_node(&address).unwrap();
+                        n.send_query(transaction_id, query, connections);
+                        debug!("Pinged least recently seen node {:?}", &n);
+                        bucket.sent_eviction_ping(address, transaction_id);
                     }
-                    Ok(InsertOutcome::Discarded)
+                    Ok(InsertOutcome::Cached)
                 }
                 Err(error) => Err(error)
             };
@@ -74,6 +372,55 @@ impl RoutingTable {
         }
     }
 
+    /// Why `node` mustn't join `bucket`, which has been taken out of the table to insert it into,
+    /// if it mustn't. Nodes already in the bucket are always let back in.
+    fn diversity_rejection(&self, node: &Node, bucket: &NodeBucket) -> Option<Rejection> {
+        let address = node.address();
+        let in_bucket = bucket.nodes();
+        if in_bucket.iter().any(|n| n.address() == address) {
+            return None;
+        }
+        if let Some(shared) = self.limits.bucket.exceeded_by(node, &in_bucket) {
+            return Some(Rejection::Bucket(shared));
+        }
+        let in_table: Vec<&Node> = self.buckets
+            .iter()
+            .flat_map(|b| b.nodes())
+            .chain(in_bucket)
+            .collect();
+        self.limits.table.exceeded_by(node, &in_table).map(Rejection::Table)
+    }
+
+    /// Fills any room in the bucket at `index` with its most recently seen replacements, as far as
+    /// `DiversityLimits` allow. They were let in as replacements, but nodes sharing their networks
+    /// may have joined the table since, so those turned away now are dropped.
+    fn promote_replacements(&mut self, index: usize) {
+        let mut bucket = self.buckets.remove(index);
+        while !bucket.is_full() {
+            let replacement = match bucket.take_replacement() {
+                Some(replacement) => replacement,
+                None => break
+            };
+            match self.diversity_rejection(&replacement, &bucket) {
+                Some(rejection) => debug!("Dropped replacement {:?}: {:?}", &replacement, &rejection),
+                None => {
+                    bucket.insert(replacement).unwrap();
+                }
+            }
+        }
+        self.buckets.insert(index, bucket);
+    }
+
+    /// The nodes we were given to bootstrap through, whose addresses we don't know.
+    pub fn routers(&self) -> &[Node] {
+        &self.routers
+    }
+
+    /// How many nodes a bucket holds, which is also how many nodes a lookup looks for.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
     pub fn bucket_needing_refresh(&self) -> Option<&NodeBucket> {
         use rand::{thread_rng, Rng};
         let mut buckets: Vec<&NodeBucket>= self.buckets.iter().filter(|ref b| b.needs_refresh()).collect();
@@ -97,16 +444,35 @@ impl RoutingTable {
         self.nearest_live_nodes_to(&self_address, true)
     }
 
+    /// The `k` nodes nearest to `address` that aren't bad, nearest first.
+    ///
+    /// Buckets are walked outward from the one covering `address`, in order of how near their
+    /// space is to it, stopping once there are `k` nodes. Since the spaces of buckets lie at
+    /// distances that don't overlap, only the nodes within each bucket need sorting.
     pub fn nearest_live_nodes_to(&mut self, address: &Address, include_routers: bool) -> Vec<&mut Node> {
-        // TODO: this should walk buckets much more efficiently
-
-        let mut candidates: Vec<&mut Node> = self.buckets
-            .iter_mut()
-            .flat_map(|b| b.get_nodes())
-            .filter(|n| !n.is_bad())
+        let mut order: Vec<(Distance, usize)> = self.buckets
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (b.min_distance_to(address), i))
             .collect();
+        order.sort();
 
-        candidates.sort_by_key(|n| n.address().distance_from(address));
+        let mut buckets: Vec<Option<&mut NodeBucket>> = self.buckets.iter_mut().map(Some).collect();
+        let mut candidates: Vec<&mut Node> = Vec::with_capacity(self.k);
+        for (_, i) in order {
+            if candidates.len() >= self.k {
+                break
+            }
+            let mut nodes: Vec<&mut Node> = buckets[i]
+                .take()
+                .unwrap()
+                .get_nodes()
+                .into_iter()
+                .filter(|n| !n.is_bad())
+                .collect();
+            nodes.sort_by_key(|n| n.address().xor(address));
+            candidates.extend(nodes);
+        }
 
         // chain on routers in case we don't have enough nodes yet
         if include_routers {
@@ -116,13 +482,20 @@ impl RoutingTable {
         }
     }
 
-    pub fn questionable_nodes(&mut self) -> Vec<&mut Node> {
-        // TODO: this should walk buckets much more efficiently
+    /// Evicts nodes that didn't answer the pings sent when their buckets were full, in favour of
+    /// replacements.
+    pub fn expire_eviction_pings(&mut self) {
+        for index in 0..self.buckets.len() {
+            if self.buckets[index].expire_eviction_ping() {
+                self.promote_replacements(index);
+            }
+        }
+    }
 
+    pub fn questionable_nodes(&mut self) -> Vec<&mut Node> {
         self.buckets
             .iter_mut()
-            .flat_map(|b| b.get_nodes())
-            .filter(|n| n.is_questionable())
+            .flat_map(|b| b.questionable_nodes())
             .collect()
     }
 
@@ -143,62 +516,172 @@ impl RoutingTable {
 #[cfg(test)]
 mod tests {
     use address::{Addressable, Address};
-    use super::{InsertOutcome, RoutingTable};
+    use super::{DiversityLimits, InsertOutcome, IpLimits, Rejection, RoutingTable, SavedRoutingTable, SharedNetwork};
     use node;
+    use servers::Connections;
+    use std::collections::HashSet;
     use transaction::TransactionIdGenerator;
 
+    /// A node at `address` reachable over UDP at `ip_address`.
+    fn node_at(address: &str, ip_address: &str) -> node::Node {
+        let mut transports = HashSet::new();
+        transports.insert(node::Transport::create(&format!("udp://{}:6667", ip_address)).unwrap());
+        node::Node::new(Address::from_str(address).unwrap(), transports)
+    }
+
     #[test]
     fn test_insert() {
         let self_address = Address::from_str("0000000000000000000000000000000000000000").unwrap();
         let self_node: node::Node = node::tests::good(self_address);
         let mut transaction_ids = TransactionIdGenerator::new();
+        let mut connections = Connections::new();
         let router = node::tests::good(Address::null());
         let mut table: RoutingTable = RoutingTable::new(2, self_address, vec![router]);
         let node_1 = node::tests::good(Address::from_str("0000000000000000000000000000000000000001").unwrap());
         let node_2 = node::tests::good(Address::from_str("ffffffffffffffffffffffffffffffffffffffff").unwrap());
-        table.insert(node_1, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_2, &self_node, &mut transaction_ids).unwrap();
+        table.insert(node_1, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_2, &self_node, &mut transaction_ids, &mut connections).unwrap();
         assert_eq!(table.buckets.len(), 1);
 
         // Splits buckets upon adding a k+1th node in the same space as self node
         let node_3 = node::tests::good(Address::from_str("fffffffffffffffffffffffffffffffffffffffe").unwrap());
-        table.insert(node_3, &self_node, &mut transaction_ids).unwrap();
+        table.insert(node_3, &self_node, &mut transaction_ids, &mut connections).unwrap();
         assert_eq!(table.buckets.len(), 2);
         let node_4 = node::tests::good(Address::from_str("7fffffffffffffffffffffffffffffffffffffff").unwrap());
         let node_5 = node::tests::good(Address::from_str("7ffffffffffffffffffffffffffffffffffffffe").unwrap());
-        table.insert(node_4, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_5, &self_node, &mut transaction_ids).unwrap();
+        table.insert(node_4, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_5, &self_node, &mut transaction_ids, &mut connections).unwrap();
         assert_eq!(table.buckets.len(), 3);
 
         // Replaces instead of duplicates existing nodes
         let node_6 = node::tests::good(Address::from_str("0000000000000000000000000000000000000001").unwrap());
         let node_7 = node::tests::good(Address::from_str("0000000000000000000000000000000000000001").unwrap());
         let node_8 = node::tests::good(Address::from_str("0000000000000000000000000000000000000001").unwrap());
-        table.insert(node_6, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_7, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_8, &self_node, &mut transaction_ids).unwrap();
+        table.insert(node_6, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_7, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_8, &self_node, &mut transaction_ids, &mut connections).unwrap();
         assert_eq!(table.buckets.len(), 3);
 
         // Disregards new nodes for full, non-self space buckets
         let node_9 = node::tests::good(Address::from_str("fffffffffffffffffffffffffffffffffffffffd").unwrap());
         let node_10 = node::tests::good(Address::from_str("fffffffffffffffffffffffffffffffffffffffc").unwrap());
         let node_11 = node::tests::good(Address::from_str("fffffffffffffffffffffffffffffffffffffffb").unwrap());
-        table.insert(node_9, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_10, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_11, &self_node, &mut transaction_ids).unwrap();
+        table.insert(node_9, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_10, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_11, &self_node, &mut transaction_ids, &mut connections).unwrap();
         assert_eq!(table.buckets.len(), 3);
 
         // Ignores self-node
         let node_12 = node::tests::good(self_address);
-        assert_eq!(table.insert(node_12, &self_node, &mut transaction_ids).unwrap(), InsertOutcome::Ignored);
+        assert_eq!(table.insert(node_12, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Ignored);
         assert_eq!(table.buckets.len(), 3);
     }
 
+    #[test]
+    fn test_insert_limits_nodes_sharing_ip_addresses() {
+        let self_address = Address::null();
+        let self_node = node::tests::good(self_address);
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut connections = Connections::new();
+        let mut table = RoutingTable::new(8, self_address, vec![]);
+        table.set_diversity_limits(DiversityLimits {
+            bucket: IpLimits { per_ip_address: 1, per_subnet: 2 },
+            table: IpLimits { per_ip_address: 8, per_subnet: 8 }
+        });
+
+        let node_1 = node_at("0000000000000000000000000000000000000001", "203.0.113.5");
+        let node_2 = node_at("0000000000000000000000000000000000000002", "203.0.113.5");
+        let node_3 = node_at("0000000000000000000000000000000000000003", "203.0.113.6");
+        let node_4 = node_at("0000000000000000000000000000000000000004", "203.0.113.7");
+        let node_5 = node_at("0000000000000000000000000000000000000005", "198.51.100.1");
+        assert_eq!(table.insert(node_1, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+        assert_eq!(table.insert(node_2, &self_node, &mut transaction_ids, &mut connections).unwrap(),
+                   InsertOutcome::Rejected(Rejection::Bucket(SharedNetwork::IpAddress("203.0.113.5".parse().unwrap()))));
+        assert_eq!(table.insert(node_3, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+        assert_eq!(table.insert(node_4, &self_node, &mut transaction_ids, &mut connections).unwrap(),
+                   InsertOutcome::Rejected(Rejection::Bucket(SharedNetwork::Subnet("203.0.113.0".parse().unwrap()))));
+        assert_eq!(table.insert(node_5, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+
+        // Nodes already in the table are let back in
+        let node_1 = node_at("0000000000000000000000000000000000000001", "203.0.113.5");
+        assert_eq!(table.insert(node_1, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Updated);
+
+        // Loopback and unspecified addresses aren't limited
+        for (i, ip_address) in ["127.0.0.1", "127.0.0.1", "0.0.0.0", "0.0.0.0"].iter().enumerate() {
+            let node = node_at(&format!("{:040x}", i + 6), ip_address);
+            assert_eq!(table.insert(node, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+        }
+    }
+
+    #[test]
+    fn test_insert_limits_nodes_sharing_ip_addresses_across_buckets() {
+        let self_address = Address::null();
+        let self_node = node::tests::good(self_address);
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut connections = Connections::new();
+        let mut table = RoutingTable::new(1, self_address, vec![]);
+        table.set_diversity_limits(DiversityLimits {
+            bucket: IpLimits { per_ip_address: 8, per_subnet: 8 },
+            table: IpLimits { per_ip_address: 2, per_subnet: 8 }
+        });
+
+        // Each lands in a bucket of its own
+        let node_1 = node_at("8000000000000000000000000000000000000000", "2001:db8::1");
+        let node_2 = node_at("4000000000000000000000000000000000000000", "2001:db8::1");
+        let node_3 = node_at("2000000000000000000000000000000000000000", "2001:db8::1");
+        assert_eq!(table.insert(node_1, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+        assert_eq!(table.insert(node_2, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+        assert_eq!(table.buckets.len(), 2);
+        assert_eq!(table.insert(node_3, &self_node, &mut transaction_ids, &mut connections).unwrap(),
+                   InsertOutcome::Rejected(Rejection::Table(SharedNetwork::IpAddress("2001:db8::1".parse().unwrap()))));
+    }
+
+    #[test]
+    fn test_promoted_replacements_are_limited() {
+        let self_address = Address::null();
+        let self_node = node::tests::good(self_address);
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut connections = Connections::new();
+        let mut table = RoutingTable::new(1, self_address, vec![]);
+        table.set_diversity_limits(DiversityLimits {
+            bucket: IpLimits { per_ip_address: 8, per_subnet: 8 },
+            table: IpLimits { per_ip_address: 1, per_subnet: 8 }
+        });
+
+        // The second is kept as a replacement, after which a node sharing its IP address joins
+        let node_1 = node_at("8000000000000000000000000000000000000000", "203.0.113.5");
+        let node_2 = node_at("c000000000000000000000000000000000000000", "198.51.100.1");
+        let node_3 = node_at("4000000000000000000000000000000000000000", "198.51.100.1");
+        let replacement = node_2.address();
+        assert_eq!(table.insert(node_1, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+        assert_eq!(table.insert(node_2, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Cached);
+        assert_eq!(table.buckets[1].replacements().len(), 1);
+        assert_eq!(table.insert(node_3, &self_node, &mut transaction_ids, &mut connections).unwrap(), InsertOutcome::Inserted);
+
+        // So once the first goes bad and makes room, the replacement isn't let in
+        let address = Address::from_str("8000000000000000000000000000000000000000").unwrap();
+        for transaction_id in 0..5 {
+            table.find_node(&address).unwrap().sent_query(transaction_id);
+        }
+        table.buckets[1].remove_worst_node();
+        table.promote_replacements(1);
+        assert!(!table.buckets[1].contains(&replacement));
+        assert!(table.buckets[1].replacements().is_empty());
+    }
+
+    #[test]
+    fn test_parse_ip_limits() {
+        assert_eq!("2,4".parse(), Ok(IpLimits { per_ip_address: 2, per_subnet: 4 }));
+        assert!("2".parse::<IpLimits>().is_err());
+        assert!("2,four".parse::<IpLimits>().is_err());
+    }
+
     #[test]
     fn test_nearest_live_node_to() {
         let self_address = Address::from_str("0000000000000000000000000000000000000000").unwrap();
         let self_node: node::Node = node::tests::good(self_address);
         let mut transaction_ids = TransactionIdGenerator::new();
+        let mut connections = Connections::new();
         let router = node::tests::good(Address::null());
         let mut table: RoutingTable = RoutingTable::new(2, self_address, vec![router]);
         let addr_1 = Address::from_str("0000000000000000000000000000000000000001").unwrap();
@@ -207,9 +690,9 @@ mod tests {
         let node_1 = node::tests::good(addr_1);
         let node_2 = node::tests::good(addr_2);
         let node_3 = node::tests::good(addr_3);
-        table.insert(node_1, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_2, &self_node, &mut transaction_ids).unwrap();
-        table.insert(node_3, &self_node, &mut transaction_ids).unwrap();
+        table.insert(node_1, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_2, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        table.insert(node_3, &self_node, &mut transaction_ids, &mut connections).unwrap();
 
         {
             let nearest = table.nearest_live_nodes_to(&Address::from_str("fffffffffffffffffffffffffffffffffffffffd").unwrap(), false);
@@ -222,4 +705,100 @@ mod tests {
             assert_eq!(nearest[1].address(), addr_2);
         }
     }
+
+    #[test]
+    fn test_nearest_live_nodes_to_matches_sorting_all_live_nodes() {
+        let self_node: node::Node = node::tests::good(Address::for_content("self"));
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut connections = Connections::new();
+        let mut table: RoutingTable = RoutingTable::new(4, self_node.address(), vec![]);
+        for i in 0..500 {
+            let node = node::tests::good(Address::for_content(&format!("node {}", i)));
+            table.insert(node, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        }
+        assert!(table.buckets.len() > 4);
+
+        let mut live_nodes: Vec<Address> = table.buckets
+            .iter_mut()
+            .flat_map(|b| b.get_nodes())
+            .filter(|n| !n.is_bad())
+            .map(|n| n.address())
+            .collect();
+        for i in 0..50 {
+            let target = Address::for_content(&format!("target {}", i));
+            live_nodes.sort_by_key(|a| a.as_numeric() ^ target.as_numeric());
+            let nearest: Vec<Address> = table.nearest_live_nodes_to(&target, false)
+                .iter()
+                .map(|n| n.address())
+                .collect();
+            assert_eq!(nearest, &live_nodes[..4]);
+        }
+    }
+
+    #[test]
+    fn test_save_and_restore() {
+        use node::Transport;
+        use std::collections::HashSet;
+        use std::env;
+        use std::fs;
+        use std::process;
+
+        let self_node: node::Node = node::tests::good(Address::for_content("self"));
+        let mut transaction_ids = TransactionIdGenerator::new();
+        let mut connections = Connections::new();
+        let mut table: RoutingTable = RoutingTable::new(4, self_node.address(), vec![]);
+        // Into the empty table first, so that it's sure to have a place
+        let stale = Address::for_content("stale node");
+        table.insert(node::tests::questionable(stale), &self_node, &mut transaction_ids, &mut connections).unwrap();
+        for i in 0..40 {
+            let node = node::tests::good(Address::for_content(&format!("node {}", i)));
+            table.insert(node, &self_node, &mut transaction_ids, &mut connections).unwrap();
+        }
+
+        let path = env::temp_dir().join(format!("comm-test-routing-table-{}", process::id()));
+        table.save().write_to_file(&path).unwrap();
+        let saved = SavedRoutingTable::read_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let mut restored = RoutingTable::restore(saved, self_node.address(), vec![]);
+
+        assert_eq!(restored.k, table.k);
+        assert_eq!(restored.buckets.len(), table.buckets.len());
+        for (bucket, restored_bucket) in table.buckets.iter().zip(restored.buckets.iter()) {
+            assert_eq!(restored_bucket.prefix(), bucket.prefix());
+            assert_eq!(restored_bucket.depth(), bucket.depth());
+            let addresses: Vec<Address> = bucket.nodes().iter().map(|n| n.address()).collect();
+            let restored_addresses: Vec<Address> = restored_bucket.nodes().iter().map(|n| n.address()).collect();
+            assert_eq!(restored_addresses, addresses);
+            for (node, restored_node) in bucket.nodes().iter().zip(restored_bucket.nodes().iter()) {
+                let transports: HashSet<&Transport> = node.transports().collect();
+                assert_eq!(restored_node.transports().collect::<HashSet<_>>(), transports);
+                assert_eq!(restored_node.last_seen().to_timespec().sec, node.last_seen().to_timespec().sec);
+            }
+        }
+        assert!(restored.find_node(&stale).unwrap().is_questionable());
+        let restored_nearest: Vec<Address> = restored.nearest().iter().map(|n| n.address()).collect();
+        let nearest: Vec<Address> = table.nearest().iter().map(|n| n.address()).collect();
+        assert_eq!(restored_nearest, nearest);
+    }
+
+    #[test]
+    fn test_rejects_saved_tables_with_gaps() {
+        use protobuf::Message;
+        use std::env;
+        use std::fs;
+        use std::process;
+
+        let self_node: node::Node = node::tests::good(Address::for_content("self"));
+        let table: RoutingTable = RoutingTable::new(4, self_node.address(), vec![]);
+        let mut saved = table.save();
+        saved.message.mut_buckets()[0].set_depth(1);
+
+        let path = env::temp_dir().join(format!("comm-test-routing-table-gap-{}", process::id()));
+        {
+            let mut file = fs::File::create(&path).unwrap();
+            saved.message.write_to_writer(&mut file).unwrap();
+        }
+        assert!(SavedRoutingTable::read_from_file(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
 }
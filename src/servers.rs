@@ -1,88 +1,897 @@
-use std::net::{ToSocketAddrs, SocketAddr};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::net::{self, IpAddr, Ipv4Addr, Ipv6Addr, ToSocketAddrs, SocketAddr};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd};
+use std::os::unix::net::UnixDatagram;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, mpsc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
 
 use network::OneshotTask;
-use node::{UdpTransport, Transport};
+use node::{MemoryTransport, TcpTransport, UdpTransport, UnixTransport, Transport, WebSocketTransport};
 use stun;
+use websocket;
+
+/// The largest frame we'll accept over a stream-oriented server. Anything claiming to be longer is
+/// treated as garbage and the connection is dropped.
+pub const MAX_FRAME_LENGTH: usize = 1 << 20;
+
+/// How long to wait on connecting to, or writing to, a peer's TCP server.
+const TCP_TIMEOUT_MS: u64 = 1000;
+
+/// The most connections a stream-oriented server reads from at once. Each is read on a thread of
+/// its own, so any more are hung up on until some close.
+const MAX_CONNECTIONS: usize = 64;
+
+/// How long a connection to a stream-oriented server may go without sending us anything before we
+/// hang up on it, so that idle or stalled peers don't hold on to a connection forever.
+const CONNECTION_READ_TIMEOUT_MS: u64 = 60000;
+
+/// Writes `message` to `writer` prefixed with its length as a big-endian `u32`. This is how
+/// `Envelope`s are framed on stream-oriented transports such as TCP.
+pub fn write_frame<W: Write>(writer: &mut W, message: &[u8]) -> io::Result<()> {
+    let length = message.len() as u32;
+    let prefix = [(length >> 24) as u8, (length >> 16) as u8, (length >> 8) as u8, length as u8];
+    writer.write_all(&prefix)?;
+    writer.write_all(message)?;
+    writer.flush()
+}
+
+/// Reads a single frame written by `write_frame` from `reader`.
+pub fn read_frame<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut prefix = [0; 4];
+    reader.read_exact(&mut prefix)?;
+    let length = prefix.iter().fold(0usize, |length, &b| (length << 8) | b as usize);
+    if length > MAX_FRAME_LENGTH {
+        return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                  format!("Frame of {} bytes is too long", length)));
+    }
+    let mut message = vec![0; length];
+    reader.read_exact(&mut message)?;
+    Ok(message)
+}
+
+/// The address other nodes can reach a server bound to `local_addr` at. One bound to an
+/// unspecified address such as `0.0.0.0` listens on every interface, so it's given the address of
+/// the interface we reach the internet through, or failing that, loopback.
+fn reachable_addr(local_addr: SocketAddr) -> SocketAddr {
+    if !local_addr.ip().is_unspecified() {
+        return local_addr
+    }
+    // Working out the interface sends nothing, and nobody's at these documentation addresses
+    let (outside, loopback) = match local_addr {
+        SocketAddr::V4(_) => ("192.0.2.1:9", IpAddr::V4(Ipv4Addr::LOCALHOST)),
+        SocketAddr::V6(_) => ("[2001:db8::1]:9", IpAddr::V6(Ipv6Addr::LOCALHOST))
+    };
+    let ip = stun::local_ip_towards(outside.parse().unwrap()).unwrap_or(loopback);
+    SocketAddr::new(ip, local_addr.port())
+}
+
+/// Keeps count of the connections a server is reading from, so that it can turn away those it has
+/// no room for.
+#[derive(Clone)]
+struct ConnectionCount {
+    open: Arc<AtomicUsize>,
+    max: usize
+}
+
+impl ConnectionCount {
+    fn new(max: usize) -> ConnectionCount {
+        ConnectionCount {
+            open: Arc::new(AtomicUsize::new(0)),
+            max: max
+        }
+    }
+
+    /// Takes up one of the connections, or returns `None` if they're all taken. It's given back
+    /// when the returned `ConnectionSlot` is dropped.
+    fn acquire(&self) -> Option<ConnectionSlot> {
+        if self.open.fetch_add(1, Ordering::SeqCst) >= self.max {
+            self.open.fetch_sub(1, Ordering::SeqCst);
+            return None
+        }
+        Some(ConnectionSlot(self.open.clone()))
+    }
+}
+
+/// One of a server's connections, for as long as it's being read from.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Makes an accepted `stream` blocking, so that it can be read on a thread of its own, with a
+/// timeout on each read.
+fn prepare_stream(stream: &net::TcpStream) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_millis(CONNECTION_READ_TIMEOUT_MS)))
+}
+
+/// Starts a connection to another node's stream-oriented server, written to on a thread of its
+/// own so that connecting, which can take up to `TCP_TIMEOUT_MS`, never holds up the event loop.
+/// Messages sent to the returned `Sender` are written with `write` in order, connecting with
+/// `connect` first. The thread quits, and the `Sender` stops taking messages, once the connection
+/// can't be made.
+fn spawn_connection<C, W>(name: String, connect: C, write: W) -> mpsc::Sender<Vec<u8>>
+        where C: Fn() -> io::Result<net::TcpStream> + Send + 'static,
+              W: Fn(&mut net::TcpStream, &[u8]) -> io::Result<()> + Send + 'static {
+    let (sender, receiver) = mpsc::channel::<Vec<u8>>();
+    thread::spawn(move || {
+        let mut stream = None;
+        for message in receiver.iter() {
+            let sent = match stream {
+                Some(ref mut stream) => write(stream, &message).is_ok(),
+                None => false
+            };
+            if sent {
+                continue
+            }
+            // Either we haven't connected yet, or the other end has closed the connection since
+            // we last used it, so send over a fresh one before giving up
+            stream = match connect() {
+                Ok(stream) => {
+                    debug!("Connected to {}", name);
+                    Some(stream)
+                }
+                Err(error) => {
+                    debug!("Couldn't connect to {}: {}", name, error);
+                    return
+                }
+            };
+            if let Err(error) = write(stream.as_mut().unwrap(), &message) {
+                debug!("Couldn't send to {}: {}", name, error);
+                return
+            }
+        }
+    });
+    sender
+}
+
+/// Sends `message` over `connections[key]`, starting the connection with `spawn` if there isn't
+/// one yet, or the last one has given up.
+fn send_over_connection<K, F>(connections: &mut HashMap<K, mpsc::Sender<Vec<u8>>>, key: K, message: Vec<u8>, spawn: F)
+        where K: Eq + ::std::hash::Hash, F: Fn() -> mpsc::Sender<Vec<u8>> {
+    let message = match connections.get(&key) {
+        Some(sender) => match sender.send(message) {
+            Ok(()) => return,
+            Err(mpsc::SendError(message)) => message
+        },
+        None => message
+    };
+    let sender = spawn();
+    let _ = sender.send(message);
+    connections.insert(key, sender);
+}
+
+/// The outgoing side of a `Network`'s connections to other nodes. Connection-oriented transports
+/// keep their open connections here so that they can be reused across messages instead of
+/// reconnecting for each one.
+pub struct Connections {
+    tcp_streams: HashMap<SocketAddr, mpsc::Sender<Vec<u8>>>,
+    udp_sockets: Vec<mio::udp::UdpSocket>,
+    unix_socket: Option<UnixDatagram>,
+    websockets: HashMap<(String, String), mpsc::Sender<Vec<u8>>>
+}
+
+impl Connections {
+    pub fn new() -> Connections {
+        Connections {
+            tcp_streams: HashMap::new(),
+            udp_sockets: vec![],
+            unix_socket: None,
+            websockets: HashMap::new()
+        }
+    }
+
+    /// An unbound Unix datagram socket to send to `UnixServer`s from, creating it if we don't
+    /// already have one.
+    pub fn unix_socket(&mut self) -> io::Result<&UnixDatagram> {
+        if self.unix_socket.is_none() {
+            self.unix_socket = Some(UnixDatagram::unbound()?);
+        }
+        Ok(self.unix_socket.as_ref().unwrap())
+    }
+
+    /// Adds a bound UDP socket to send datagrams from. Sending from the same socket our
+    /// `UdpServer` listens on means replies come back to the port we advertise, which is what
+    /// keeps NAT mappings working.
+    pub fn add_udp_socket(&mut self, socket: mio::udp::UdpSocket) {
+        self.udp_sockets.push(socket);
+    }
+
+    /// A bound UDP socket of the same address family as `socket_addr`, if we have one.
+    pub fn udp_socket_for(&self, socket_addr: &SocketAddr) -> Option<&mio::udp::UdpSocket> {
+        self.udp_sockets.iter().find(|socket| {
+            match (socket.local_addr(), socket_addr) {
+                (Ok(SocketAddr::V4(_)), &SocketAddr::V4(_)) => true,
+                (Ok(SocketAddr::V6(_)), &SocketAddr::V6(_)) => true,
+                _ => false
+            }
+        })
+    }
+
+    /// Sends `message` as a frame over a TCP connection to `socket_addr`, connecting if we don't
+    /// already have one. The message is only queued; it's sent, or dropped if the connection
+    /// can't be made, on the connection's own thread.
+    pub fn send_tcp(&mut self, socket_addr: &SocketAddr, message: Vec<u8>) {
+        let socket_addr = *socket_addr;
+        send_over_connection(&mut self.tcp_streams, socket_addr, message, || {
+            spawn_connection(format!("tcp://{}", socket_addr), move || {
+                let timeout = Duration::from_millis(TCP_TIMEOUT_MS);
+                let stream = net::TcpStream::connect_timeout(&socket_addr, timeout)?;
+                stream.set_write_timeout(Some(timeout))?;
+                stream.set_nodelay(true)?;
+                Ok(stream)
+            }, write_frame)
+        });
+    }
+
+    /// Sends `message` over a WebSocket connection to `path` on `host`, connecting and doing the
+    /// opening handshake if we don't already have one. Like `send_tcp`, this only queues it.
+    pub fn send_websocket(&mut self, host: &str, path: &str, message: Vec<u8>) {
+        let key = (host.to_string(), path.to_string());
+        send_over_connection(&mut self.websockets, key.clone(), message, || {
+            let (host, path) = key.clone();
+            spawn_connection(format!("ws://{}{}", host, path), move || {
+                let socket_addr = resolve(&host).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, format!("Couldn't resolve {}", host))
+                })?;
+                let timeout = Duration::from_millis(TCP_TIMEOUT_MS);
+                let mut stream = net::TcpStream::connect_timeout(&socket_addr, timeout)?;
+                stream.set_read_timeout(Some(timeout))?;
+                stream.set_write_timeout(Some(timeout))?;
+                stream.set_nodelay(true)?;
+                websocket::connect(&mut stream, &host, &path)?;
+                Ok(stream)
+            }, |stream, message| websocket::write_message(stream, message, true))
+        });
+    }
+}
 
 pub struct UdpServer {
     socket_addr: SocketAddr,
-    socket: Option<mio::udp::UdpSocket>
+    socket: Option<mio::udp::UdpSocket>,
+    advertised_addr: Option<SocketAddr>,
+    stun_config: stun::Config
 }
 
 impl UdpServer {
     pub fn new(socket_addr: SocketAddr) -> UdpServer {
         UdpServer {
             socket_addr: socket_addr,
-            socket: None
+            socket: None,
+            advertised_addr: None,
+            stun_config: stun::Config::new()
+        }
+    }
+
+    /// Advertise `advertised_addr` to other nodes as the way to reach this server, instead of
+    /// asking STUN servers for our mapped address.
+    pub fn advertise(&mut self, advertised_addr: SocketAddr) {
+        self.advertised_addr = Some(advertised_addr);
+    }
+
+    /// Use `stun_config` to discover our mapped address.
+    pub fn set_stun_config(&mut self, stun_config: stun::Config) {
+        self.stun_config = stun_config;
+    }
+
+    /// Binds the socket the server listens on, unless it already is. This comes before working
+    /// out the server's transport, so that STUN servers are asked about the socket's own mapping.
+    fn bind(&mut self) {
+        if self.socket.is_some() {
+            return
         }
+        let socket = match self.socket_addr {
+            SocketAddr::V4(_) => mio::udp::UdpSocket::bound(&self.socket_addr),
+            SocketAddr::V6(_) => UdpServer::bind_v6_only(&self.socket_addr)
+        };
+        self.socket = Some(socket.expect("Couldn't bind socket"));
     }
 
     fn run(&mut self) -> &mio::Evented {
-        let socket = mio::udp::UdpSocket::bound(&self.socket_addr).expect("Couldn't bind socket");
-        self.socket = Some(socket);
-        return self.socket.as_ref().unwrap()
+        self.bind();
+        self.socket.as_ref().unwrap()
+    }
+
+    /// Binds an IPv6 socket that doesn't also accept IPv4 traffic. This lets an IPv4 and an IPv6
+    /// `UdpServer` listen on the same port, which is how a node is run dual-stack.
+    fn bind_v6_only(socket_addr: &SocketAddr) -> io::Result<mio::udp::UdpSocket> {
+        use net2::UdpBuilder;
+        use std::os::unix::io::{FromRawFd, IntoRawFd};
+
+        let builder = UdpBuilder::new_v6()?;
+        builder.only_v6(true)?;
+        let socket = builder.bind(socket_addr)?;
+        socket.set_nonblocking(true)?;
+        Ok(unsafe { mio::udp::UdpSocket::from_raw_fd(socket.into_raw_fd()) })
+    }
+
+    /// A handle on the bound socket that can be used for sending.
+    fn socket(&self) -> Option<mio::udp::UdpSocket> {
+        self.socket.as_ref().and_then(|socket| socket.try_clone().ok())
+    }
+
+    /// The address the socket is bound to, with the port the OS picked if we asked for any.
+    fn local_addr(&self) -> SocketAddr {
+        self.socket.as_ref()
+            .and_then(|socket| socket.local_addr().ok())
+            .unwrap_or(self.socket_addr)
+    }
+
+    /// Runs `probe` on a blocking handle on the bound socket, which STUN needs, and makes the
+    /// socket non-blocking again afterwards.
+    fn stun<T, F>(&self, probe: F) -> Result<T, String>
+        where F: FnOnce(&net::UdpSocket) -> Result<T, String> {
+        let socket = self.socket.as_ref().expect("Must `bind` the server before STUNning from it");
+        let clone = socket.try_clone().map_err(|e| e.to_string())?;
+        // mio's socket can't give up its file descriptor, so it's forgotten to hand it over
+        let socket = unsafe { net::UdpSocket::from_raw_fd(clone.as_raw_fd()) };
+        mem::forget(clone);
+        socket.set_nonblocking(false).map_err(|e| e.to_string())?;
+        let result = probe(&socket);
+        socket.set_nonblocking(true).map_err(|e| e.to_string())?;
+        result
     }
 
     fn read(&self, channel: mio::Sender<OneshotTask>) {
-        let mut buf = [0; 4096];
+        // As large as a datagram can be, so that none are cut short
+        let mut buf = [0; 65536];
         let ref socket = self.socket.as_ref().expect("Must `run` the server before reading from it");
-        if let Ok(Some((size, _))) = socket.recv_from(&mut buf) {
+        if let Ok(Some((size, source))) = socket.recv_from(&mut buf) {
+            let source = Transport::Udp(UdpTransport::new(source));
+            channel
+                .send(OneshotTask::Incoming(buf[..size].iter().cloned().collect(), Some(source)))
+                .expect("Couldn't handle incoming");
+        }
+    }
+
+    /// Probes for the kind of NAT this server is behind, unless we've been told what address to
+    /// advertise, in which case it doesn't matter.
+    fn nat_type(&self) -> Option<stun::NatType> {
+        if self.advertised_addr.is_some() {
+            return None
+        }
+        match self.stun(|socket| Ok(stun::detect_nat_type(socket, &self.stun_config))) {
+            Ok(nat_type) => Some(nat_type),
+            Err(error) => {
+                debug!("Couldn't detect NAT type of {}: {}", self.local_addr(), error);
+                Some(stun::NatType::Unknown)
+            }
+        }
+    }
+
+    /// The transport to advertise for this server. Unless an address to advertise was given
+    /// explicitly, it's the address STUN reports we're mapped to. Failing that, e.g. on a network
+    /// with no route to any STUN server, it's just the address we're bound to.
+    fn transport(&self) -> Transport {
+        if let Some(advertised_addr) = self.advertised_addr {
+            return Transport::Udp(UdpTransport::new(advertised_addr))
+        }
+
+        let local_addr = reachable_addr(self.local_addr());
+        match self.stun(|socket| stun::get_mapped_address(socket, &self.stun_config)) {
+            Ok(mapped_host) => Transport::Udp(UdpTransport::new(mapped_host)),
+            Err(error) => {
+                info!("Couldn't STUN {}, advertising it as is: {}", local_addr, error);
+                Transport::Udp(UdpTransport::new(local_addr))
+            }
+        }
+    }
+}
+
+/// Accepts TCP connections from other nodes. Every accepted connection is read on its own thread,
+/// one length-prefixed frame at a time, until the other end hangs up or goes quiet for too long.
+/// Up to `MAX_CONNECTIONS` are read from at once.
+pub struct TcpServer {
+    socket_addr: SocketAddr,
+    listener: Option<net::TcpListener>,
+    evented: Option<mio::tcp::TcpListener>,
+    advertised_addr: Option<SocketAddr>,
+    connections: ConnectionCount
+}
+
+impl TcpServer {
+    pub fn new(socket_addr: SocketAddr) -> TcpServer {
+        TcpServer {
+            socket_addr: socket_addr,
+            listener: None,
+            evented: None,
+            advertised_addr: None,
+            connections: ConnectionCount::new(MAX_CONNECTIONS)
+        }
+    }
+
+    /// Advertise `advertised_addr` to other nodes as the way to reach this server, instead of
+    /// the address it's bound to.
+    pub fn advertise(&mut self, advertised_addr: SocketAddr) {
+        self.advertised_addr = Some(advertised_addr);
+    }
+
+    fn run(&mut self) -> &mio::Evented {
+        let listener = net::TcpListener::bind(&self.socket_addr).expect("Couldn't bind listener");
+        let evented = listener.try_clone()
+            .and_then(|l| mio::tcp::TcpListener::from_listener(l, &self.socket_addr))
+            .expect("Couldn't register listener");
+        self.listener = Some(listener);
+        self.evented = Some(evented);
+        self.evented.as_ref().unwrap()
+    }
+
+    fn read(&self, channel: mio::Sender<OneshotTask>) {
+        let ref listener = self.listener.as_ref().expect("Must `run` the server before reading from it");
+        while let Ok((stream, peer_addr)) = listener.accept() {
+            let slot = match self.connections.acquire() {
+                Some(slot) => slot,
+                None => {
+                    debug!("Too many connections, hanging up on {}", peer_addr);
+                    continue
+                }
+            };
+            debug!("Accepted connection from {}", peer_addr);
+            let channel = channel.clone();
+            thread::spawn(move || TcpServer::read_stream(stream, slot, channel));
+        }
+    }
+
+    fn read_stream(mut stream: net::TcpStream, _slot: ConnectionSlot, channel: mio::Sender<OneshotTask>) {
+        if prepare_stream(&stream).is_err() {
+            return
+        }
+        while let Ok(message) = read_frame(&mut stream) {
+            if channel.send(OneshotTask::Incoming(message, None)).is_err() {
+                break
+            }
+        }
+    }
+
+    fn transport(&self) -> Transport {
+        let socket_addr = self.advertised_addr.unwrap_or_else(|| reachable_addr(self.socket_addr));
+        Transport::Tcp(TcpTransport::new(socket_addr))
+    }
+}
+
+/// Accepts WebSocket connections from other nodes on a single path. Like `TcpServer`, every
+/// accepted connection is read on its own thread, one binary message at a time, and up to
+/// `MAX_CONNECTIONS` are read from at once.
+pub struct WebSocketServer {
+    socket_addr: SocketAddr,
+    path: String,
+    listener: Option<net::TcpListener>,
+    evented: Option<mio::tcp::TcpListener>,
+    advertised_host: Option<String>,
+    connections: ConnectionCount
+}
+
+impl WebSocketServer {
+    pub fn new(socket_addr: SocketAddr, path: &str) -> WebSocketServer {
+        WebSocketServer {
+            socket_addr: socket_addr,
+            path: path.to_string(),
+            listener: None,
+            evented: None,
+            advertised_host: None,
+            connections: ConnectionCount::new(MAX_CONNECTIONS)
+        }
+    }
+
+    /// Advertise `advertised_host` to other nodes as the way to reach this server, instead of
+    /// the address it's bound to. Unlike for UDP and TCP it's kept as a host name, since that's
+    /// what a proxy in front of the server will route on.
+    pub fn advertise(&mut self, advertised_host: &str) {
+        self.advertised_host = Some(advertised_host.to_string());
+    }
+
+    fn run(&mut self) -> &mio::Evented {
+        let listener = net::TcpListener::bind(&self.socket_addr).expect("Couldn't bind listener");
+        let evented = listener.try_clone()
+            .and_then(|l| mio::tcp::TcpListener::from_listener(l, &self.socket_addr))
+            .expect("Couldn't register listener");
+        self.listener = Some(listener);
+        self.evented = Some(evented);
+        self.evented.as_ref().unwrap()
+    }
+
+    fn read(&self, channel: mio::Sender<OneshotTask>) {
+        let listener = self.listener.as_ref().expect("Must `run` the server before reading from it");
+        while let Ok((stream, peer_addr)) = listener.accept() {
+            let slot = match self.connections.acquire() {
+                Some(slot) => slot,
+                None => {
+                    debug!("Too many WebSocket connections, hanging up on {}", peer_addr);
+                    continue
+                }
+            };
+            debug!("Accepted WebSocket connection from {}", peer_addr);
+            let channel = channel.clone();
+            let path = self.path.clone();
+            thread::spawn(move || WebSocketServer::read_stream(stream, slot, path, channel));
+        }
+    }
+
+    fn read_stream(mut stream: net::TcpStream, _slot: ConnectionSlot, path: String,
+                   channel: mio::Sender<OneshotTask>) {
+        if prepare_stream(&stream).is_err() {
+            return
+        }
+        if let Err(error) = websocket::accept(&mut stream, &path) {
+            debug!("Failed WebSocket handshake: {}", error);
+            return
+        }
+        while let Ok(message) = websocket::read_message(&mut stream, false) {
+            if channel.send(OneshotTask::Incoming(message, None)).is_err() {
+                break
+            }
+        }
+    }
+
+    fn transport(&self) -> Transport {
+        let host = match self.advertised_host {
+            Some(ref advertised_host) => advertised_host.clone(),
+            None => reachable_addr(self.socket_addr).to_string()
+        };
+        Transport::WebSocket(WebSocketTransport::new(&host, &self.path))
+    }
+}
+
+/// Where messages for a `MemoryServer` are left. Writing to the doorbell wakes up the event loop
+/// the server is registered with, so that it comes and collects them.
+struct Mailbox {
+    server_id: usize,
+    messages: mpsc::Sender<Vec<u8>>,
+    doorbell: mio::unix::PipeWriter
+}
+
+/// Tells `MemoryServer`s apart, so that one can't take down another's mailbox.
+static NEXT_MEMORY_SERVER_ID: AtomicUsize = AtomicUsize::new(0);
+
+lazy_static! {
+    /// Every running `MemoryServer` in the process, by name.
+    static ref MEMORY_HUB: Mutex<HashMap<String, Mailbox>> = Mutex::new(HashMap::new());
+}
+
+/// Hands `message` to the `MemoryServer` called `name`. Returns whether there was such a server
+/// running.
+pub fn deliver_in_memory(name: &str, message: Vec<u8>) -> bool {
+    let hub = MEMORY_HUB.lock().unwrap();
+    match hub.get(name) {
+        Some(mailbox) => {
+            if mailbox.messages.send(message).is_err() {
+                return false
+            }
+            // If the pipe is full, the server already has a wake up pending.
+            let _ = (&mailbox.doorbell).write(&[1]);
+            true
+        }
+        None => false
+    }
+}
+
+/// A server that lives entirely within the process. Other nodes in the same process reach it by
+/// name via a `MemoryTransport`, which makes it possible to run whole networks in tests without
+/// any real sockets.
+pub struct MemoryServer {
+    id: usize,
+    name: String,
+    messages: Option<mpsc::Receiver<Vec<u8>>>,
+    doorbell: Option<mio::unix::PipeReader>
+}
+
+impl MemoryServer {
+    pub fn new(name: &str) -> MemoryServer {
+        MemoryServer {
+            id: NEXT_MEMORY_SERVER_ID.fetch_add(1, Ordering::SeqCst),
+            name: name.to_string(),
+            messages: None,
+            doorbell: None
+        }
+    }
+
+    fn run(&mut self) -> &mio::Evented {
+        let (reader, writer) = mio::unix::pipe().expect("Couldn't create pipe");
+        let (sender, receiver) = mpsc::channel();
+        let mailbox = Mailbox {
+            server_id: self.id,
+            messages: sender,
+            doorbell: writer
+        };
+        self.register(mailbox).expect("Couldn't register memory server");
+        self.messages = Some(receiver);
+        self.doorbell = Some(reader);
+        self.doorbell.as_ref().unwrap()
+    }
+
+    /// Leaves `mailbox` in the hub under our name, unless another server already goes by it.
+    fn register(&self, mailbox: Mailbox) -> io::Result<()> {
+        let mut hub = MEMORY_HUB.lock().unwrap();
+        if hub.contains_key(&self.name) {
+            return Err(io::Error::new(io::ErrorKind::AddrInUse,
+                                      format!("memory://{} is in use", self.name)));
+        }
+        hub.insert(self.name.clone(), mailbox);
+        Ok(())
+    }
+
+    fn read(&self, channel: mio::Sender<OneshotTask>) {
+        let mut doorbell = self.doorbell.as_ref().expect("Must `run` the server before reading from it");
+        let mut buf = [0; 64];
+        while let Ok(size) = doorbell.read(&mut buf) {
+            if size == 0 {
+                break
+            }
+        }
+        let ref messages = self.messages.as_ref().unwrap();
+        while let Ok(message) = messages.try_recv() {
             channel
-                .send(OneshotTask::Incoming(buf[..size].iter().cloned().collect()))
+                .send(OneshotTask::Incoming(message, None))
                 .expect("Couldn't handle incoming");
         }
     }
 
     fn transport(&self) -> Transport {
-        let mapped_host = stun::get_mapped_address(self.socket_addr).expect("Couldn't STUN myself");
-        Transport::Udp(UdpTransport::new(mapped_host))
+        Transport::Memory(MemoryTransport::new(&self.name))
+    }
+}
+
+impl Drop for MemoryServer {
+    fn drop(&mut self) {
+        let mut hub = MEMORY_HUB.lock().unwrap();
+        if hub.get(&self.name).map_or(false, |mailbox| mailbox.server_id == self.id) {
+            hub.remove(&self.name);
+        }
+    }
+}
+
+/// A server listening on a Unix datagram socket, for nodes on the same host to reach us through
+/// a `UnixTransport`. Who may connect is up to the permissions of the socket file.
+pub struct UnixServer {
+    path: PathBuf,
+    mode: Option<u32>,
+    socket: Option<UnixDatagram>,
+    evented: Option<mio::unix::UnixSocket>
+}
+
+impl UnixServer {
+    pub fn new<P: AsRef<Path>>(path: P) -> UnixServer {
+        UnixServer {
+            path: path.as_ref().to_path_buf(),
+            mode: None,
+            socket: None,
+            evented: None
+        }
+    }
+
+    /// Sets the permissions of the socket file once it's bound, e.g. `0o660` to let only the
+    /// owner and group in.
+    pub fn set_mode(&mut self, mode: u32) {
+        self.mode = Some(mode);
+    }
+
+    fn run(&mut self) -> &mio::Evented {
+        let socket = self.bind().expect("Couldn't bind Unix socket");
+        if let Some(mode) = self.mode {
+            fs::set_permissions(&self.path, fs::Permissions::from_mode(mode))
+                .expect("Couldn't set permissions of Unix socket");
+        }
+        socket.set_nonblocking(true).expect("Couldn't make Unix socket nonblocking");
+        // mio has no datagram Unix sockets, but registering only needs the file descriptor.
+        let evented = socket.try_clone()
+            .map(|s| unsafe { mio::unix::UnixSocket::from_raw_fd(s.into_raw_fd()) })
+            .expect("Couldn't register Unix socket");
+        self.socket = Some(socket);
+        self.evented = Some(evented);
+        self.evented.as_ref().unwrap()
+    }
+
+    /// Binds the socket, first removing any socket file left behind by a server that's no longer
+    /// running.
+    fn bind(&self) -> io::Result<UnixDatagram> {
+        match UnixDatagram::bind(&self.path) {
+            Err(ref error) if error.kind() == io::ErrorKind::AddrInUse => {
+                if UnixDatagram::unbound()?.connect(&self.path).is_ok() {
+                    return Err(io::Error::new(io::ErrorKind::AddrInUse,
+                                              format!("{} is in use", self.path.display())));
+                }
+                debug!("Removing stale Unix socket {}", self.path.display());
+                fs::remove_file(&self.path)?;
+                UnixDatagram::bind(&self.path)
+            }
+            result => result
+        }
+    }
+
+    fn read(&self, channel: mio::Sender<OneshotTask>) {
+        let socket = self.socket.as_ref().expect("Must `run` the server before reading from it");
+        let mut buf = [0; 65536];
+        while let Ok(size) = socket.recv(&mut buf) {
+            channel
+                .send(OneshotTask::Incoming(buf[..size].to_vec(), None))
+                .expect("Couldn't handle incoming");
+        }
+    }
+
+    fn transport(&self) -> Transport {
+        Transport::Unix(UnixTransport::new(&self.path))
+    }
+}
+
+impl Drop for UnixServer {
+    fn drop(&mut self) {
+        if self.socket.is_some() {
+            let _ = fs::remove_file(&self.path);
+        }
     }
 }
 
 pub enum Server {
-    Udp(UdpServer)
+    Udp(UdpServer),
+    Tcp(TcpServer),
+    Memory(MemoryServer),
+    Unix(UnixServer),
+    WebSocket(WebSocketServer)
+}
+
+/// The first socket address `host` resolves to.
+fn resolve(host: &str) -> Option<SocketAddr> {
+    host.to_socket_addrs().ok().and_then(|mut socket_addrs| socket_addrs.next())
 }
 
 impl Server {
+    /// Creates a server from a URL such as `udp://0.0.0.0:6667`. The scheme picks the kind of
+    /// server: `udp`, `tcp`, `memory`, `unix` or `ws`. WebSocket servers are given the path to
+    /// accept connections on, e.g. `ws://0.0.0.0:8080/comm`, which defaults to `/`.
+    ///
+    /// UDP, TCP and WebSocket servers take an `advertise` option giving the address other nodes
+    /// should use to reach them, e.g. `udp://0.0.0.0:6667?advertise=203.0.113.5:6667`. Without
+    /// it, UDP servers advertise what STUN tells us our address is, and TCP and WebSocket servers
+    /// the address they're bound to, with an unspecified IP such as `0.0.0.0` swapped for that of
+    /// our outward-facing interface. WebSocket servers advertise the given host as is, e.g.
+    /// `ws://0.0.0.0:8080/comm?advertise=comm.example.com:80`.
+    ///
+    /// Unix servers take a `mode` option giving the permissions of the socket file in octal, e.g.
+    /// `unix:///run/comm.sock?mode=660`.
     pub fn create(url: &str) -> Option<Server> {
         let parts: Vec<&str> = url.splitn(2, "://").collect();
+        if parts.len() != 2 {
+            return None
+        }
         let protocol = parts[0];
-        let host = parts[1];
+        let parts: Vec<&str> = parts[1].splitn(2, '?').collect();
+        let host = parts[0];
+
+        let mut advertised = None;
+        let mut mode = None;
+        for option in parts.get(1).map(|o| o.split('&').collect::<Vec<&str>>()).unwrap_or_default() {
+            let option: Vec<&str> = option.splitn(2, '=').collect();
+            match (option[0], option.get(1)) {
+                ("advertise", Some(value)) => {
+                    advertised = Some(*value);
+                }
+                ("mode", Some(value)) => {
+                    mode = Some(u32::from_str_radix(value, 8).ok()?);
+                }
+                _ => return None
+            }
+        }
+
         match protocol {
+            "udp" | "tcp" | "ws" if mode.is_some() => None,
+            "memory" | "unix" if advertised.is_some() => None,
             "udp" => {
-                if let Ok(mut socket_addrs) = host.to_socket_addrs() {
-                    if let Some(socket_addr) = socket_addrs.next() {
-                        return Some(Server::Udp(UdpServer::new(socket_addr)))
-                    }
+                let mut server = UdpServer::new(resolve(host)?);
+                if let Some(advertised) = advertised {
+                    server.advertise(resolve(advertised)?);
+                }
+                Some(Server::Udp(server))
+            }
+            "tcp" => {
+                let mut server = TcpServer::new(resolve(host)?);
+                if let Some(advertised) = advertised {
+                    server.advertise(resolve(advertised)?);
                 }
-                None
+                Some(Server::Tcp(server))
+            }
+            "ws" => {
+                let (host, path) = websocket::split_path(host);
+                let mut server = WebSocketServer::new(resolve(host)?, path);
+                if let Some(advertised) = advertised {
+                    server.advertise(advertised);
+                }
+                Some(Server::WebSocket(server))
+            }
+            "memory" if mode.is_none() => Some(Server::Memory(MemoryServer::new(host))),
+            "unix" => {
+                let mut server = UnixServer::new(host);
+                if let Some(mode) = mode {
+                    server.set_mode(mode);
+                }
+                Some(Server::Unix(server))
             }
             _ => None
         }
     }
 
+    /// Binds the server's socket ahead of `run`, if it's a kind of server whose transport depends
+    /// on it.
+    pub fn bind(&mut self) {
+        match self {
+            Server::Udp(server) => server.bind(),
+            Server::Tcp(_) | Server::Memory(_) | Server::Unix(_) | Server::WebSocket(_) => { }
+        }
+    }
+
+    /// The kind of NAT this server is behind, if it's a kind of server that can tell.
+    pub fn nat_type(&self) -> Option<stun::NatType> {
+        match self {
+            Server::Udp(server) => server.nat_type(),
+            Server::Tcp(_) | Server::Memory(_) | Server::Unix(_) | Server::WebSocket(_) => None
+        }
+    }
+
+    /// Use `stun_config` to discover the mapped address of this server, if it's a kind that
+    /// needs to.
+    pub fn set_stun_config(&mut self, stun_config: stun::Config) {
+        match self {
+            Server::Udp(server) => server.set_stun_config(stun_config),
+            Server::Tcp(_) | Server::Memory(_) | Server::Unix(_) | Server::WebSocket(_) => { }
+        }
+    }
+
     pub fn transport(&self) -> Transport {
         match self {
-            Server::Udp(server) => server.transport()
+            Server::Udp(server) => server.transport(),
+            Server::Tcp(server) => server.transport(),
+            Server::Memory(server) => server.transport(),
+            Server::Unix(server) => server.transport(),
+            Server::WebSocket(server) => server.transport()
         }
     }
 
     pub fn read(&self, channel: mio::Sender<OneshotTask>) {
         match self {
-            Server::Udp(server) => server.read(channel)
+            Server::Udp(server) => server.read(channel),
+            Server::Tcp(server) => server.read(channel),
+            Server::Memory(server) => server.read(channel),
+            Server::Unix(server) => server.read(channel),
+            Server::WebSocket(server) => server.read(channel)
         }
     }
 
     pub fn run(&mut self) -> &mio::Evented {
         match self {
-            Server::Udp(server) => server.run()
+            Server::Udp(server) => server.run(),
+            Server::Tcp(server) => server.run(),
+            Server::Memory(server) => server.run(),
+            Server::Unix(server) => server.run(),
+            Server::WebSocket(server) => server.run()
+        }
+    }
+
+    /// The bound UDP socket of a running server, for sending datagrams from. `None` for servers
+    /// that aren't UDP.
+    pub fn udp_socket(&self) -> Option<mio::udp::UdpSocket> {
+        match self {
+            Server::Udp(server) => server.socket(),
+            Server::Tcp(_) | Server::Memory(_) | Server::Unix(_) | Server::WebSocket(_) => None
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Server;
+    use node::Transport;
+    use std::env;
+    use std::process;
+    use super::{ConnectionCount, Connections, MAX_FRAME_LENGTH, MemoryServer, Server, TcpServer,
+                UdpServer, UnixServer, WebSocketServer, deliver_in_memory, read_frame, write_frame};
 
     #[test]
     fn it_creates_udp_servers() {
@@ -92,4 +901,391 @@ mod tests {
             _ => assert!(false)
         }
     }
+
+    #[test]
+    fn it_creates_tcp_servers() {
+        let server = Server::create("tcp://0.0.0.0:6667");
+        match server {
+            Some(Server::Tcp(_)) => assert!(true),
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn it_creates_memory_servers() {
+        let server = Server::create("memory://alpha");
+        match server {
+            Some(Server::Memory(_)) => assert!(true),
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn it_creates_unix_servers() {
+        match Server::create("unix:///tmp/comm.sock?mode=660") {
+            Some(Server::Unix(server)) => assert_eq!(server.mode, Some(0o660)),
+            _ => assert!(false)
+        }
+        assert!(Server::create("unix:///tmp/comm.sock?mode=rw").is_none());
+        assert!(Server::create("unix:///tmp/comm.sock?advertise=203.0.113.5:6667").is_none());
+        assert!(Server::create("udp://0.0.0.0:6667?mode=660").is_none());
+    }
+
+    #[test]
+    fn it_creates_websocket_servers() {
+        use node::WebSocketTransport;
+
+        match Server::create("ws://0.0.0.0:8080/comm") {
+            Some(Server::WebSocket(server)) => assert_eq!(server.path, "/comm"),
+            _ => assert!(false)
+        }
+        match Server::create("ws://0.0.0.0:8080") {
+            Some(Server::WebSocket(server)) => assert_eq!(server.path, "/"),
+            _ => assert!(false)
+        }
+        let server = Server::create("ws://0.0.0.0:8080/comm?advertise=comm.example.com:80").unwrap();
+        assert_eq!(server.transport(),
+                   Transport::WebSocket(WebSocketTransport::new("comm.example.com:80", "/comm")));
+        assert!(Server::create("ws://0.0.0.0:8080/comm?mode=660").is_none());
+    }
+
+    #[test]
+    fn test_websocket_server() {
+        use mio::{EventLoop, EventSet, Handler, PollOpt, Token};
+        use network::OneshotTask;
+        use std::thread;
+
+        struct Collector {
+            server: WebSocketServer,
+            received: Option<Vec<u8>>
+        }
+
+        impl Handler for Collector {
+            type Timeout = ();
+            type Message = OneshotTask;
+
+            fn ready(&mut self, event_loop: &mut EventLoop<Collector>, _: Token, _: EventSet) {
+                self.server.read(event_loop.channel());
+            }
+
+            fn notify(&mut self, event_loop: &mut EventLoop<Collector>, task: OneshotTask) {
+                if let OneshotTask::Incoming(message, _) = task {
+                    self.received = Some(message);
+                    event_loop.shutdown();
+                }
+            }
+        }
+
+        let mut event_loop = EventLoop::new().unwrap();
+        let mut server = WebSocketServer::new("127.0.0.1:0".parse().unwrap(), "/comm");
+        event_loop.register(server.run(), Token(0), EventSet::readable(), PollOpt::level()).unwrap();
+        let socket_addr = server.listener.as_ref().unwrap().local_addr().unwrap();
+        let mut collector = Collector {
+            server: server,
+            received: None
+        };
+
+        // The handshake needs the event loop running to be accepted
+        let transport = Transport::create(&format!("ws://{}/comm", socket_addr)).unwrap();
+        thread::spawn(move || transport.send(b"hello".to_vec(), &mut Connections::new()));
+        event_loop.run(&mut collector).unwrap();
+        assert_eq!(collector.received, Some(b"hello".to_vec()));
+    }
+
+    #[test]
+    fn test_connections_reuse_websockets() {
+        use websocket;
+
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+        let mut connections = Connections::new();
+        connections.send_websocket(&host, "/comm", b"first".to_vec());
+        connections.send_websocket(&host, "/comm", b"second".to_vec());
+
+        let (mut stream, _) = listener.accept().unwrap();
+        websocket::accept(&mut stream, "/comm").unwrap();
+        assert_eq!(websocket::read_message(&mut stream, false).unwrap(), b"first".to_vec());
+        assert_eq!(websocket::read_message(&mut stream, false).unwrap(), b"second".to_vec());
+    }
+
+    #[test]
+    fn test_unix_server() {
+        use node::UnixTransport;
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+        use std::os::unix::net::UnixDatagram;
+
+        let path = env::temp_dir().join(format!("comm-test-unix-server-{}.sock", process::id()));
+        let mut server = UnixServer::new(&path);
+        server.set_mode(0o600);
+        server.run();
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+        let mut connections = Connections::new();
+        Transport::Unix(UnixTransport::new(&path)).send(b"hello".to_vec(), &mut connections);
+        let mut buf = [0; 16];
+        let size = server.socket.as_ref().unwrap().recv(&mut buf).unwrap();
+        assert_eq!(&buf[..size], b"hello");
+
+        // A second server can't take over the socket while the first is running, but can once
+        // it's left behind
+        assert!(UnixServer::new(&path).bind().is_err());
+        drop(server);
+        assert!(!path.exists());
+        let stale = UnixDatagram::bind(&path).unwrap();
+        drop(stale);
+        let mut server = UnixServer::new(&path);
+        server.run();
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn it_creates_servers_with_advertised_addresses() {
+        use node::{TcpTransport, Transport, UdpTransport};
+
+        let advertised = "203.0.113.5:6667".parse().unwrap();
+        let server = Server::create("udp://0.0.0.0:6667?advertise=203.0.113.5:6667").unwrap();
+        assert_eq!(server.transport(), Transport::Udp(UdpTransport::new(advertised)));
+        let server = Server::create("tcp://0.0.0.0:6667?advertise=203.0.113.5:6667").unwrap();
+        assert_eq!(server.transport(), Transport::Tcp(TcpTransport::new(advertised)));
+
+        let server = Server::create("tcp://0.0.0.0:6667").unwrap();
+        assert!(!server.transport().ip_address().unwrap().is_unspecified());
+
+        assert!(Server::create("udp://0.0.0.0:6667?advertise=nowhere").is_none());
+        assert!(Server::create("udp://0.0.0.0:6667?bogus=1").is_none());
+        assert!(Server::create("0.0.0.0:6667").is_none());
+    }
+
+    #[test]
+    fn test_udp_transport_falls_back_to_bound_address() {
+        use node::{Transport, UdpTransport};
+        use std::net::UdpSocket;
+        use std::time::Duration;
+        use stun;
+
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let stun_config = stun::Config {
+            servers: vec![silent.local_addr().unwrap().to_string()],
+            timeout: Duration::from_millis(100),
+            attempts: 1
+        };
+        let mut server = UdpServer::new("127.0.0.1:0".parse().unwrap());
+        server.set_stun_config(stun_config);
+        server.bind();
+        let socket_addr = server.socket.as_ref().unwrap().local_addr().unwrap();
+        assert!(socket_addr.port() != 0);
+        assert_eq!(server.transport(), Transport::Udp(UdpTransport::new(socket_addr)));
+    }
+
+    #[test]
+    fn test_udp_transport_uses_stun() {
+        use node::{Transport, UdpTransport};
+        use std::time::Duration;
+        use stun;
+
+        let mapped = "203.0.113.7:41000".parse().unwrap();
+        let stun_server = stun::tests::stun_server(1, move |_| mapped);
+        let stun_config = stun::Config {
+            servers: vec![stun_server.to_string()],
+            timeout: Duration::from_millis(1000),
+            attempts: 1
+        };
+        let mut server = UdpServer::new("127.0.0.1:0".parse().unwrap());
+        server.set_stun_config(stun_config);
+        server.bind();
+        assert_eq!(server.transport(), Transport::Udp(UdpTransport::new(mapped)));
+    }
+
+    #[test]
+    fn test_udp_server_stuns_from_its_own_socket() {
+        use node::{Transport, UdpTransport};
+        use std::time::Duration;
+        use stun;
+
+        let stun_server = stun::tests::stun_server(1, |source| source);
+        let stun_config = stun::Config {
+            servers: vec![stun_server.to_string()],
+            timeout: Duration::from_millis(1000),
+            attempts: 1
+        };
+        let mut server = UdpServer::new("127.0.0.1:0".parse().unwrap());
+        server.set_stun_config(stun_config);
+        server.bind();
+        let socket_addr = server.socket.as_ref().unwrap().local_addr().unwrap();
+        assert_eq!(server.transport(), Transport::Udp(UdpTransport::new(socket_addr)));
+
+        // And it's left non-blocking for the event loop
+        let mut buf = [0; 1];
+        assert!(server.socket.as_ref().unwrap().recv_from(&mut buf).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_frames() {
+        use std::io::Cursor;
+
+        let mut buffer = vec![];
+        write_frame(&mut buffer, b"first").unwrap();
+        write_frame(&mut buffer, b"").unwrap();
+        write_frame(&mut buffer, b"second").unwrap();
+        assert_eq!(&buffer[..9], &[0, 0, 0, 5, b'f', b'i', b'r', b's', b't']);
+
+        let mut reader = Cursor::new(buffer);
+        assert_eq!(read_frame(&mut reader).unwrap(), b"first".to_vec());
+        assert_eq!(read_frame(&mut reader).unwrap(), b"".to_vec());
+        assert_eq!(read_frame(&mut reader).unwrap(), b"second".to_vec());
+        assert!(read_frame(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_oversized_frame() {
+        use std::io::Cursor;
+
+        let length = MAX_FRAME_LENGTH as u32 + 1;
+        let prefix = vec![(length >> 24) as u8, (length >> 16) as u8, (length >> 8) as u8, length as u8];
+        assert!(read_frame(&mut Cursor::new(prefix)).is_err());
+    }
+
+    #[test]
+    fn test_connection_count() {
+        let count = ConnectionCount::new(2);
+        let first = count.acquire();
+        let second = count.acquire();
+        assert!(first.is_some());
+        assert!(second.is_some());
+        assert!(count.acquire().is_none());
+        drop(first);
+        assert!(count.acquire().is_some());
+    }
+
+    #[test]
+    fn test_tcp_server_limits_connections() {
+        use mio::{EventLoop, Handler};
+        use network::OneshotTask;
+        use std::io::Read;
+        use std::net::TcpStream;
+        use std::time::Duration;
+
+        struct Ignore;
+
+        impl Handler for Ignore {
+            type Timeout = ();
+            type Message = OneshotTask;
+        }
+
+        let event_loop: EventLoop<Ignore> = EventLoop::new().unwrap();
+        let mut server = TcpServer::new("127.0.0.1:0".parse().unwrap());
+        server.connections = ConnectionCount::new(1);
+        server.run();
+        let socket_addr = server.listener.as_ref().unwrap().local_addr().unwrap();
+        let mut first = TcpStream::connect(socket_addr).unwrap();
+        let mut second = TcpStream::connect(socket_addr).unwrap();
+        server.read(event_loop.channel());
+
+        // The second is hung up on, while the first is still being read from
+        let mut buf = [0; 1];
+        second.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+        assert_eq!(second.read(&mut buf).unwrap(), 0);
+        first.set_read_timeout(Some(Duration::from_millis(100))).unwrap();
+        assert!(first.read(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_connections_reuse_tcp_streams() {
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let socket_addr = listener.local_addr().unwrap();
+        let mut connections = Connections::new();
+        connections.send_tcp(&socket_addr, b"first".to_vec());
+        connections.send_tcp(&socket_addr, b"second".to_vec());
+
+        let (mut stream, _) = listener.accept().unwrap();
+        assert_eq!(read_frame(&mut stream).unwrap(), b"first".to_vec());
+        assert_eq!(read_frame(&mut stream).unwrap(), b"second".to_vec());
+    }
+
+    #[test]
+    fn test_connections_reconnect_after_failing_to_connect() {
+        use std::net::TcpListener;
+        use std::thread;
+        use std::time::Duration;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let socket_addr = listener.local_addr().unwrap();
+        drop(listener);
+        let mut connections = Connections::new();
+        connections.send_tcp(&socket_addr, b"lost".to_vec());
+        thread::sleep(Duration::from_millis(100));
+
+        let listener = TcpListener::bind(socket_addr).unwrap();
+        connections.send_tcp(&socket_addr, b"found".to_vec());
+        let (mut stream, _) = listener.accept().unwrap();
+        assert_eq!(read_frame(&mut stream).unwrap(), b"found".to_vec());
+    }
+
+    #[test]
+    fn test_connections_udp_socket_for() {
+        use mio;
+        use std::net::SocketAddr;
+
+        let v4: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let v6: SocketAddr = "[::1]:0".parse().unwrap();
+        let mut connections = Connections::new();
+        assert!(connections.udp_socket_for(&v4).is_none());
+
+        let socket = mio::udp::UdpSocket::bound(&v4).unwrap();
+        let local_addr = socket.local_addr().unwrap();
+        connections.add_udp_socket(socket);
+        assert_eq!(connections.udp_socket_for(&v4).unwrap().local_addr().unwrap(), local_addr);
+        assert!(connections.udp_socket_for(&v6).is_none());
+    }
+
+    #[test]
+    fn test_dual_stack_udp_servers() {
+        let mut v4_server = UdpServer::new("0.0.0.0:0".parse().unwrap());
+        v4_server.run();
+        let port = v4_server.socket().unwrap().local_addr().unwrap().port();
+
+        // Binds to the same port as the IPv4 server without conflict
+        let mut v6_server = UdpServer::new(format!("[::]:{}", port).parse().unwrap());
+        v6_server.run();
+        let v6_address = v6_server.socket().unwrap().local_addr().unwrap();
+        assert!(v6_address.is_ipv6());
+        assert_eq!(v6_address.port(), port);
+    }
+
+    #[test]
+    fn test_deliver_in_memory() {
+        assert!(!deliver_in_memory("test_deliver_in_memory", b"lost".to_vec()));
+        {
+            let mut server = MemoryServer::new("test_deliver_in_memory");
+            server.run();
+            assert!(deliver_in_memory("test_deliver_in_memory", b"hello".to_vec()));
+            let received = server.messages.as_ref().unwrap().try_recv().unwrap();
+            assert_eq!(received, b"hello".to_vec());
+        }
+
+        // Stops receiving once the server is gone
+        assert!(!deliver_in_memory("test_deliver_in_memory", b"lost".to_vec()));
+    }
+
+    #[test]
+    fn test_memory_server_names_are_unique() {
+        use std::panic;
+
+        let mut server = MemoryServer::new("test_memory_server_names_are_unique");
+        server.run();
+        let result = panic::catch_unwind(|| {
+            MemoryServer::new("test_memory_server_names_are_unique").run();
+        });
+        assert!(result.is_err());
+
+        // The one that lost out doesn't take the other's mailbox with it
+        assert!(deliver_in_memory("test_memory_server_names_are_unique", b"hello".to_vec()));
+        drop(server);
+        assert!(!deliver_in_memory("test_memory_server_names_are_unique", b"lost".to_vec()));
+    }
 }
@@ -0,0 +1,203 @@
+use address::Address;
+use node::Status;
+use rustc_serialize::json::{Json, ToJson};
+use std::collections::BTreeMap;
+use time;
+
+/// What a `RoutingTable` knows at a point in time: its buckets, and what we've seen of each of
+/// the nodes in them. Taken with `RoutingTable::snapshot`, or from a running `Network` with
+/// `OneshotTask::SnapshotRoutingTable`, for diagnosing why messages aren't reaching a node.
+#[derive(Clone, Debug)]
+pub struct RoutingTableSnapshot {
+    pub self_address: Address,
+    pub k: usize,
+    pub buckets: Vec<BucketSnapshot>
+}
+
+#[derive(Clone, Debug)]
+pub struct BucketSnapshot {
+    /// The lowest address in the bucket's space.
+    pub prefix: Address,
+    /// How many leading bits the addresses in the bucket's space share.
+    pub depth: usize,
+    /// Nodes seen while the bucket was full, waiting to replace any that stop responding.
+    pub replacements: usize,
+    pub nodes: Vec<NodeSnapshot>
+}
+
+#[derive(Clone, Debug)]
+pub struct NodeSnapshot {
+    pub address: Address,
+    pub status: Status,
+    pub pending_queries: usize,
+    pub last_seen: time::Tm,
+    pub transports: Vec<TransportSnapshot>
+}
+
+#[derive(Clone, Debug)]
+pub struct TransportSnapshot {
+    pub transport: String,
+    /// Round-trip time of the last query answered over the transport.
+    pub rtt: Option<time::Duration>,
+    /// How many queries over the transport have gone unanswered since the last one that was.
+    pub failures: usize
+}
+
+impl RoutingTableSnapshot {
+    pub fn node_count(&self) -> usize {
+        self.buckets.iter().map(|b| b.nodes.len()).sum()
+    }
+
+    /// The snapshot as a Graphviz digraph, with our node at the root, then each bucket, then the
+    /// nodes in it coloured by their status.
+    pub fn to_graphviz(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph routing_table {\n");
+        dot.push_str("    rankdir=LR;\n");
+        dot.push_str("    node [fontname=monospace];\n");
+        dot.push_str(&format!("    \"self\" [shape=doubleoctagon, label=\"{}\\nk = {}\"];\n",
+                              self.self_address.to_str(), self.k));
+        for (i, bucket) in self.buckets.iter().enumerate() {
+            dot.push_str(&format!("    \"bucket {}\" [shape=box, label=\"{}\\n{} nodes, {} replacements\"];\n",
+                                  i, bucket.range(), bucket.nodes.len(), bucket.replacements));
+            dot.push_str(&format!("    \"self\" -> \"bucket {}\";\n", i));
+            for node in &bucket.nodes {
+                let color = match node.status {
+                    Status::Good => "green",
+                    Status::Questionable => "orange",
+                    Status::Bad => "red"
+                };
+                dot.push_str(&format!("    \"{}\" [shape=ellipse, color={}, label=\"{}\\n{:?}, {} pending\\nlast seen {}\"];\n",
+                                      node.address.to_str(), color, node.address.to_str(), node.status,
+                                      node.pending_queries, node.last_seen.rfc3339()));
+                dot.push_str(&format!("    \"bucket {}\" -> \"{}\";\n", i, node.address.to_str()));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl BucketSnapshot {
+    /// The bucket's space in prefix notation, e.g. `8000000000000000000000000000000000000000/1`.
+    pub fn range(&self) -> String {
+        format!("{}/{}", self.prefix.to_str(), self.depth)
+    }
+}
+
+impl ToJson for RoutingTableSnapshot {
+    fn to_json(&self) -> Json {
+        let mut object = BTreeMap::new();
+        object.insert("self_address".to_string(), self.self_address.to_str().to_json());
+        object.insert("k".to_string(), self.k.to_json());
+        object.insert("node_count".to_string(), self.node_count().to_json());
+        object.insert("buckets".to_string(), self.buckets.to_json());
+        Json::Object(object)
+    }
+}
+
+impl ToJson for BucketSnapshot {
+    fn to_json(&self) -> Json {
+        let mut object = BTreeMap::new();
+        object.insert("range".to_string(), self.range().to_json());
+        object.insert("prefix".to_string(), self.prefix.to_str().to_json());
+        object.insert("depth".to_string(), self.depth.to_json());
+        object.insert("node_count".to_string(), self.nodes.len().to_json());
+        object.insert("replacements".to_string(), self.replacements.to_json());
+        object.insert("nodes".to_string(), self.nodes.to_json());
+        Json::Object(object)
+    }
+}
+
+impl ToJson for NodeSnapshot {
+    fn to_json(&self) -> Json {
+        let mut object = BTreeMap::new();
+        object.insert("address".to_string(), self.address.to_str().to_json());
+        object.insert("status".to_string(), format!("{:?}", self.status).to_json());
+        object.insert("pending_queries".to_string(), self.pending_queries.to_json());
+        object.insert("last_seen".to_string(), self.last_seen.rfc3339().to_string().to_json());
+        object.insert("transports".to_string(), self.transports.to_json());
+        Json::Object(object)
+    }
+}
+
+impl ToJson for TransportSnapshot {
+    fn to_json(&self) -> Json {
+        let mut object = BTreeMap::new();
+        object.insert("transport".to_string(), self.transport.to_json());
+        object.insert("rtt_ms".to_string(), self.rtt.map(|rtt| rtt.num_milliseconds()).to_json());
+        object.insert("failures".to_string(), self.failures.to_json());
+        Json::Object(object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use address::Address;
+    use node::Status;
+    use rustc_serialize::json::ToJson;
+    use super::{BucketSnapshot, NodeSnapshot, RoutingTableSnapshot, TransportSnapshot};
+    use time;
+
+    fn snapshot() -> RoutingTableSnapshot {
+        let node = |content, status| NodeSnapshot {
+            address: Address::for_content(content),
+            status: status,
+            pending_queries: 2,
+            last_seen: time::at_utc(time::Timespec::new(1500000000, 0)),
+            transports: vec![TransportSnapshot {
+                transport: "Udp(UdpTransport { 10.0.0.1:6667 })".to_string(),
+                rtt: Some(time::Duration::milliseconds(42)),
+                failures: 1
+            }]
+        };
+        RoutingTableSnapshot {
+            self_address: Address::for_content("self"),
+            k: 8,
+            buckets: vec![
+                BucketSnapshot {
+                    prefix: Address::null(),
+                    depth: 1,
+                    replacements: 0,
+                    nodes: vec![node("alpha", Status::Good), node("beta", Status::Bad)]
+                },
+                BucketSnapshot {
+                    prefix: Address::from_str("8000000000000000000000000000000000000000").unwrap(),
+                    depth: 1,
+                    replacements: 3,
+                    nodes: vec![]
+                }
+            ]
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = snapshot().to_json();
+        assert_eq!(json["node_count"].as_u64(), Some(2));
+        let buckets = json["buckets"].as_array().unwrap();
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[1]["range"].as_string(), Some("8000000000000000000000000000000000000000/1"));
+        assert_eq!(buckets[1]["replacements"].as_u64(), Some(3));
+        let node = &buckets[0]["nodes"][0];
+        assert_eq!(node["address"].as_string(), Some(Address::for_content("alpha").to_str().as_str()));
+        assert_eq!(node["status"].as_string(), Some("Good"));
+        assert_eq!(node["pending_queries"].as_u64(), Some(2));
+        assert_eq!(node["last_seen"].as_string(), Some("2017-07-14T02:40:00Z"));
+        let transport = &node["transports"][0];
+        assert_eq!(transport["transport"].as_string(), Some("Udp(UdpTransport { 10.0.0.1:6667 })"));
+        assert_eq!(transport["rtt_ms"].as_i64(), Some(42));
+        assert_eq!(transport["failures"].as_u64(), Some(1));
+    }
+
+    #[test]
+    fn test_to_graphviz() {
+        let dot = snapshot().to_graphviz();
+        assert!(dot.starts_with("digraph routing_table {"));
+        assert!(dot.contains("\"self\" -> \"bucket 1\";"));
+        let beta = Address::for_content("beta").to_str();
+        assert!(dot.contains(&format!("\"bucket 0\" -> \"{}\";", beta)));
+        assert!(dot.contains(&format!("\"{}\" [shape=ellipse, color=red", beta)));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+}
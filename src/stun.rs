@@ -2,10 +2,11 @@ extern crate stun;
 extern crate rand;
 
 use rand::Rng;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
 
-// TODO: These should be part of configuration
-const STUN_SERVERS: &'static [&'static str] = &[
+/// The STUN servers used when none are configured.
+pub const DEFAULT_SERVERS: &[&str] = &[
     "stun.l.google.com:19302",
     "stun1.l.google.com:19302",
     "stun2.l.google.com:19302",
@@ -13,21 +14,452 @@ const STUN_SERVERS: &'static [&'static str] = &[
     "stun4.l.google.com:19302"
 ];
 
-pub fn get_mapped_address(local_addr: SocketAddr) -> Result<SocketAddr, String> {
-    let stun_server = rand::thread_rng().choose(STUN_SERVERS).expect("No STUN server");
-    let local_port = local_addr.port();
-    let ip_version = match local_addr {
-        SocketAddr::V4(_) => stun::IpVersion::V4,
-        SocketAddr::V6(_) => stun::IpVersion::V6
-    };
-    let client = stun::Client::new(stun_server, local_port, ip_version);
-    let mesage = stun::Message::request();
-    let response = client.send(mesage.encode());
-    let stun::Message { attributes, ..} = stun::Message::decode(response);
+/// How long to wait on a STUN server's response before giving up on it, by default.
+pub const DEFAULT_TIMEOUT_MS: u64 = 2000;
+
+/// How many STUN servers to try before giving up, by default.
+pub const DEFAULT_ATTEMPTS: usize = 3;
+
+/// How to go about discovering the address a NAT maps our sockets to.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// `host:port` of each STUN server we may ask. Servers are tried in random order. If empty,
+    /// STUN is disabled.
+    pub servers: Vec<String>,
+
+    /// How long to wait on each server's response.
+    pub timeout: Duration,
+
+    /// How many requests to make, across servers, before giving up.
+    pub attempts: usize
+}
+
+impl Config {
+    pub fn new() -> Config {
+        Config {
+            servers: DEFAULT_SERVERS.iter().map(|s| s.to_string()).collect(),
+            timeout: Duration::from_millis(DEFAULT_TIMEOUT_MS),
+            attempts: DEFAULT_ATTEMPTS
+        }
+    }
+
+    /// A `Config` that never contacts a STUN server, e.g. for nodes on a LAN or an air-gapped
+    /// network.
+    pub fn disabled() -> Config {
+        Config {
+            servers: vec![],
+            timeout: Duration::from_millis(DEFAULT_TIMEOUT_MS),
+            attempts: 0
+        }
+    }
+}
+
+/// How a NAT between us and the internet maps and filters UDP traffic, as far as STUN probing can
+/// tell. This decides whether other nodes can reach us without our having contacted them first.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NatType {
+    /// There's no NAT; our sockets are reachable at the address they're bound to.
+    Open,
+
+    /// Once a socket has a mapping, anyone can send to it through the mapped address.
+    FullCone,
+
+    /// Only hosts we've sent to may send back to a mapped address, from any port.
+    Restricted,
+
+    /// Only the host and port we've sent to may send back to a mapped address.
+    PortRestricted,
+
+    /// Each destination gets its own mapping, so the mapped address STUN reports is useless to
+    /// anyone but the STUN server.
+    Symmetric,
+
+    /// Probing failed, e.g. because no STUN servers answered or too few are configured.
+    Unknown
+}
+
+/// CHANGE-REQUEST flags, asking a server to respond from its alternate IP and/or port.
+const CHANGE_IP: u8 = 0x04;
+const CHANGE_PORT: u8 = 0x02;
+
+/// The message type of a binding success response.
+const BINDING_RESPONSE: [u8; 2] = [0x01, 0x01];
+
+/// The attribute type of XOR-MAPPED-ADDRESS.
+const XOR_MAPPED_ADDRESS: u16 = 0x0020;
+
+/// The magic cookie every STUN message carries, which mapped addresses are XORed with.
+const MAGIC_COOKIE: [u8; 4] = [0x21, 0x12, 0xA4, 0x42];
+
+/// The length of a STUN header, which the attributes follow.
+const HEADER_LENGTH: usize = 20;
+
+/// Asks STUN servers from `config` what address `socket` is mapped to on the other side of any
+/// NATs between us and them. Servers that don't respond within `config.timeout` are skipped in
+/// favor of another, up to `config.attempts` times. `socket` must be blocking.
+pub fn get_mapped_address(socket: &UdpSocket, config: &Config) -> Result<SocketAddr, String> {
+    let local_addr = socket.local_addr().map_err(|e| e.to_string())?;
+    let mut servers = config.servers.clone();
+    rand::thread_rng().shuffle(&mut servers);
 
-    if let stun::Attribute::XorMappedAddress(stun::XorMappedAddress(address)) = attributes[0] {
-        Ok(address)
+    let mut last_error = "No STUN servers configured".to_string();
+    for stun_server in servers.iter().cycle().take(config.attempts) {
+        let result = resolve(stun_server, local_addr)
+            .and_then(|server_addr| request_mapped_address(socket, server_addr, 0, config.timeout));
+        match result {
+            Ok(address) => return Ok(address),
+            Err(error) => {
+                debug!("STUN request to {} failed: {}", stun_server, error);
+                last_error = error;
+            }
+        }
+    }
+    Err(last_error)
+}
+
+/// Classifies the NAT `socket` sits behind by probing two STUN servers from `config` from it:
+///
+/// 1. Ask the first server for our mapped address. If it's the address we sent from, there's no
+///    NAT.
+/// 2. Ask the second server. If it sees a different mapped address, the NAT is symmetric.
+/// 3. Ask the first server to answer from another IP and port. If that gets through, the NAT is
+///    full-cone.
+/// 4. Ask the first server to answer from another port only. If that gets through, the NAT is
+///    restricted; otherwise it's port-restricted.
+///
+/// Servers that ignore CHANGE-REQUEST, as many public ones do, make cone NATs look
+/// port-restricted, which errs on the side of caution. Like for `get_mapped_address`, `socket`
+/// must be blocking.
+pub fn detect_nat_type(socket: &UdpSocket, config: &Config) -> NatType {
+    match probe_nat_type(socket, config) {
+        Ok(nat_type) => nat_type,
+        Err(error) => {
+            debug!("Couldn't detect NAT type of {:?}: {}", socket.local_addr(), error);
+            NatType::Unknown
+        }
+    }
+}
+
+fn probe_nat_type(socket: &UdpSocket, config: &Config) -> Result<NatType, String> {
+    let local_addr = socket.local_addr().map_err(|e| e.to_string())?;
+    let mut servers = config.servers.iter().filter_map(|s| resolve(s, local_addr).ok());
+    let first = servers.next().ok_or("No STUN servers to probe")?;
+
+    let mapped = request_mapped_address(socket, first, 0, config.timeout)?;
+    let sent_from = SocketAddr::new(local_ip_towards(first)?, local_addr.port());
+    if mapped == sent_from {
+        return Ok(NatType::Open)
+    }
+
+    let second = servers.find(|s| *s != first).ok_or("Need a second STUN server to probe")?;
+    if request_mapped_address(socket, second, 0, config.timeout)? != mapped {
+        return Ok(NatType::Symmetric)
+    }
+
+    if request_mapped_address(socket, first, CHANGE_IP | CHANGE_PORT, config.timeout).is_ok() {
+        Ok(NatType::FullCone)
+    } else if request_mapped_address(socket, first, CHANGE_PORT, config.timeout).is_ok() {
+        Ok(NatType::Restricted)
     } else {
-        Err(format!("Couldn't resolve mapped adress"))
+        Ok(NatType::PortRestricted)
+    }
+}
+
+/// Resolves `stun_server` to an address of the same family as `local_addr`.
+fn resolve(stun_server: &str, local_addr: SocketAddr) -> Result<SocketAddr, String> {
+    stun_server.to_socket_addrs()
+        .map_err(|e| format!("Couldn't resolve {}: {}", stun_server, e))?
+        .find(|a| a.is_ipv4() == local_addr.is_ipv4())
+        .ok_or(format!("{} has no address of the right family", stun_server))
+}
+
+/// The local IP we'd send to `server_addr` from.
+pub fn local_ip_towards(server_addr: SocketAddr) -> Result<IpAddr, String> {
+    let unspecified = match server_addr {
+        SocketAddr::V4(_) => "0.0.0.0:0",
+        SocketAddr::V6(_) => "[::]:0"
+    };
+    let socket = UdpSocket::bind(unspecified).map_err(|e| e.to_string())?;
+    socket.connect(server_addr).map_err(|e| e.to_string())?;
+    socket.local_addr().map(|a| a.ip()).map_err(|e| e.to_string())
+}
+
+/// Sends a binding request to `server_addr` from `socket` and waits up to `timeout` for the
+/// response. With `change` flags set, only a response from an address other than `server_addr`,
+/// as the flags ask for, counts.
+fn request_mapped_address(socket: &UdpSocket, server_addr: SocketAddr, change: u8, timeout: Duration) -> Result<SocketAddr, String> {
+    let mut request = stun::Message::request().encode();
+    if change != 0 {
+        request[3] = 8;
+        request.extend(&[0x00, 0x03, 0x00, 0x04, 0, 0, 0, change]);
+    }
+    socket.send_to(&request, server_addr).map_err(|e| e.to_string())?;
+
+    let deadline = Instant::now() + timeout;
+    let mut buf = [0; 512];
+    loop {
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(format!("No response from {}", server_addr))
+        }
+        socket.set_read_timeout(Some(deadline - now)).map_err(|e| e.to_string())?;
+        let (size, source) = socket.recv_from(&mut buf)
+            .map_err(|e| format!("No response from {}: {}", server_addr, e))?;
+
+        // A response to some other request isn't ours
+        if size < HEADER_LENGTH || buf[8..20] != request[8..20] {
+            continue
+        }
+        let changed_ip = source.ip() != server_addr.ip();
+        let changed_port = source.port() != server_addr.port();
+        if (change & CHANGE_IP != 0 && !changed_ip) || (change & CHANGE_PORT != 0 && !changed_port) {
+            continue
+        }
+
+        return parse_mapped_address(&buf[..size])
+            .ok_or(format!("Couldn't find a mapped address in the response from {}", server_addr))
+    }
+}
+
+/// Finds the XOR-MAPPED-ADDRESS in a binding success response. Returns `None` if there isn't one,
+/// or the response is cut short anywhere. Other attributes are skipped over.
+fn parse_mapped_address(response: &[u8]) -> Option<SocketAddr> {
+    if response.len() < HEADER_LENGTH || response[..2] != BINDING_RESPONSE {
+        return None
+    }
+    let length = read_u16(&response[2..4]) as usize;
+    let mut attributes = response.get(HEADER_LENGTH..HEADER_LENGTH + length)?;
+    while attributes.len() >= 4 {
+        let attribute_type = read_u16(&attributes[..2]);
+        let length = read_u16(&attributes[2..4]) as usize;
+        let value = attributes.get(4..4 + length)?;
+        if attribute_type == XOR_MAPPED_ADDRESS {
+            let key: Vec<u8> = MAGIC_COOKIE.iter().chain(&response[8..HEADER_LENGTH]).cloned().collect();
+            return parse_xor_address(value, &key)
+        }
+        // Values are padded to a multiple of 4 bytes
+        attributes = attributes.get(4 + (length + 3) / 4 * 4..).unwrap_or(&[]);
+    }
+    None
+}
+
+/// Decodes an XOR-MAPPED-ADDRESS value. `key` is the magic cookie followed by the transaction ID,
+/// which the address is XORed with.
+fn parse_xor_address(value: &[u8], key: &[u8]) -> Option<SocketAddr> {
+    if value.len() < 4 {
+        return None
+    }
+    let port = read_u16(&value[2..4]) ^ read_u16(&key[..2]);
+    let ip = match (value[1], value.len()) {
+        (1, 8) => {
+            let mut octets = [0; 4];
+            for (i, octet) in octets.iter_mut().enumerate() {
+                *octet = value[4 + i] ^ key[i];
+            }
+            IpAddr::from(octets)
+        }
+        (2, 20) => {
+            let mut octets = [0; 16];
+            for (i, octet) in octets.iter_mut().enumerate() {
+                *octet = value[4 + i] ^ key[i];
+            }
+            IpAddr::from(octets)
+        }
+        _ => return None
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+fn read_u16(bytes: &[u8]) -> u16 {
+    (bytes[0] as u16) << 8 | bytes[1] as u16
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::net::{IpAddr, SocketAddr, UdpSocket};
+    use std::thread;
+    use std::time::Duration;
+    use super::{CHANGE_IP, CHANGE_PORT, MAGIC_COOKIE, Config, NatType, detect_nat_type, get_mapped_address, parse_mapped_address};
+
+    /// Encodes a binding success response for `request`, telling the requester it was seen at
+    /// `mapped`.
+    pub fn binding_response(request: &[u8], mapped: SocketAddr) -> Vec<u8> {
+        let port = mapped.port() ^ 0x2112;
+        let mut value = vec![0, 1, (port >> 8) as u8, port as u8];
+        match mapped.ip() {
+            IpAddr::V4(ip) => {
+                value.extend(ip.octets().iter().zip(MAGIC_COOKIE.iter()).map(|(b, m)| b ^ m));
+            }
+            IpAddr::V6(ip) => {
+                value[1] = 2;
+                let key = MAGIC_COOKIE.iter().chain(&request[8..20]);
+                value.extend(ip.octets().iter().zip(key).map(|(b, k)| b ^ k));
+            }
+        }
+
+        let length = value.len() + 4;
+        let mut response = vec![0x01, 0x01, (length >> 8) as u8, length as u8];
+        response.extend(&request[4..20]);
+        response.extend(&[0x00, 0x20, 0x00, value.len() as u8]);
+        response.extend(value);
+        response
+    }
+
+    /// Runs a stand-in STUN server that answers `requests` binding requests, reporting each
+    /// requester's address as `mapped` returns it. Returns the address to reach it at.
+    pub fn stun_server<F>(requests: usize, mapped: F) -> SocketAddr
+            where F: Fn(SocketAddr) -> SocketAddr + Send + 'static {
+        changing_stun_server(requests, mapped, false, false)
+    }
+
+    /// Like `stun_server`, but honors CHANGE-REQUESTs for another IP if `change_ip`, and for
+    /// another port if `change_port`, as a NAT that lets such responses through would. Requests
+    /// it can't honor are answered from the usual address, as public servers do.
+    pub fn changing_stun_server<F>(requests: usize, mapped: F, change_ip: bool, change_port: bool) -> SocketAddr
+            where F: Fn(SocketAddr) -> SocketAddr + Send + 'static {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let other_port = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let other_ip = UdpSocket::bind("127.0.0.2:0").unwrap();
+        let address = socket.local_addr().unwrap();
+        thread::spawn(move || {
+            let mut buf = [0; 512];
+            for _ in 0..requests {
+                let (size, source) = socket.recv_from(&mut buf).unwrap();
+                let request = &buf[..size];
+                let change = if size >= 28 && request[20..22] == [0x00, 0x03] { request[27] } else { 0 };
+                let response = binding_response(request, mapped(source));
+                let responder = if change & CHANGE_IP != 0 && change_ip {
+                    &other_ip
+                } else if change & CHANGE_IP == 0 && change & CHANGE_PORT != 0 && change_port {
+                    &other_port
+                } else {
+                    &socket
+                };
+                responder.send_to(&response, source).unwrap();
+            }
+        });
+        address
+    }
+
+    fn config(servers: Vec<SocketAddr>) -> Config {
+        Config {
+            servers: servers.iter().map(|s| s.to_string()).collect(),
+            timeout: Duration::from_millis(200),
+            attempts: 2
+        }
+    }
+
+    #[test]
+    fn test_get_mapped_address() {
+        let mapped: SocketAddr = "203.0.113.7:41000".parse().unwrap();
+        let server = stun_server(1, move |_| mapped);
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        assert_eq!(get_mapped_address(&socket, &config(vec![server])), Ok(mapped));
+    }
+
+    #[test]
+    fn test_get_mapped_address_retries_other_servers() {
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mapped: SocketAddr = "203.0.113.7:41000".parse().unwrap();
+        let server = stun_server(1, move |_| mapped);
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let config = config(vec![silent.local_addr().unwrap(), server]);
+        assert_eq!(get_mapped_address(&socket, &config), Ok(mapped));
+    }
+
+    #[test]
+    fn test_get_mapped_address_times_out() {
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        assert!(get_mapped_address(&socket, &config(vec![silent.local_addr().unwrap()])).is_err());
+    }
+
+    #[test]
+    fn test_get_mapped_address_ignores_truncated_attributes() {
+        let mapped: SocketAddr = "203.0.113.7:41000".parse().unwrap();
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let address = server.local_addr().unwrap();
+        thread::spawn(move || {
+            let mut buf = [0; 512];
+            for cut in &[2, 6] {
+                // The header still claims the whole attribute
+                let (size, source) = server.recv_from(&mut buf).unwrap();
+                let response = binding_response(&buf[..size], mapped);
+                server.send_to(&response[..response.len() - cut], source).unwrap();
+            }
+        });
+        assert!(get_mapped_address(&socket, &config(vec![address])).is_err());
+
+        let server = stun_server(1, move |_| mapped);
+        assert_eq!(get_mapped_address(&socket, &config(vec![server])), Ok(mapped));
+    }
+
+    #[test]
+    fn test_parse_mapped_address() {
+        let mut request = vec![0x00, 0x01, 0x00, 0x00];
+        request.extend(&MAGIC_COOKIE);
+        request.extend(&[7; 12]);
+        let mapped: SocketAddr = "[2001:db8::7]:41000".parse().unwrap();
+        let response = binding_response(&request, mapped);
+        assert_eq!(parse_mapped_address(&response), Some(mapped));
+        for size in 0..response.len() {
+            assert_eq!(parse_mapped_address(&response[..size]), None);
+        }
+
+        // Unknown attributes before it are skipped, padding and all
+        let mut padded = response[..20].to_vec();
+        padded.extend(&[0x80, 0x22, 0x00, 0x03, b'a', b'b', b'c', 0]);
+        padded.extend(&response[20..]);
+        padded[3] += 8;
+        assert_eq!(parse_mapped_address(&padded), Some(mapped));
+    }
+
+    #[test]
+    fn test_get_mapped_address_disabled() {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        assert!(get_mapped_address(&socket, &Config::disabled()).is_err());
+    }
+
+    #[test]
+    fn test_detect_open_nat_type() {
+        let first = stun_server(1, |source| source);
+        let second = stun_server(0, |source| source);
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        assert_eq!(detect_nat_type(&socket, &config(vec![first, second])), NatType::Open);
+    }
+
+    #[test]
+    fn test_detect_symmetric_nat_type() {
+        let first = stun_server(1, |_| "203.0.113.7:41000".parse().unwrap());
+        let second = stun_server(1, |_| "203.0.113.7:41001".parse().unwrap());
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        assert_eq!(detect_nat_type(&socket, &config(vec![first, second])), NatType::Symmetric);
+    }
+
+    #[test]
+    fn test_detect_cone_nat_types() {
+        let mapped = |_| "203.0.113.7:41000".parse().unwrap();
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        let first = changing_stun_server(2, mapped, true, true);
+        let second = stun_server(1, mapped);
+        assert_eq!(detect_nat_type(&socket, &config(vec![first, second])), NatType::FullCone);
+
+        let first = changing_stun_server(3, mapped, false, true);
+        let second = stun_server(1, mapped);
+        assert_eq!(detect_nat_type(&socket, &config(vec![first, second])), NatType::Restricted);
+
+        let first = changing_stun_server(3, mapped, false, false);
+        let second = stun_server(1, mapped);
+        assert_eq!(detect_nat_type(&socket, &config(vec![first, second])), NatType::PortRestricted);
+    }
+
+    #[test]
+    fn test_detect_nat_type_needs_two_servers() {
+        let first = stun_server(1, |_| "203.0.113.7:41000".parse().unwrap());
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        assert_eq!(detect_nat_type(&socket, &config(vec![first])), NatType::Unknown);
+        assert_eq!(detect_nat_type(&socket, &Config::disabled()), NatType::Unknown);
     }
 }
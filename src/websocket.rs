@@ -0,0 +1,349 @@
+//! Just enough of the WebSocket protocol (RFC 6455) to carry binary `Envelope`s between nodes:
+//! the opening handshake, and binary messages in either direction. Peers that can only get out
+//! through HTTP proxies can still reach a node that speaks it.
+
+use crypto::digest::Digest;
+use crypto::sha1::Sha1;
+use rand;
+use rustc_serialize::base64::{STANDARD, ToBase64};
+use servers::MAX_FRAME_LENGTH;
+use std::io::{self, Read, Write};
+
+/// Appended to a client's key to work out the accept key the server proves itself with.
+const GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// The most we'll read of a handshake before giving up on it.
+const MAX_HANDSHAKE_LENGTH: usize = 8192;
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// The body of a close frame failing the connection with status 1002, a protocol error.
+const CLOSE_PROTOCOL_ERROR: [u8; 2] = [0x03, 0xEA];
+
+/// Splits the `host:port/path` part of a `ws://` URL into the host and the path, which is `/`
+/// if there's none.
+pub fn split_path(address: &str) -> (&str, &str) {
+    match address.find('/') {
+        Some(index) => (&address[..index], &address[index..]),
+        None => (address, "/")
+    }
+}
+
+/// The `Sec-WebSocket-Accept` a server answers a `Sec-WebSocket-Key` of `key` with.
+pub fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.input_str(key);
+    hasher.input_str(GUID);
+    let mut digest = [0; 20];
+    hasher.result(&mut digest);
+    digest.to_base64(STANDARD)
+}
+
+/// Performs the client side of the opening handshake over `stream`, asking `host` for `path`.
+pub fn connect<S: Read + Write>(stream: &mut S, host: &str, path: &str) -> io::Result<()> {
+    let key = rand::random::<[u8; 16]>().to_base64(STANDARD);
+    write!(stream,
+           "GET {} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+            Sec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n\r\n",
+           path, host, key)?;
+    stream.flush()?;
+
+    let (status_line, headers) = read_head(stream)?;
+    if status_line.split(' ').nth(1) != Some("101") {
+        return Err(handshake_error(format!("Unexpected response: {}", status_line)))
+    }
+    if header(&headers, "sec-websocket-accept") != Some(&accept_key(&key)) {
+        return Err(handshake_error("Wrong Sec-WebSocket-Accept".to_string()))
+    }
+    Ok(())
+}
+
+/// Performs the server side of the opening handshake over `stream`, turning away any request
+/// that isn't for `path`.
+pub fn accept<S: Read + Write>(stream: &mut S, path: &str) -> io::Result<()> {
+    let (request_line, headers) = read_head(stream)?;
+    let parts: Vec<&str> = request_line.split(' ').collect();
+    if parts.len() != 3 || parts[0] != "GET" || parts[1] != path {
+        stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")?;
+        return Err(handshake_error(format!("Unexpected request: {}", request_line)))
+    }
+    let upgrade = header(&headers, "upgrade").map(|u| u.to_lowercase());
+    let key = match (upgrade.as_ref().map(|u| &u[..]), header(&headers, "sec-websocket-key")) {
+        (Some("websocket"), Some(key)) => key.to_string(),
+        _ => {
+            stream.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n")?;
+            return Err(handshake_error("Not a WebSocket request".to_string()))
+        }
+    };
+    write!(stream,
+           "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+            Sec-WebSocket-Accept: {}\r\n\r\n",
+           accept_key(&key))?;
+    stream.flush()
+}
+
+/// Writes `message` as a single binary frame. Clients must `mask` their frames; servers must not.
+pub fn write_message<W: Write>(writer: &mut W, message: &[u8], mask: bool) -> io::Result<()> {
+    write_frame(writer, OPCODE_BINARY, message, mask)
+}
+
+/// Reads the next message from `stream`, putting fragmented messages back together and answering
+/// pings along the way. `mask` is whether our own frames, i.e. pongs, must be masked, which also
+/// means the other end's must not be, and the other way around. A close frame ends the connection
+/// with an error, as does a frame that's masked when it mustn't be or the other way around, after
+/// we send a close frame of our own.
+pub fn read_message<S: Read + Write>(stream: &mut S, mask: bool) -> io::Result<Vec<u8>> {
+    let mut message = vec![];
+    loop {
+        let (fin, opcode, payload) = match read_frame(stream, !mask) {
+            Ok(frame) => frame,
+            Err(error) => {
+                if error.kind() == io::ErrorKind::InvalidData {
+                    let _ = write_frame(stream, OPCODE_CLOSE, &CLOSE_PROTOCOL_ERROR, mask);
+                }
+                return Err(error)
+            }
+        };
+        match opcode {
+            OPCODE_CONTINUATION | OPCODE_TEXT | OPCODE_BINARY => {
+                if message.len() + payload.len() > MAX_FRAME_LENGTH {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, "Message is too long"))
+                }
+                message.extend(payload);
+                if fin {
+                    return Ok(message)
+                }
+            }
+            OPCODE_PING => write_frame(stream, OPCODE_PONG, &payload, mask)?,
+            OPCODE_PONG => { }
+            OPCODE_CLOSE => {
+                return Err(io::Error::new(io::ErrorKind::ConnectionAborted, "Connection closed"))
+            }
+            _ => {
+                return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                          format!("Unknown opcode {}", opcode)))
+            }
+        }
+    }
+}
+
+fn write_frame<W: Write>(writer: &mut W, opcode: u8, payload: &[u8], mask: bool) -> io::Result<()> {
+    let mask_bit = if mask { 0x80 } else { 0 };
+    let mut frame = vec![0x80 | opcode];
+    let length = payload.len();
+    if length < 126 {
+        frame.push(mask_bit | length as u8);
+    } else if length <= 0xFFFF {
+        frame.push(mask_bit | 126);
+        frame.extend(&[(length >> 8) as u8, length as u8]);
+    } else {
+        frame.push(mask_bit | 127);
+        frame.extend((0..8).rev().map(|i| ((length as u64) >> (8 * i)) as u8));
+    }
+    if mask {
+        let key = rand::random::<[u8; 4]>();
+        frame.extend(&key);
+        frame.extend(payload.iter().enumerate().map(|(i, b)| b ^ key[i % 4]));
+    } else {
+        frame.extend(payload);
+    }
+    writer.write_all(&frame)?;
+    writer.flush()
+}
+
+/// Reads a single frame, unmasking it. Servers must only accept masked frames, and clients only
+/// unmasked ones, as `masked` says.
+fn read_frame<R: Read>(reader: &mut R, masked: bool) -> io::Result<(bool, u8, Vec<u8>)> {
+    let mut head = [0; 2];
+    reader.read_exact(&mut head)?;
+    let fin = head[0] & 0x80 != 0;
+    let opcode = head[0] & 0x0F;
+    if (head[1] & 0x80 != 0) != masked {
+        let error = if masked { "Frame isn't masked" } else { "Frame is masked" };
+        return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+    }
+    let length = match head[1] & 0x7F {
+        126 => {
+            let mut length = [0; 2];
+            reader.read_exact(&mut length)?;
+            length.iter().fold(0u64, |length, &b| (length << 8) | b as u64)
+        }
+        127 => {
+            let mut length = [0; 8];
+            reader.read_exact(&mut length)?;
+            length.iter().fold(0u64, |length, &b| (length << 8) | b as u64)
+        }
+        length => length as u64
+    };
+    if length > MAX_FRAME_LENGTH as u64 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                  format!("Frame of {} bytes is too long", length)));
+    }
+
+    let mut key = [0; 4];
+    if masked {
+        reader.read_exact(&mut key)?;
+    }
+    let mut payload = vec![0; length as usize];
+    reader.read_exact(&mut payload)?;
+    if masked {
+        for (i, b) in payload.iter_mut().enumerate() {
+            *b ^= key[i % 4];
+        }
+    }
+    Ok((fin, opcode, payload))
+}
+
+/// Reads an HTTP request or response head, returning its first line and its headers with
+/// lowercased names. Reads a byte at a time so as not to swallow any frames that follow.
+fn read_head<R: Read>(reader: &mut R) -> io::Result<(String, Vec<(String, String)>)> {
+    let mut head = vec![];
+    let mut byte = [0; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_HANDSHAKE_LENGTH {
+            return Err(handshake_error("Handshake is too long".to_string()))
+        }
+        reader.read_exact(&mut byte)?;
+        head.push(byte[0]);
+    }
+    let head = String::from_utf8(head)
+        .map_err(|_| handshake_error("Handshake isn't UTF-8".to_string()))?;
+
+    let mut lines = head.split("\r\n").filter(|l| !l.is_empty());
+    let first_line = lines.next().unwrap_or("").to_string();
+    let headers = lines
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.splitn(2, ':').collect();
+            match parts.len() {
+                2 => Some((parts[0].trim().to_lowercase(), parts[1].trim().to_string())),
+                _ => None
+            }
+        })
+        .collect();
+    Ok((first_line, headers))
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a String> {
+    headers.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+}
+
+fn handshake_error(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::io::{self, Cursor, Read, Write};
+    use std::net::{SocketAddr, TcpListener};
+    use std::thread;
+    use super::{accept, accept_key, connect, read_message, write_message};
+
+    /// A stream that reads from `input` and writes to `output`.
+    struct Duplex {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>
+    }
+
+    impl Read for Duplex {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for Duplex {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.output.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Runs a stand-in WebSocket server at `path` that echoes each message it receives back to
+    /// the sender. Returns the address to reach it at.
+    pub fn echo_server(path: &'static str) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                thread::spawn(move || {
+                    if accept(&mut stream, path).is_err() {
+                        return
+                    }
+                    while let Ok(message) = read_message(&mut stream, false) {
+                        write_message(&mut stream, &message, false).unwrap();
+                    }
+                });
+            }
+        });
+        address
+    }
+
+    #[test]
+    fn test_accept_key() {
+        // The example from RFC 6455
+        assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+    }
+
+    #[test]
+    fn test_read_message() {
+        // Masked "Hello" from RFC 6455, in two fragments, with a ping in between
+        let mut frames = vec![0x01, 0x83, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d];
+        frames.extend(&[0x89, 0x80, 0x00, 0x00, 0x00, 0x00]);
+        frames.extend(&[0x80, 0x82, 0x37, 0xfa, 0x21, 0x3d, 0x5b, 0x95]);
+        let mut stream = Duplex { input: Cursor::new(frames), output: vec![] };
+        assert_eq!(read_message(&mut stream, false).unwrap(), b"Hello".to_vec());
+
+        // The ping was answered with a pong
+        assert_eq!(stream.output, vec![0x8a, 0x00]);
+    }
+
+    #[test]
+    fn test_read_unmasked_message() {
+        // Unmasked "Hello" from RFC 6455, which a server must not accept
+        let frames = vec![0x81, 0x05, 0x48, 0x65, 0x6c, 0x6c, 0x6f];
+        let mut stream = Duplex { input: Cursor::new(frames.clone()), output: vec![] };
+        assert!(read_message(&mut stream, false).is_err());
+
+        // The connection was closed with a protocol error
+        assert_eq!(stream.output, vec![0x88, 0x02, 0x03, 0xea]);
+
+        // While a client must only accept unmasked frames
+        let mut stream = Duplex { input: Cursor::new(frames), output: vec![] };
+        assert_eq!(read_message(&mut stream, true).unwrap(), b"Hello".to_vec());
+    }
+
+    #[test]
+    fn test_write_message() {
+        for &length in &[5, 300, 70000] {
+            let message = vec![7; length];
+            for &mask in &[true, false] {
+                let mut frames = Cursor::new(vec![]);
+                write_message(&mut frames, &message, mask).unwrap();
+                frames.set_position(0);
+                assert_eq!(read_message(&mut frames, !mask).unwrap(), message);
+            }
+        }
+    }
+
+    #[test]
+    fn test_echo() {
+        use std::net::TcpStream;
+
+        let address = echo_server("/comm");
+        let mut stream = TcpStream::connect(address).unwrap();
+        connect(&mut stream, &address.to_string(), "/comm").unwrap();
+        write_message(&mut stream, b"hello", true).unwrap();
+        assert_eq!(read_message(&mut stream, true).unwrap(), b"hello".to_vec());
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        assert!(connect(&mut stream, &address.to_string(), "/elsewhere").is_err());
+    }
+}